@@ -10,6 +10,7 @@ use crate::kzg_types::ArkG1;
 use crate::kzg_types::ArkG1Affine;
 use crate::kzg_types::ArkG1ProjAddAffine;
 use crate::kzg_types::ArkG2;
+use crate::kzg_types::ArkGT;
 use crate::utils::PolyData;
 
 pub struct ArkBackend;
@@ -20,6 +21,7 @@ impl EcBackend for ArkBackend {
     type G1Affine = ArkG1Affine;
     type G1 = ArkG1;
     type G2 = ArkG2;
+    type GT = ArkGT;
     type Poly = PolyData;
     type FFTSettings = FFTSettings;
     type KZGSettings = KZGSettings;
@@ -12,12 +12,15 @@ use crate::utils::{
     pc_g2projective_into_blst_p2, PolyData,
 };
 use arbitrary::Arbitrary;
-use ark_bls12_381::{g1, g2, Fr, G1Affine};
+use ark_bls12_381::{g1, g2, Bls12_381, Fq, Fq12, Fq2, Fq6, Fr, G1Affine};
 use ark_ec::short_weierstrass_jacobian::GroupAffine;
-use ark_ec::ModelParameters;
 use ark_ec::{models::short_weierstrass_jacobian::GroupProjective, AffineCurve, ProjectiveCurve};
+use ark_ec::{ModelParameters, PairingEngine};
 use ark_ff::PrimeField;
-use ark_ff::{biginteger::BigInteger256, BigInteger, Field};
+use ark_ff::{
+    biginteger::{BigInteger256, BigInteger384},
+    BigInteger, Field,
+};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::{One, Zero};
 
@@ -25,12 +28,13 @@ use ark_std::{One, Zero};
 use ark_std::UniformRand;
 
 use kzg::common_utils::reverse_bit_order;
-use kzg::eip_4844::{BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2};
+use kzg::eip_4844::{BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2, BYTES_PER_GT};
 use kzg::eth::c_bindings::{blst_fp, blst_fp2, blst_fr, blst_p1, blst_p2};
 use kzg::msm::precompute::{precompute, PrecomputationTable};
 use kzg::{
     FFTFr, FFTSettings, FFTSettingsPoly, Fr as KzgFr, G1Affine as G1AffineTrait, G1Fp, G1GetFp,
-    G1LinComb, G1Mul, G1ProjAddAffine, G2Mul, KZGSettings, PairingVerify, Poly, Scalar256, G1, G2,
+    G1LinComb, G1Mul, G1ProjAddAffine, G2Mul, GTPow, KZGSettings, Pairing, PairingVerify, Poly,
+    Scalar256, G1, G2, GT,
 };
 use std::ops::{AddAssign, Neg, Sub};
 
@@ -494,6 +498,32 @@ impl G1LinComb<ArkFr, ArkFp, ArkG1Affine, ArkG1ProjAddAffine> for ArkG1 {
         g1_linear_combination(&mut out, points, scalars, len, precomputation);
         out
     }
+
+    fn g1_lincomb_batch(
+        points: &[Vec<Self>],
+        scalars: &[Vec<ArkFr>],
+        _precomputation: Option<
+            &PrecomputationTable<ArkFr, Self, ArkFp, ArkG1Affine, ArkG1ProjAddAffine>,
+        >,
+    ) -> Result<Vec<Self>, String> {
+        // Precomputed tables are not used by arkworks MSM, so they are ignored here as well,
+        // even if another crate enables a precomputation feature of `kzg`
+        if points.len() != scalars.len() {
+            return Err("Invalid batch size".to_owned());
+        }
+
+        points
+            .iter()
+            .zip(scalars.iter())
+            .map(|(points, scalars)| {
+                if points.len() != scalars.len() {
+                    return Err("Invalid point count length".to_owned());
+                }
+
+                Ok(Self::g1_lincomb(points, scalars, points.len(), None))
+            })
+            .collect()
+    }
 }
 
 impl PairingVerify<ArkG1, ArkG2> for ArkG1 {
@@ -502,6 +532,28 @@ impl PairingVerify<ArkG1, ArkG2> for ArkG1 {
     }
 }
 
+impl Pairing<ArkG1, ArkG2, ArkGT> for ArkG1 {
+    fn miller_loop(a: &ArkG1, b: &ArkG2) -> ArkGT {
+        ArkGT(Bls12_381::miller_loop(&[(
+            a.0.into_affine().into(),
+            b.0.into_affine().into(),
+        )]))
+    }
+
+    fn final_exp(f: &ArkGT) -> ArkGT {
+        ArkGT(Bls12_381::final_exponentiation(&f.0).unwrap_or_default())
+    }
+
+    fn multi_miller_loop(a: &[ArkG1], b: &[ArkG2]) -> ArkGT {
+        let prepared = a
+            .iter()
+            .zip(b.iter())
+            .map(|(a, b)| (a.0.into_affine().into(), b.0.into_affine().into()))
+            .collect::<Vec<_>>();
+        ArkGT(Bls12_381::miller_loop(&prepared))
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub struct ArkG2(pub GroupProjective<g2::Parameters>);
@@ -729,13 +781,46 @@ impl G2 for ArkG2 {
     }
 
     fn to_bytes(&self) -> [u8; 96] {
-        <[u8; 96]>::try_from(self.0.x.c0.0.to_bytes_le()).unwrap()
+        let mut out = [0u8; 96];
+        let v = self.to_blst_p2();
+        unsafe {
+            blst::blst_p2_compress(
+                out.as_mut_ptr(),
+                &blst::blst_p2 {
+                    x: blst::blst_fp2 {
+                        fp: [
+                            blst::blst_fp { l: v.x.fp[0].l },
+                            blst::blst_fp { l: v.x.fp[1].l },
+                        ],
+                    },
+                    y: blst::blst_fp2 {
+                        fp: [
+                            blst::blst_fp { l: v.y.fp[0].l },
+                            blst::blst_fp { l: v.y.fp[1].l },
+                        ],
+                    },
+                    z: blst::blst_fp2 {
+                        fp: [
+                            blst::blst_fp { l: v.z.fp[0].l },
+                            blst::blst_fp { l: v.z.fp[1].l },
+                        ],
+                    },
+                },
+            );
+        }
+        out
     }
 
     fn add_or_dbl(&mut self, b: &Self) -> Self {
         Self(self.0 + b.0)
     }
 
+    fn is_valid(&self) -> bool {
+        let affine = self.0.into_affine();
+
+        affine.is_on_curve() && affine.is_in_correct_subgroup_assuming_on_curve()
+    }
+
     fn dbl(&self) -> Self {
         Self(self.0.double())
     }
@@ -755,6 +840,103 @@ impl G2Mul<ArkFr> for ArkG2 {
     }
 }
 
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct ArkGT(pub Fq12);
+
+impl ArkGT {
+    fn coeffs(&self) -> [Fq; 12] {
+        let f = &self.0;
+        [
+            f.c0.c0.c0, f.c0.c0.c1, f.c0.c1.c0, f.c0.c1.c1, f.c0.c2.c0, f.c0.c2.c1, f.c1.c0.c0,
+            f.c1.c0.c1, f.c1.c1.c0, f.c1.c1.c1, f.c1.c2.c0, f.c1.c2.c1,
+        ]
+    }
+
+    fn from_coeffs(c: &[Fq; 12]) -> Self {
+        Self(Fq12::new(
+            Fq6::new(
+                Fq2::new(c[0], c[1]),
+                Fq2::new(c[2], c[3]),
+                Fq2::new(c[4], c[5]),
+            ),
+            Fq6::new(
+                Fq2::new(c[6], c[7]),
+                Fq2::new(c[8], c[9]),
+                Fq2::new(c[10], c[11]),
+            ),
+        ))
+    }
+}
+
+impl GT for ArkGT {
+    fn one() -> Self {
+        Self(Fq12::one())
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        bytes
+            .try_into()
+            .map_err(|_| {
+                format!(
+                    "Invalid byte length. Expected {}, got {}",
+                    BYTES_PER_GT,
+                    bytes.len()
+                )
+            })
+            .and_then(|bytes: &[u8; BYTES_PER_GT]| {
+                let mut coeffs = [Fq::zero(); 12];
+                for (fq, chunk) in coeffs.iter_mut().zip(bytes.chunks_exact(48)) {
+                    let storage: [u64; 6] = core::array::from_fn(|i| {
+                        bytes_be_to_uint64(&chunk[(5 - i) * 8..(6 - i) * 8])
+                    });
+                    *fq = Fq::from_repr(BigInteger384::new(storage))
+                        .ok_or_else(|| "Invalid field element".to_string())?;
+                }
+
+                let gt = Self::from_coeffs(&coeffs);
+                if !gt.0.pow(Fr::characteristic()).is_one() {
+                    return Err("Element is not in the target group".to_string());
+                }
+
+                Ok(gt)
+            })
+    }
+
+    fn to_bytes(&self) -> [u8; 576] {
+        let mut out = [0u8; BYTES_PER_GT];
+        for (fq, chunk) in self.coeffs().iter().zip(out.chunks_exact_mut(48)) {
+            chunk.copy_from_slice(&fq.into_repr().to_bytes_be());
+        }
+        out
+    }
+
+    fn is_one(&self) -> bool {
+        self.0.is_one()
+    }
+
+    fn mul(&self, b: &Self) -> Self {
+        Self(self.0 * b.0)
+    }
+
+    fn sqr(&self) -> Self {
+        Self(self.0.square())
+    }
+
+    fn inverse(&self) -> Self {
+        Self(self.0.inverse().unwrap_or_default())
+    }
+
+    fn equals(&self, b: &Self) -> bool {
+        self.0 == b.0
+    }
+}
+
+impl GTPow<ArkFr> for ArkGT {
+    fn pow(&self, b: &ArkFr) -> Self {
+        Self(self.0.pow(b.to_u64_arr()))
+    }
+}
+
 impl Poly<ArkFr> for PolyData {
     fn new(size: usize) -> PolyData {
         Self {
@@ -937,9 +1119,13 @@ impl
         }
 
         let field_elements_per_blob = g1_monomial.len();
-        let field_elements_per_ext_blob = field_elements_per_blob * 2;
+        // The extended blob spans the FFT domain, see `KZGSettings::get_extension_factor`.
+        // Settings with a narrower domain are not used for cells.
+        let field_elements_per_ext_blob = fft_settings
+            .get_max_width()
+            .max(field_elements_per_blob * 2);
 
-        let n = field_elements_per_ext_blob / 2;
+        let n = field_elements_per_blob;
         let k = n / cell_size;
         let k2 = 2 * k;
 
@@ -1408,6 +1594,12 @@ impl G1AffineTrait<ArkG1, ArkFp> for ArkG1Affine {
             .map(|aff| Self { aff })
             .map_err(|err| err.to_string())
     }
+
+    fn from_bytes_uncompressed_unchecked(bytes: [u8; 96]) -> Result<Self, String> {
+        G1Affine::deserialize_unchecked(&bytes[..])
+            .map(|aff| Self { aff })
+            .map_err(|err| err.to_string())
+    }
 }
 
 #[derive(Debug)]
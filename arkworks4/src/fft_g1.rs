@@ -4,7 +4,7 @@ use crate::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine};
 
 use crate::kzg_types::ArkG1ProjAddAffine;
 
-use kzg::msm::msm_impls::msm;
+use kzg::msm::msm_impls::{msm, msm_nbits};
 
 use kzg::msm::precompute::PrecomputationTable;
 use kzg::{Fr as KzgFr, G1Mul};
@@ -30,6 +30,21 @@ pub fn g1_linear_combination(
     );
 }
 
+pub fn g1_linear_combination_short(
+    out: &mut ArkG1,
+    points: &[ArkG1],
+    scalars: &[ArkFr],
+    len: usize,
+    scalar_bits: usize,
+) {
+    *out = msm_nbits::<ArkG1, ArkFp, ArkG1Affine, ArkG1ProjAddAffine, ArkFr>(
+        points,
+        scalars,
+        len,
+        scalar_bits,
+    );
+}
+
 pub fn make_data(data: usize) -> Vec<ArkG1> {
     let mut vec = Vec::new();
     if data != 0 {
@@ -2,7 +2,7 @@ use crate::consts::{
     G1_GENERATOR, G1_IDENTITY, G1_NEGATIVE_GENERATOR, G2_GENERATOR, G2_NEGATIVE_GENERATOR,
     SCALE2_ROOT_OF_UNITY,
 };
-use crate::fft_g1::g1_linear_combination;
+use crate::fft_g1::{g1_linear_combination, g1_linear_combination_short};
 use crate::kzg_proofs::{
     eval_poly, expand_root_of_unity, pairings_verify, FFTSettings as LFFTSettings,
     KZGSettings as LKZGSettings,
@@ -15,10 +15,14 @@ use crate::utils::{
     pc_g1projective_into_blst_p1, pc_g2projective_into_blst_p2, PolyData, PRECOMPUTATION_TABLES,
 };
 use arbitrary::Arbitrary;
-use ark_bls12_381::{g1, g2, Fr, G1Affine, G2Affine};
+use ark_bls12_381::{g1, g2, Bls12_381, Fq, Fq12, Fq2, Fq6, Fr, G1Affine, G2Affine};
+use ark_ec::pairing::{MillerLoopOutput, Pairing as _};
 use ark_ec::{models::short_weierstrass::Projective, AffineRepr, Group};
 use ark_ec::{CurveConfig, CurveGroup};
-use ark_ff::{biginteger::BigInteger256, BigInteger, Field};
+use ark_ff::{
+    biginteger::{BigInteger256, BigInteger384},
+    BigInteger, Field, PrimeField,
+};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ark_std::{One, Zero};
 
@@ -32,12 +36,12 @@ use kzg::common_utils::reverse_bit_order;
 use kzg::msm::precompute::{precompute, PrecomputationTable};
 use kzg::{
     eth, FFTFr, FFTSettings, FFTSettingsPoly, Fr as KzgFr, G1Affine as G1AffineTrait, G1Fp,
-    G1GetFp, G1LinComb, G1Mul, G1ProjAddAffine, G2Mul, KZGSettings, PairingVerify, Poly, Scalar256,
-    G1, G2,
+    G1GetFp, G1LinComb, G1Mul, G1ProjAddAffine, G2Mul, GTPow, KZGSettings, Pairing, PairingVerify,
+    Poly, Scalar256, G1, G2, GT,
 };
 use std::ops::{AddAssign, Mul, Neg, Sub};
 
-use kzg::eip_4844::{BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2};
+use kzg::eip_4844::{BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2, BYTES_PER_GT};
 
 extern crate alloc;
 use alloc::sync::Arc;
@@ -319,6 +323,23 @@ impl G1 for ArkG1 {
             })
     }
 
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, String> {
+        bytes
+            .try_into()
+            .map_err(|_| {
+                format!(
+                    "Invalid byte length. Expected {}, got {}",
+                    BYTES_PER_G1,
+                    bytes.len()
+                )
+            })
+            .and_then(|bytes: &[u8; BYTES_PER_G1]| {
+                G1Affine::deserialize_compressed_unchecked(bytes.as_slice())
+                    .map(|affine| Self(affine.into_group()))
+                    .map_err(|err| format!("Failed to deserialize G1: {err}"))
+            })
+    }
+
     fn from_hex(hex: &str) -> Result<Self, String> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
@@ -419,6 +440,17 @@ impl G1LinComb<ArkFr, ArkFp, ArkG1Affine, ArkG1ProjAddAffine> for ArkG1 {
         g1_linear_combination(&mut out, points, scalars, len, precomputation);
         out
     }
+
+    fn g1_lincomb_short(
+        points: &[Self],
+        scalars: &[ArkFr],
+        len: usize,
+        scalar_bits: usize,
+    ) -> Self {
+        let mut out = ArkG1::default();
+        g1_linear_combination_short(&mut out, points, scalars, len, scalar_bits);
+        out
+    }
 }
 
 impl PairingVerify<ArkG1, ArkG2> for ArkG1 {
@@ -427,6 +459,30 @@ impl PairingVerify<ArkG1, ArkG2> for ArkG1 {
     }
 }
 
+impl Pairing<ArkG1, ArkG2, ArkGT> for ArkG1 {
+    fn miller_loop(a: &ArkG1, b: &ArkG2) -> ArkGT {
+        ArkGT(Bls12_381::miller_loop(a.0.into_affine(), b.0.into_affine()).0)
+    }
+
+    fn final_exp(f: &ArkGT) -> ArkGT {
+        ArkGT(
+            Bls12_381::final_exponentiation(MillerLoopOutput(f.0))
+                .map(|gt| gt.0)
+                .unwrap_or_default(),
+        )
+    }
+
+    fn multi_miller_loop(a: &[ArkG1], b: &[ArkG2]) -> ArkGT {
+        ArkGT(
+            Bls12_381::multi_miller_loop(
+                a.iter().map(|a| a.0.into_affine()),
+                b.iter().map(|b| b.0.into_affine()),
+            )
+            .0,
+        )
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub struct ArkG2(pub Projective<g2::Config>);
@@ -470,6 +526,23 @@ impl G2 for ArkG2 {
             })
     }
 
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, String> {
+        bytes
+            .try_into()
+            .map_err(|_| {
+                format!(
+                    "Invalid byte length. Expected {}, got {}",
+                    BYTES_PER_G2,
+                    bytes.len()
+                )
+            })
+            .and_then(|bytes: &[u8; BYTES_PER_G2]| {
+                G2Affine::deserialize_compressed_unchecked(bytes.as_slice())
+                    .map(|affine| Self(affine.into_group()))
+                    .map_err(|err| format!("Failed to deserialize G2: {err}"))
+            })
+    }
+
     fn to_bytes(&self) -> [u8; 96] {
         let mut buff = [0u8; BYTES_PER_G2];
         self.0.serialize_compressed(&mut &mut buff[..]).unwrap();
@@ -480,6 +553,10 @@ impl G2 for ArkG2 {
         Self(self.0 + b.0)
     }
 
+    fn is_valid(&self) -> bool {
+        true
+    }
+
     fn dbl(&self) -> Self {
         Self(self.0.double())
     }
@@ -499,6 +576,103 @@ impl G2Mul<ArkFr> for ArkG2 {
     }
 }
 
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct ArkGT(pub Fq12);
+
+impl ArkGT {
+    fn coeffs(&self) -> [Fq; 12] {
+        let f = &self.0;
+        [
+            f.c0.c0.c0, f.c0.c0.c1, f.c0.c1.c0, f.c0.c1.c1, f.c0.c2.c0, f.c0.c2.c1, f.c1.c0.c0,
+            f.c1.c0.c1, f.c1.c1.c0, f.c1.c1.c1, f.c1.c2.c0, f.c1.c2.c1,
+        ]
+    }
+
+    fn from_coeffs(c: &[Fq; 12]) -> Self {
+        Self(Fq12::new(
+            Fq6::new(
+                Fq2::new(c[0], c[1]),
+                Fq2::new(c[2], c[3]),
+                Fq2::new(c[4], c[5]),
+            ),
+            Fq6::new(
+                Fq2::new(c[6], c[7]),
+                Fq2::new(c[8], c[9]),
+                Fq2::new(c[10], c[11]),
+            ),
+        ))
+    }
+}
+
+impl GT for ArkGT {
+    fn one() -> Self {
+        Self(Fq12::one())
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        bytes
+            .try_into()
+            .map_err(|_| {
+                format!(
+                    "Invalid byte length. Expected {}, got {}",
+                    BYTES_PER_GT,
+                    bytes.len()
+                )
+            })
+            .and_then(|bytes: &[u8; BYTES_PER_GT]| {
+                let mut coeffs = [Fq::zero(); 12];
+                for (fq, chunk) in coeffs.iter_mut().zip(bytes.chunks_exact(48)) {
+                    let storage: [u64; 6] = core::array::from_fn(|i| {
+                        bytes_be_to_uint64(&chunk[(5 - i) * 8..(6 - i) * 8])
+                    });
+                    *fq = Fq::from_bigint(BigInteger384::new(storage))
+                        .ok_or_else(|| "Invalid field element".to_string())?;
+                }
+
+                let gt = Self::from_coeffs(&coeffs);
+                if !gt.0.pow(Fr::characteristic()).is_one() {
+                    return Err("Element is not in the target group".to_string());
+                }
+
+                Ok(gt)
+            })
+    }
+
+    fn to_bytes(&self) -> [u8; 576] {
+        let mut out = [0u8; BYTES_PER_GT];
+        for (fq, chunk) in self.coeffs().iter().zip(out.chunks_exact_mut(48)) {
+            chunk.copy_from_slice(&fq.into_bigint().to_bytes_be());
+        }
+        out
+    }
+
+    fn is_one(&self) -> bool {
+        self.0.is_one()
+    }
+
+    fn mul(&self, b: &Self) -> Self {
+        Self(self.0 * b.0)
+    }
+
+    fn sqr(&self) -> Self {
+        Self(self.0.square())
+    }
+
+    fn inverse(&self) -> Self {
+        Self(self.0.inverse().unwrap_or_default())
+    }
+
+    fn equals(&self, b: &Self) -> bool {
+        self.0 == b.0
+    }
+}
+
+impl GTPow<ArkFr> for ArkGT {
+    fn pow(&self, b: &ArkFr) -> Self {
+        Self(self.0.pow(b.to_u64_arr()))
+    }
+}
+
 impl Poly<ArkFr> for PolyData {
     fn new(size: usize) -> PolyData {
         Self {
@@ -681,9 +855,13 @@ impl
         }
 
         let field_elements_per_blob = g1_monomial.len();
-        let field_elements_per_ext_blob = field_elements_per_blob * 2;
+        // The extended blob spans the FFT domain, see `KZGSettings::get_extension_factor`.
+        // Settings with a narrower domain are not used for cells.
+        let field_elements_per_ext_blob = fft_settings
+            .get_max_width()
+            .max(field_elements_per_blob * 2);
 
-        let n = field_elements_per_ext_blob / 2;
+        let n = field_elements_per_blob;
         let k = n / cell_size;
         let k2 = 2 * k;
 
@@ -1165,6 +1343,12 @@ impl G1AffineTrait<ArkG1, ArkFp> for ArkG1Affine {
             .map(|aff| ArkG1Affine { aff })
             .map_err(|err| err.to_string())
     }
+
+    fn from_bytes_uncompressed_unchecked(bytes: [u8; 96]) -> Result<Self, String> {
+        G1Affine::deserialize_uncompressed_unchecked(&bytes[..])
+            .map(|aff| ArkG1Affine { aff })
+            .map_err(|err| err.to_string())
+    }
 }
 
 #[derive(Debug)]
@@ -20,4 +20,14 @@ mod recover_tests {
     fn more_than_half_missing_() {
         more_than_half_missing::<Fr, FFTSettings, PolyData, PolyData>();
     }
+
+    #[test]
+    fn decode_with_errors_() {
+        decode_with_errors::<Fr, FFTSettings>();
+    }
+
+    #[test]
+    fn erasure_coder_() {
+        erasure_coder::<Fr, FFTSettings, PolyData>();
+    }
 }
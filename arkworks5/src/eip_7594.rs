@@ -10,6 +10,7 @@ use crate::kzg_types::ArkG1;
 use crate::kzg_types::ArkG1Affine;
 use crate::kzg_types::ArkG1ProjAddAffine;
 use crate::kzg_types::ArkG2;
+use crate::kzg_types::ArkGT;
 use crate::utils::PolyData;
 
 pub struct ArkBackend;
@@ -21,6 +22,7 @@ impl EcBackend for ArkBackend {
     type G1ProjAddAffine = ArkG1ProjAddAffine;
     type G1 = ArkG1;
     type G2 = ArkG2;
+    type GT = ArkGT;
     type Poly = PolyData;
     type FFTSettings = FFTSettings;
     type KZGSettings = KZGSettings;
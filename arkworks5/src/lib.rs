@@ -9,5 +9,7 @@ pub mod kzg_proofs;
 pub mod kzg_types;
 pub mod poly;
 pub mod recover;
+#[cfg(feature = "serde")]
+mod serialization;
 pub mod utils;
 pub mod zero_poly;
@@ -5,7 +5,7 @@ mod tests {
     use rust_kzg_arkworks5::fft_g1::g1_linear_combination;
     use rust_kzg_arkworks5::kzg_proofs::pairings_verify;
     use rust_kzg_arkworks5::kzg_types::{
-        ArkFp, ArkFr, ArkG1, ArkG1Affine, ArkG1ProjAddAffine, ArkG2,
+        ArkFp, ArkFr, ArkG1, ArkG1Affine, ArkG1ProjAddAffine, ArkG2, ArkGT,
     };
 
     #[test]
@@ -104,6 +104,11 @@ mod tests {
         g1_identity_is_identity::<ArkG1>();
     }
 
+    #[test]
+    pub fn g1_from_bytes_batch_works_() {
+        g1_from_bytes_batch_works::<ArkFr, ArkG1>();
+    }
+
     #[test]
     pub fn g1_make_linear_combination_() {
         g1_make_linear_combination::<ArkFr, ArkG1, ArkFp, ArkG1Affine, ArkG1ProjAddAffine>(
@@ -125,6 +130,11 @@ mod tests {
         );
     }
 
+    #[test]
+    fn g1_short_linear_combination_() {
+        g1_short_linear_combination::<ArkFr, ArkG1, ArkFp, ArkG1Affine, ArkG1ProjAddAffine>()
+    }
+
     #[test]
     fn g1_small_linear_combination_() {
         g1_small_linear_combination::<ArkFr, ArkG1, ArkFp, ArkG1Affine, ArkG1ProjAddAffine>(
@@ -141,4 +151,23 @@ mod tests {
     pub fn fr_is_null_works_() {
         fr_is_null_works::<ArkFr>();
     }
+    #[test]
+    pub fn gt_pairing_is_bilinear_() {
+        gt_pairing_is_bilinear::<ArkFr, ArkG1, ArkG2, ArkGT>();
+    }
+
+    #[test]
+    pub fn gt_miller_loop_and_final_exp_work_() {
+        gt_miller_loop_and_final_exp_work::<ArkFr, ArkG1, ArkG2, ArkGT>();
+    }
+
+    #[test]
+    pub fn gt_arithmetic_works_() {
+        gt_arithmetic_works::<ArkFr, ArkG1, ArkG2, ArkGT>();
+    }
+
+    #[test]
+    pub fn gt_bytes_roundtrip_() {
+        gt_bytes_roundtrip::<ArkG1, ArkG2, ArkGT>();
+    }
 }
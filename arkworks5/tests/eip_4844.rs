@@ -2,15 +2,16 @@
 mod tests {
     use kzg::eip_4844::{
         blob_to_kzg_commitment_rust, blob_to_polynomial, bytes_to_blob,
-        compute_blob_kzg_proof_rust, compute_challenge_rust, compute_kzg_proof_rust,
-        compute_powers, evaluate_polynomial_in_evaluation_form, verify_blob_kzg_proof_batch_rust,
-        verify_blob_kzg_proof_rust, verify_kzg_proof_rust,
+        compute_blob_kzg_proof_rust, compute_challenge_rust, compute_kzg_multi_proof_rust,
+        compute_kzg_proof_rust, compute_powers, evaluate_polynomial_in_evaluation_form,
+        verify_blob_kzg_proof_batch_rust, verify_blob_kzg_proof_rust, verify_kzg_multi_proof_rust,
+        verify_kzg_proof_batch_rust, verify_kzg_proof_rust,
     };
     use kzg::Fr;
     use kzg_bench::tests::eip_4844::{
         blob_to_kzg_commitment_test, bytes_to_bls_field_test,
         compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test,
-        compute_and_verify_blob_kzg_proof_test,
+        compute_and_verify_blob_kzg_proof_test, compute_and_verify_kzg_multi_proof_test,
         compute_and_verify_kzg_proof_fails_with_incorrect_proof_test,
         compute_and_verify_kzg_proof_round_trip_test,
         compute_and_verify_kzg_proof_within_domain_test, compute_kzg_proof_test,
@@ -19,6 +20,7 @@ mod tests {
         test_vectors_compute_kzg_proof, test_vectors_verify_blob_kzg_proof,
         test_vectors_verify_blob_kzg_proof_batch, test_vectors_verify_kzg_proof,
         verify_kzg_proof_batch_fails_with_incorrect_proof_test, verify_kzg_proof_batch_test,
+        verify_kzg_proof_openings_batch_test,
     };
     use rust_kzg_arkworks5::consts::SCALE2_ROOT_OF_UNITY;
     use rust_kzg_arkworks5::eip_4844::load_trusted_setup_filename_rust;
@@ -229,6 +231,49 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn verify_kzg_proof_openings_batch_test_() {
+        verify_kzg_proof_openings_batch_test::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+            ArkG1ProjAddAffine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_kzg_proof_rust,
+            &verify_kzg_proof_batch_rust,
+        );
+    }
+
+    #[test]
+    pub fn compute_and_verify_kzg_multi_proof_test_() {
+        compute_and_verify_kzg_multi_proof_test::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+            ArkG1ProjAddAffine,
+        >(
+            &load_trusted_setup_filename_rust,
+            &blob_to_kzg_commitment_rust,
+            &bytes_to_blob,
+            &compute_kzg_proof_rust,
+            &compute_kzg_multi_proof_rust,
+            &verify_kzg_multi_proof_rust,
+        );
+    }
+
     #[test]
     pub fn test_vectors_blob_to_kzg_commitment_() {
         test_vectors_blob_to_kzg_commitment::<
@@ -2,12 +2,20 @@
 mod tests {
     use kzg::eip_4844::bytes_to_blob;
     use kzg_bench::tests::eip_7594::{
+        all_blob_point_proofs_test, batch_verifier_reports_failed_items_test,
+        compute_cell_kzg_proofs_test, custom_transcript_test, das_2d_test,
+        das_extension_factor_and_cell_size_test, das_sampling_test, data_column_sidecar_test,
+        randomized_batch_verification_test, recover_cells_and_kzg_proofs_batch_test,
+        recover_cells_correcting_errors_test, recover_cells_without_proofs_test,
         test_vectors_compute_cells, test_vectors_compute_cells_and_kzg_proofs,
+        test_vectors_compute_columns_for_custody_group,
         test_vectors_compute_verify_cell_kzg_proof_batch_challenge,
-        test_vectors_recover_cells_and_kzg_proofs, test_vectors_verify_cell_kzg_proof_batch,
+        test_vectors_get_custody_groups, test_vectors_recover_cells_and_kzg_proofs,
+        test_vectors_verify_cell_kzg_proof_batch, trusted_point_validation_test,
     };
     use rust_kzg_arkworks5::eip_4844::load_trusted_setup_filename_rust;
     use rust_kzg_arkworks5::eip_7594::ArkBackend;
+    use rust_kzg_arkworks5::kzg_proofs::generate_trusted_setup;
 
     #[test]
     pub fn test_vectors_compute_cells_() {
@@ -36,4 +44,79 @@ mod tests {
     pub fn test_vectors_compute_verify_cell_kzg_proof_batch_challenge_() {
         test_vectors_compute_verify_cell_kzg_proof_batch_challenge::<ArkBackend>();
     }
+
+    #[test]
+    pub fn test_vectors_get_custody_groups_() {
+        test_vectors_get_custody_groups();
+    }
+
+    #[test]
+    pub fn test_vectors_compute_columns_for_custody_group_() {
+        test_vectors_compute_columns_for_custody_group();
+    }
+
+    #[test]
+    pub fn batch_verifier_reports_failed_items_() {
+        batch_verifier_reports_failed_items_test::<ArkBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn data_column_sidecar_() {
+        data_column_sidecar_test::<ArkBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn trusted_point_validation_() {
+        trusted_point_validation_test::<ArkBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn custom_transcript_() {
+        custom_transcript_test::<ArkBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn randomized_batch_verification_() {
+        randomized_batch_verification_test::<ArkBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn all_blob_point_proofs_() {
+        all_blob_point_proofs_test::<ArkBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn compute_cell_kzg_proofs_() {
+        compute_cell_kzg_proofs_test::<ArkBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn recover_cells_without_proofs_() {
+        recover_cells_without_proofs_test::<ArkBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn das_sampling_() {
+        das_sampling_test::<ArkBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn recover_cells_correcting_errors_() {
+        recover_cells_correcting_errors_test::<ArkBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn recover_cells_and_kzg_proofs_batch_() {
+        recover_cells_and_kzg_proofs_batch_test::<ArkBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn das_extension_factor_and_cell_size_() {
+        das_extension_factor_and_cell_size_test::<ArkBackend>(&generate_trusted_setup);
+    }
+
+    #[test]
+    pub fn das_2d_() {
+        das_2d_test::<ArkBackend>(&generate_trusted_setup);
+    }
 }
@@ -8,6 +8,7 @@ use crate::types::g1::FsG1;
 use crate::types::g1::FsG1Affine;
 use crate::types::g1::FsG1ProjAddAffine;
 use crate::types::g2::FsG2;
+use crate::types::gt::FsGT;
 use crate::types::kzg_settings::FsKZGSettings;
 use crate::types::poly::FsPoly;
 
@@ -21,6 +22,7 @@ impl EcBackend for BlstBackend {
     type G1Affine = FsG1Affine;
     type G1 = FsG1;
     type G2 = FsG2;
+    type GT = FsGT;
     type Poly = FsPoly;
     type FFTSettings = FsFFTSettings;
     type KZGSettings = FsKZGSettings;
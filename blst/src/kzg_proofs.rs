@@ -1,20 +1,26 @@
 extern crate alloc;
 
+use alloc::vec;
+
 use crate::types::fp::FsFp;
 use crate::types::g1::FsG1;
 use crate::types::{fr::FsFr, g1::FsG1Affine};
 
 use crate::types::g1::FsG1ProjAddAffine;
 
-use kzg::msm::{msm_impls::msm, precompute::PrecomputationTable};
+use kzg::msm::{
+    msm_impls::{msm, msm_nbits},
+    precompute::PrecomputationTable,
+};
 
 use crate::types::g2::FsG2;
+use crate::types::gt::FsGT;
 use blst::{
-    blst_fp12_is_one, blst_p1_affine, blst_p1_cneg, blst_p1_to_affine, blst_p2_affine,
-    blst_p2_to_affine, Pairing,
+    blst_fp12, blst_fp12_is_one, blst_p1_affine, blst_p1_cneg, blst_p1_to_affine, blst_p2_affine,
+    blst_p2_to_affine,
 };
 
-use kzg::PairingVerify;
+use kzg::{Pairing, PairingVerify, GT};
 
 impl PairingVerify<FsG1, FsG2> for FsG1 {
     fn verify(a1: &FsG1, a2: &FsG2, b1: &FsG1, b2: &FsG2) -> bool {
@@ -22,6 +28,39 @@ impl PairingVerify<FsG1, FsG2> for FsG1 {
     }
 }
 
+impl Pairing<FsG1, FsG2, FsGT> for FsG1 {
+    fn miller_loop(a: &FsG1, b: &FsG2) -> FsGT {
+        let mut aa = blst_p1_affine::default();
+        let mut bb = blst_p2_affine::default();
+        unsafe {
+            blst_p1_to_affine(&mut aa, &a.0);
+            blst_p2_to_affine(&mut bb, &b.0);
+        }
+        FsGT(blst_fp12::miller_loop(&bb, &aa))
+    }
+
+    fn final_exp(f: &FsGT) -> FsGT {
+        FsGT(f.0.final_exp())
+    }
+
+    fn multi_miller_loop(a: &[FsG1], b: &[FsG2]) -> FsGT {
+        let len = a.len().min(b.len());
+        if len == 0 {
+            return FsGT::one();
+        }
+
+        let mut aa = vec![blst_p1_affine::default(); len];
+        let mut bb = vec![blst_p2_affine::default(); len];
+        for i in 0..len {
+            unsafe {
+                blst_p1_to_affine(&mut aa[i], &a[i].0);
+                blst_p2_to_affine(&mut bb[i], &b[i].0);
+            }
+        }
+        FsGT(blst_fp12::miller_loop_n(&bb, &aa))
+    }
+}
+
 pub fn g1_linear_combination(
     out: &mut FsG1,
     points: &[FsG1],
@@ -71,6 +110,22 @@ pub fn g1_linear_combination(
     }
 }
 
+#[cfg(not(feature = "sppark"))]
+pub fn g1_linear_combination_short(
+    out: &mut FsG1,
+    points: &[FsG1],
+    scalars: &[FsFr],
+    len: usize,
+    scalar_bits: usize,
+) {
+    *out = msm_nbits::<FsG1, FsFp, FsG1Affine, FsG1ProjAddAffine, FsFr>(
+        points,
+        scalars,
+        len,
+        scalar_bits,
+    );
+}
+
 pub fn pairings_verify(a1: &FsG1, a2: &FsG2, b1: &FsG1, b2: &FsG2) -> bool {
     let mut aa1 = blst_p1_affine::default();
     let mut bb1 = blst_p1_affine::default();
@@ -90,7 +145,7 @@ pub fn pairings_verify(a1: &FsG1, a2: &FsG2, b1: &FsG1, b2: &FsG2) -> bool {
         blst_p2_to_affine(&mut bb2, &b2.0);
 
         let dst = [0u8; 3];
-        let mut pairing_blst = Pairing::new(false, &dst);
+        let mut pairing_blst = blst::Pairing::new(false, &dst);
         pairing_blst.raw_aggregate(&aa2, &aa1);
         pairing_blst.raw_aggregate(&bb2, &bb1);
         let gt_point = pairing_blst.as_fp12().final_exp();
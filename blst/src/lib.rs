@@ -9,6 +9,8 @@ pub mod fft_g1;
 pub mod fk20_proofs;
 pub mod kzg_proofs;
 pub mod recovery;
+#[cfg(feature = "serde")]
+mod serialization;
 pub mod types;
 pub mod utils;
 pub mod zero_poly;
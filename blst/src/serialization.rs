@@ -0,0 +1,42 @@
+//! Serde implementations of the field element and point types (feature `serde`), see
+//! [`kzg::serialization`] for the encoding.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::types::{fr::FsFr, g1::FsG1, g2::FsG2};
+
+impl Serialize for FsFr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        kzg::serialization::serialize_fr(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FsFr {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        kzg::serialization::deserialize_fr(deserializer)
+    }
+}
+
+impl Serialize for FsG1 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        kzg::serialization::serialize_g1(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FsG1 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        kzg::serialization::deserialize_g1(deserializer)
+    }
+}
+
+impl Serialize for FsG2 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        kzg::serialization::serialize_g2(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FsG2 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        kzg::serialization::deserialize_g2(deserializer)
+    }
+}
@@ -21,6 +21,8 @@ use kzg::{
 
 use crate::consts::{G1_GENERATOR, G1_IDENTITY, G1_NEGATIVE_GENERATOR};
 use crate::kzg_proofs::g1_linear_combination;
+#[cfg(not(feature = "sppark"))]
+use crate::kzg_proofs::g1_linear_combination_short;
 use crate::types::fr::FsFr;
 
 use super::fp::FsFp;
@@ -285,6 +287,13 @@ impl G1LinComb<FsFr, FsFp, FsG1Affine, FsG1ProjAddAffine> for FsG1 {
         g1_linear_combination(&mut out, points, scalars, len, precomputation);
         out
     }
+
+    #[cfg(not(feature = "sppark"))]
+    fn g1_lincomb_short(points: &[Self], scalars: &[FsFr], len: usize, scalar_bits: usize) -> Self {
+        let mut out = FsG1::default();
+        g1_linear_combination_short(&mut out, points, scalars, len, scalar_bits);
+        out
+    }
 }
 
 #[repr(C)]
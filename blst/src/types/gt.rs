@@ -0,0 +1,139 @@
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+
+use blst::{
+    blst_bendian_from_fp, blst_fp, blst_fp12, blst_fp12_inverse, blst_fp12_is_equal,
+    blst_fp12_is_one, blst_fp12_mul, blst_fp12_sqr, blst_fp_from_bendian, blst_scalar,
+    blst_scalar_from_fr,
+};
+use kzg::eip_4844::BYTES_PER_GT;
+use kzg::{GTPow, GT};
+
+use crate::types::fr::FsFr;
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct FsGT(pub blst_fp12);
+
+impl FsGT {
+    fn coeffs(&self) -> impl Iterator<Item = &blst_fp> {
+        self.0
+            .fp6
+            .iter()
+            .flat_map(|fp6| fp6.fp2.iter())
+            .flat_map(|fp2| fp2.fp.iter())
+    }
+
+    fn coeffs_mut(&mut self) -> impl Iterator<Item = &mut blst_fp> {
+        self.0
+            .fp6
+            .iter_mut()
+            .flat_map(|fp6| fp6.fp2.iter_mut())
+            .flat_map(|fp2| fp2.fp.iter_mut())
+    }
+}
+
+impl GT for FsGT {
+    fn one() -> Self {
+        Self(blst_fp12::default())
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        bytes
+            .try_into()
+            .map_err(|_| {
+                format!(
+                    "Invalid byte length. Expected {}, got {}",
+                    BYTES_PER_GT,
+                    bytes.len()
+                )
+            })
+            .and_then(|bytes: &[u8; BYTES_PER_GT]| {
+                let mut gt = Self::one();
+                for (fp, chunk) in gt.coeffs_mut().zip(bytes.chunks_exact(48)) {
+                    let mut canonical = [0u8; 48];
+                    unsafe {
+                        // blst reduces the input modulo p, so check that encoding it back gives
+                        // the same bytes
+                        blst_fp_from_bendian(fp, chunk.as_ptr());
+                        blst_bendian_from_fp(canonical.as_mut_ptr(), fp);
+                    }
+                    if canonical != chunk {
+                        return Err("Invalid field element".to_string());
+                    }
+                }
+
+                if !gt.0.in_group() {
+                    return Err("Element is not in the target group".to_string());
+                }
+
+                Ok(gt)
+            })
+    }
+
+    fn to_bytes(&self) -> [u8; 576] {
+        let mut out = [0u8; BYTES_PER_GT];
+        for (fp, chunk) in self.coeffs().zip(out.chunks_exact_mut(48)) {
+            unsafe {
+                blst_bendian_from_fp(chunk.as_mut_ptr(), fp);
+            }
+        }
+        out
+    }
+
+    fn is_one(&self) -> bool {
+        unsafe { blst_fp12_is_one(&self.0) }
+    }
+
+    fn mul(&self, b: &Self) -> Self {
+        let mut result = blst_fp12::default();
+        unsafe {
+            blst_fp12_mul(&mut result, &self.0, &b.0);
+        }
+        Self(result)
+    }
+
+    fn sqr(&self) -> Self {
+        let mut result = blst_fp12::default();
+        unsafe {
+            blst_fp12_sqr(&mut result, &self.0);
+        }
+        Self(result)
+    }
+
+    fn inverse(&self) -> Self {
+        let mut result = blst_fp12::default();
+        unsafe {
+            blst_fp12_inverse(&mut result, &self.0);
+        }
+        Self(result)
+    }
+
+    fn equals(&self, b: &Self) -> bool {
+        unsafe { blst_fp12_is_equal(&self.0, &b.0) }
+    }
+}
+
+impl GTPow<FsFr> for FsGT {
+    fn pow(&self, b: &FsFr) -> Self {
+        let mut scalar = blst_scalar::default();
+        unsafe {
+            blst_scalar_from_fr(&mut scalar, &b.0);
+        }
+
+        // Square and multiply, starting from the most significant bit of the little-endian scalar
+        let mut result = Self::one();
+        for byte in scalar.b.iter().rev() {
+            for i in (0..8).rev() {
+                result = result.sqr();
+                if (byte >> i) & 1 == 1 {
+                    result = result.mul(self);
+                }
+            }
+        }
+        result
+    }
+}
@@ -75,9 +75,13 @@ impl KZGSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsFp, FsG1Affine, FsG1
         }
 
         let field_elements_per_blob = g1_monomial.len();
-        let field_elements_per_ext_blob = field_elements_per_blob * 2;
+        // The extended blob spans the FFT domain, see `KZGSettings::get_extension_factor`.
+        // Settings with a narrower domain are not used for cells.
+        let field_elements_per_ext_blob = fft_settings
+            .get_max_width()
+            .max(field_elements_per_blob * 2);
 
-        let n = field_elements_per_ext_blob / 2;
+        let n = field_elements_per_blob;
         let k = n / cell_size;
         let k2 = 2 * k;
 
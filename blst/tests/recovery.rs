@@ -26,4 +26,14 @@ mod tests {
     fn more_than_half_missing_() {
         more_than_half_missing::<FsFr, FsFFTSettings, FsPoly, FsPoly>();
     }
+
+    #[test]
+    fn decode_with_errors_() {
+        decode_with_errors::<FsFr, FsFFTSettings>();
+    }
+
+    #[test]
+    fn erasure_coder_() {
+        erasure_coder::<FsFr, FsFFTSettings, FsPoly>();
+    }
 }
@@ -0,0 +1,25 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use kzg_bench::tests::serialization::*;
+    use rust_kzg_blst::types::{fr::FsFr, g1::FsG1, g2::FsG2};
+
+    #[test]
+    pub fn fr_serde_works_() {
+        fr_serde_works::<FsFr>();
+    }
+
+    #[test]
+    pub fn g1_serde_works_() {
+        g1_serde_works::<FsFr, FsG1>();
+    }
+
+    #[test]
+    pub fn g2_serde_works_() {
+        g2_serde_works::<FsFr, FsG2>();
+    }
+
+    #[test]
+    pub fn byte_containers_serde_works_() {
+        byte_containers_serde_works::<FsFr, FsG1>();
+    }
+}
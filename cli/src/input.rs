@@ -0,0 +1,184 @@
+//! Reading of command line values: inline hex strings, or files in hex, binary or JSON format.
+
+use std::fs;
+
+use clap::ValueEnum;
+use serde_json::Value as Json;
+
+/// Encoding of input files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// JSON if the file starts with `[` or `"`, hex if it only contains hex digits, separators
+    /// and `0x` prefixes, binary otherwise.
+    Auto,
+    /// Hex strings (with or without `0x` prefix), separated by whitespace or commas.
+    Hex,
+    /// Raw bytes.
+    Binary,
+    /// A hex string, or an array of hex strings. Cell indices are an array of numbers.
+    Json,
+}
+
+pub struct Reader {
+    format: Format,
+}
+
+impl Reader {
+    pub fn new(format: Format) -> Self {
+        Self { format }
+    }
+
+    /// Reads exactly one `N`-byte value named `name`. `source` is either an inline `0x`-prefixed
+    /// hex string or a file path.
+    pub fn value<const N: usize>(&self, name: &str, source: &str) -> Result<[u8; N], String> {
+        let mut values = self.values::<N>(name, source)?;
+        if values.len() != 1 {
+            return Err(format!(
+                "Expected a single {name} of {N} bytes, got {}",
+                values.len()
+            ));
+        }
+
+        Ok(values.remove(0))
+    }
+
+    /// Reads a list of `N`-byte values named `name`. `source` is either inline `0x`-prefixed hex
+    /// strings separated by commas, or a file path.
+    pub fn values<const N: usize>(&self, name: &str, source: &str) -> Result<Vec<[u8; N]>, String> {
+        if source.starts_with("0x") {
+            return parse_hex(source).and_then(|values| split::<N>(name, values));
+        }
+
+        let contents = read(source)?;
+        let values = match self.file_format(&contents) {
+            Format::Binary => vec![contents],
+            Format::Json => parse_json_bytes(name, &contents)?,
+            _ => parse_hex(as_text(source, &contents)?)?,
+        };
+
+        split::<N>(name, values)
+    }
+
+    /// Reads cell indices. `source` is either an inline list of numbers separated by commas, or a
+    /// file path.
+    pub fn indices(&self, name: &str, source: &str) -> Result<Vec<usize>, String> {
+        if let Ok(indices) = parse_numbers(source) {
+            return Ok(indices);
+        }
+
+        let contents = read(source)?;
+        let text = as_text(source, &contents)?;
+        if self.file_format(&contents) == Format::Json {
+            serde_json::from_str::<Vec<usize>>(text)
+                .map_err(|err| format!("Invalid {name} in {source}: {err}"))
+        } else {
+            parse_numbers(text).map_err(|err| format!("Invalid {name} in {source}: {err}"))
+        }
+    }
+
+    fn file_format(&self, contents: &[u8]) -> Format {
+        if self.format != Format::Auto {
+            return self.format;
+        }
+
+        let Ok(text) = std::str::from_utf8(contents) else {
+            return Format::Binary;
+        };
+        let text = text.trim_start();
+        if text.starts_with('[') || text.starts_with('"') {
+            Format::Json
+        } else if !text.is_empty()
+            && text
+                .chars()
+                .all(|c| c.is_ascii_hexdigit() || c.is_whitespace() || c == ',' || c == 'x')
+        {
+            Format::Hex
+        } else {
+            Format::Binary
+        }
+    }
+}
+
+fn read(path: &str) -> Result<Vec<u8>, String> {
+    fs::read(path).map_err(|err| format!("Unable to read {path}: {err}"))
+}
+
+fn as_text<'a>(path: &str, contents: &'a [u8]) -> Result<&'a str, String> {
+    std::str::from_utf8(contents).map_err(|_| format!("{path} is not a text file"))
+}
+
+/// Decodes hex strings separated by whitespace or commas.
+fn parse_hex(text: &str) -> Result<Vec<Vec<u8>>, String> {
+    text.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .map(decode_hex)
+        .collect()
+}
+
+fn decode_hex(token: &str) -> Result<Vec<u8>, String> {
+    hex::decode(token.strip_prefix("0x").unwrap_or(token))
+        .map_err(|err| format!("Invalid hex string \"{token}\": {err}"))
+}
+
+fn parse_json_bytes(name: &str, contents: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+    let json: Json = serde_json::from_slice(contents)
+        .map_err(|err| format!("Invalid JSON for {name}: {err}"))?;
+
+    match json {
+        Json::String(value) => Ok(vec![decode_hex(&value)?]),
+        Json::Array(values) => values
+            .iter()
+            .map(|value| {
+                value
+                    .as_str()
+                    .ok_or_else(|| format!("Expected {name} as hex strings, got {value}"))
+                    .and_then(decode_hex)
+            })
+            .collect(),
+        json => Err(format!(
+            "Expected {name} as a hex string or an array of hex strings, got {json}"
+        )),
+    }
+}
+
+fn parse_numbers(text: &str) -> Result<Vec<usize>, String> {
+    text.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            token
+                .parse()
+                .map_err(|_| format!("Invalid number \"{token}\""))
+        })
+        .collect()
+}
+
+/// Splits decoded values into `N`-byte values. A single value (e.g. binary file) may hold several
+/// concatenated values; otherwise every value must be exactly `N` bytes.
+fn split<const N: usize>(name: &str, values: Vec<Vec<u8>>) -> Result<Vec<[u8; N]>, String> {
+    let bytes = match <[Vec<u8>; 1]>::try_from(values) {
+        Ok([bytes]) => bytes,
+        Err(values) => {
+            return values
+                .into_iter()
+                .map(|value| {
+                    let len = value.len();
+                    <[u8; N]>::try_from(value).map_err(|_| {
+                        format!("Invalid {name} length, expected {N} bytes, got {len}")
+                    })
+                })
+                .collect();
+        }
+    };
+
+    if bytes.len() % N != 0 {
+        return Err(format!(
+            "Invalid {name} length, expected a multiple of {N} bytes, got {}",
+            bytes.len()
+        ));
+    }
+
+    Ok(bytes
+        .chunks_exact(N)
+        .map(|chunk| chunk.try_into().unwrap())
+        .collect())
+}
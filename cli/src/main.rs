@@ -0,0 +1,328 @@
+//! Command line tool for the byte-level EIP-4844 and EIP-7594 KZG operations.
+//!
+//! Values are read from files (hex, binary or JSON) or given inline as `0x`-prefixed hex, and
+//! results are printed in the YAML format of the consensus spec test vectors.
+
+mod input;
+mod output;
+
+use std::{fs, path::PathBuf, process::ExitCode};
+
+use clap::{Parser, Subcommand};
+use kzg::eth::{
+    BYTES_PER_BLOB, BYTES_PER_CELL, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
+};
+use rust_kzg_dyn::{Backend, DynKzg, KzgApi};
+
+use crate::{
+    input::{Format, Reader},
+    output::{Report, Value},
+};
+
+/// Compute and verify EIP-4844 and EIP-7594 KZG commitments and proofs.
+///
+/// Every value is a file path, or inline `0x`-prefixed hex (comma-separated for lists).
+#[derive(Parser)]
+#[command(name = "kzg-cli", version)]
+struct Cli {
+    /// Backend to run the operation with [default: first enabled backend]
+    #[arg(short, long, global = true)]
+    backend: Option<Backend>,
+
+    /// Trusted setup file, in the text format of c-kzg-4844
+    #[arg(short, long, global = true, env = "TRUSTED_SETUP")]
+    trusted_setup: Option<PathBuf>,
+
+    /// Encoding of input files
+    #[arg(short, long, global = true, value_enum, default_value_t = Format::Auto)]
+    format: Format,
+
+    /// Print only the output, without echoing the inputs
+    #[arg(long, global = true)]
+    output_only: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Compute the commitment to a blob (`blob_to_kzg_commitment`)
+    Commit {
+        #[arg(long)]
+        blob: String,
+    },
+    /// Compute the proof of the blob evaluation at `z` (`compute_kzg_proof`), or the blob proof
+    /// for its commitment (`compute_blob_kzg_proof`); the commitment is computed if omitted
+    Prove {
+        #[arg(long)]
+        blob: String,
+        #[arg(long)]
+        z: Option<String>,
+        #[arg(long, conflicts_with = "z")]
+        commitment: Option<String>,
+    },
+    /// Verify the proof of evaluation `y` at `z` (`verify_kzg_proof`), or the proof of a blob
+    /// (`verify_blob_kzg_proof`)
+    Verify {
+        #[arg(long)]
+        commitment: String,
+        #[arg(long)]
+        proof: String,
+        #[arg(long, required_unless_present = "z", conflicts_with = "z")]
+        blob: Option<String>,
+        #[arg(long, requires = "y")]
+        z: Option<String>,
+        #[arg(long, requires = "z")]
+        y: Option<String>,
+    },
+    /// Verify the proofs of several blobs at once (`verify_blob_kzg_proof_batch`)
+    BatchVerify {
+        #[arg(long)]
+        blobs: String,
+        #[arg(long)]
+        commitments: String,
+        #[arg(long)]
+        proofs: String,
+    },
+    /// Compute the cells of the extended blob (`compute_cells`), and optionally their proofs
+    /// (`compute_cells_and_kzg_proofs`)
+    ComputeCells {
+        #[arg(long)]
+        blob: String,
+        #[arg(long)]
+        with_proofs: bool,
+    },
+    /// Recover all cells and proofs from at least half of the cells
+    /// (`recover_cells_and_kzg_proofs`)
+    RecoverCells {
+        /// Cell indices, comma-separated or a file
+        #[arg(long)]
+        cell_indices: String,
+        #[arg(long)]
+        cells: String,
+    },
+    /// Verify cell proofs, one commitment per cell (`verify_cell_kzg_proof_batch`)
+    VerifyCells {
+        #[arg(long)]
+        commitments: String,
+        /// Cell indices, comma-separated or a file
+        #[arg(long)]
+        cell_indices: String,
+        #[arg(long)]
+        cells: String,
+        #[arg(long)]
+        proofs: String,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(&cli) {
+        Ok(report) => {
+            print!("{report}");
+            if report.output.is_some() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn load_settings(cli: &Cli) -> Result<DynKzg, String> {
+    let backend = cli.backend.unwrap_or(Backend::ENABLED[0]);
+    let path = cli.trusted_setup.as_ref().ok_or_else(|| {
+        "No trusted setup, pass --trusted-setup or set TRUSTED_SETUP environment variable"
+            .to_string()
+    })?;
+    let contents = fs::read_to_string(path)
+        .map_err(|err| format!("Unable to read {}: {err}", path.display()))?;
+
+    DynKzg::load_trusted_setup_string(backend, &contents)
+}
+
+/// Operation on the loaded trusted setup. Inputs derived from the settings (e.g. a computed
+/// commitment) are appended to the input list.
+type Operation = dyn FnOnce(&DynKzg, &mut Vec<(&'static str, Value)>) -> Result<Value, String>;
+
+/// Reads the inputs and runs the command. Errors of the operation itself (e.g. invalid points)
+/// are reported with a `null` output, like in the consensus spec tests.
+fn run(cli: &Cli) -> Result<Report, String> {
+    let reader = Reader::new(cli.format);
+    let mut input = Vec::new();
+
+    // Inputs are read before loading the trusted setup, which takes a while.
+    let operation: Box<Operation> = match &cli.command {
+        Command::Commit { blob } => {
+            let blob = reader.value::<BYTES_PER_BLOB>("blob", blob)?;
+            input.push(("blob", Value::bytes(blob)));
+
+            Box::new(move |kzg, _| kzg.blob_to_kzg_commitment(&blob).map(Value::bytes))
+        }
+        Command::Prove {
+            blob,
+            z: Some(z),
+            commitment: _,
+        } => {
+            let blob = reader.value::<BYTES_PER_BLOB>("blob", blob)?;
+            let z = reader.value::<BYTES_PER_FIELD_ELEMENT>("z", z)?;
+            input.push(("blob", Value::bytes(blob)));
+            input.push(("z", Value::bytes(z)));
+
+            Box::new(move |kzg, _| {
+                kzg.compute_kzg_proof(&blob, &z)
+                    .map(|(proof, y)| Value::List(vec![Value::bytes(proof), Value::bytes(y)]))
+            })
+        }
+        Command::Prove {
+            blob,
+            z: None,
+            commitment,
+        } => {
+            let blob = reader.value::<BYTES_PER_BLOB>("blob", blob)?;
+            let commitment = commitment
+                .as_ref()
+                .map(|commitment| reader.value::<BYTES_PER_COMMITMENT>("commitment", commitment))
+                .transpose()?;
+            input.push(("blob", Value::bytes(blob)));
+
+            Box::new(move |kzg, input| {
+                let commitment = match commitment {
+                    Some(commitment) => commitment,
+                    None => kzg.blob_to_kzg_commitment(&blob)?,
+                };
+                input.push(("commitment", Value::bytes(commitment)));
+
+                kzg.compute_blob_kzg_proof(&blob, &commitment)
+                    .map(Value::bytes)
+            })
+        }
+        Command::Verify {
+            commitment,
+            proof,
+            blob,
+            z,
+            y,
+        } => {
+            let commitment = reader.value::<BYTES_PER_COMMITMENT>("commitment", commitment)?;
+            let proof = reader.value::<BYTES_PER_PROOF>("proof", proof)?;
+
+            match (blob, z, y) {
+                (_, Some(z), Some(y)) => {
+                    let z = reader.value::<BYTES_PER_FIELD_ELEMENT>("z", z)?;
+                    let y = reader.value::<BYTES_PER_FIELD_ELEMENT>("y", y)?;
+                    input.push(("commitment", Value::bytes(commitment)));
+                    input.push(("z", Value::bytes(z)));
+                    input.push(("y", Value::bytes(y)));
+                    input.push(("proof", Value::bytes(proof)));
+
+                    Box::new(move |kzg, _| {
+                        kzg.verify_kzg_proof(&commitment, &z, &y, &proof)
+                            .map(Value::Bool)
+                    })
+                }
+                (Some(blob), _, _) => {
+                    let blob = reader.value::<BYTES_PER_BLOB>("blob", blob)?;
+                    input.push(("blob", Value::bytes(blob)));
+                    input.push(("commitment", Value::bytes(commitment)));
+                    input.push(("proof", Value::bytes(proof)));
+
+                    Box::new(move |kzg, _| {
+                        kzg.verify_blob_kzg_proof(&blob, &commitment, &proof)
+                            .map(Value::Bool)
+                    })
+                }
+                _ => unreachable!("argument groups are checked by clap"),
+            }
+        }
+        Command::BatchVerify {
+            blobs,
+            commitments,
+            proofs,
+        } => {
+            let blobs = reader.values::<BYTES_PER_BLOB>("blobs", blobs)?;
+            let commitments = reader.values::<BYTES_PER_COMMITMENT>("commitments", commitments)?;
+            let proofs = reader.values::<BYTES_PER_PROOF>("proofs", proofs)?;
+            input.push(("blobs", Value::list(&blobs)));
+            input.push(("commitments", Value::list(&commitments)));
+            input.push(("proofs", Value::list(&proofs)));
+
+            Box::new(move |kzg, _| {
+                kzg.verify_blob_kzg_proof_batch(&blobs, &commitments, &proofs)
+                    .map(Value::Bool)
+            })
+        }
+        Command::ComputeCells { blob, with_proofs } => {
+            let blob = reader.value::<BYTES_PER_BLOB>("blob", blob)?;
+            input.push(("blob", Value::bytes(blob)));
+
+            if *with_proofs {
+                Box::new(move |kzg, _| {
+                    kzg.compute_cells_and_kzg_proofs(&blob)
+                        .map(|(cells, proofs)| {
+                            Value::List(vec![Value::list(&cells), Value::list(&proofs)])
+                        })
+                })
+            } else {
+                Box::new(move |kzg, _| kzg.compute_cells(&blob).map(|cells| Value::list(&cells)))
+            }
+        }
+        Command::RecoverCells {
+            cell_indices,
+            cells,
+        } => {
+            let cell_indices = reader.indices("cell_indices", cell_indices)?;
+            let cells = reader.values::<BYTES_PER_CELL>("cells", cells)?;
+            input.push(("cell_indices", Value::Indices(cell_indices.clone())));
+            input.push(("cells", Value::list(&cells)));
+
+            Box::new(move |kzg, _| {
+                kzg.recover_cells_and_kzg_proofs(&cell_indices, &cells)
+                    .map(|(cells, proofs)| {
+                        Value::List(vec![Value::list(&cells), Value::list(&proofs)])
+                    })
+            })
+        }
+        Command::VerifyCells {
+            commitments,
+            cell_indices,
+            cells,
+            proofs,
+        } => {
+            let commitments = reader.values::<BYTES_PER_COMMITMENT>("commitments", commitments)?;
+            let cell_indices = reader.indices("cell_indices", cell_indices)?;
+            let cells = reader.values::<BYTES_PER_CELL>("cells", cells)?;
+            let proofs = reader.values::<BYTES_PER_PROOF>("proofs", proofs)?;
+            input.push(("commitments", Value::list(&commitments)));
+            input.push(("cell_indices", Value::Indices(cell_indices.clone())));
+            input.push(("cells", Value::list(&cells)));
+            input.push(("proofs", Value::list(&proofs)));
+
+            Box::new(move |kzg, _| {
+                kzg.verify_cell_kzg_proof_batch(&commitments, &cell_indices, &cells, &proofs)
+                    .map(Value::Bool)
+            })
+        }
+    };
+
+    let kzg = load_settings(cli)?;
+    let output = match operation(&kzg, &mut input) {
+        Ok(output) => Some(output),
+        Err(err) => {
+            eprintln!("error: {err}");
+            None
+        }
+    };
+
+    Ok(Report {
+        input,
+        output,
+        output_only: cli.output_only,
+    })
+}
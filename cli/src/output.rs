@@ -0,0 +1,87 @@
+//! YAML output in the format of the consensus spec KZG test vectors (`data.yaml`).
+
+use std::fmt::{self, Display, Formatter, Write};
+
+pub enum Value {
+    Null,
+    Bool(bool),
+    Bytes(Vec<u8>),
+    Indices(Vec<usize>),
+    List(Vec<Value>),
+}
+
+impl Value {
+    pub fn bytes(bytes: impl AsRef<[u8]>) -> Self {
+        Value::Bytes(bytes.as_ref().to_vec())
+    }
+
+    pub fn list<T: AsRef<[u8]>>(values: &[T]) -> Self {
+        Value::List(values.iter().map(Value::bytes).collect())
+    }
+
+    fn is_block(&self) -> bool {
+        matches!(self, Value::List(values) if !values.is_empty())
+    }
+
+    /// Writes the value after `key:` (or after `- ` in a list), at nesting level `indent`.
+    fn write(&self, f: &mut Formatter<'_>, indent: usize) -> fmt::Result {
+        match self {
+            Value::Null => f.write_str("null"),
+            Value::Bool(value) => write!(f, "{value}"),
+            Value::Bytes(bytes) => write!(f, "'0x{}'", hex::encode(bytes)),
+            Value::Indices(indices) => {
+                f.write_char('[')?;
+                for (i, index) in indices.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{index}")?;
+                }
+                f.write_char(']')
+            }
+            Value::List(values) if values.is_empty() => f.write_str("[]"),
+            Value::List(values) => {
+                for (i, value) in values.iter().enumerate() {
+                    // nested lists continue on the same line, as in `- - '0x..'`
+                    if i > 0 {
+                        write!(f, "\n{:width$}", "", width = indent * 2)?;
+                    }
+                    f.write_str("- ")?;
+                    value.write(f, indent + 1)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Inputs and output of a single operation. `output` is `None` for invalid inputs.
+pub struct Report {
+    pub input: Vec<(&'static str, Value)>,
+    pub output: Option<Value>,
+    pub output_only: bool,
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fn field(f: &mut Formatter<'_>, key: &str, value: &Value, indent: usize) -> fmt::Result {
+            write!(f, "{:width$}{key}:", "", width = indent * 2)?;
+            if value.is_block() {
+                write!(f, "\n{:width$}", "", width = indent * 2)?;
+            } else {
+                f.write_char(' ')?;
+            }
+            value.write(f, indent)?;
+            f.write_char('\n')
+        }
+
+        if !self.output_only {
+            f.write_str("input:\n")?;
+            for (key, value) in &self.input {
+                field(f, key, value, 1)?;
+            }
+        }
+
+        field(f, "output", self.output.as_ref().unwrap_or(&Value::Null), 0)
+    }
+}
@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        process::{Command, Output},
+    };
+
+    use kzg::eth::{BYTES_PER_BLOB, CELLS_PER_EXT_BLOB};
+    use kzg_bench::tests::{eip_4844::generate_random_blob_bytes, utils::get_trusted_setup_path};
+
+    fn kzg_cli(args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_kzg-cli"))
+            .arg("--trusted-setup")
+            .arg(get_trusted_setup_path())
+            .args(args)
+            .output()
+            .unwrap()
+    }
+
+    /// Runs the command with `--output-only` and returns the output values, one per line.
+    fn output_values(args: &[&str]) -> Vec<String> {
+        let output = kzg_cli(&[&["--output-only"], args].concat());
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .lines()
+            .map(|line| {
+                line.trim_start_matches("output:")
+                    .trim_start_matches([' ', '-'])
+                    .trim_matches('\'')
+                    .to_string()
+            })
+            .filter(|value| !value.is_empty())
+            .collect()
+    }
+
+    fn write_file(name: &str, contents: impl AsRef<[u8]>) -> String {
+        let path = std::env::temp_dir().join(format!("kzg-cli-{}-{name}", std::process::id()));
+        fs::write(&path, contents).unwrap();
+        path.into_os_string().into_string().unwrap()
+    }
+
+    #[test]
+    fn commit_zero_blob_() {
+        let blob = write_file("zero_blob.bin", [0u8; BYTES_PER_BLOB]);
+        let output = kzg_cli(&["commit", "--blob", &blob]);
+        assert!(output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.starts_with("input:\n  blob: '0x0000"));
+        assert!(stdout.ends_with(&format!("\noutput: '0xc0{}'\n", "00".repeat(47))));
+    }
+
+    #[test]
+    fn blob_proof_round_trip_() {
+        let mut rng = rand::thread_rng();
+        let blob = write_file(
+            "round_trip_blob.txt",
+            format!("0x{}\n", hex::encode(generate_random_blob_bytes(&mut rng))),
+        );
+
+        let commitment = output_values(&["commit", "--blob", &blob]).remove(0);
+        let proof = output_values(&["prove", "--blob", &blob]).remove(0);
+        let verify_args = ["--commitment", &commitment, "--proof", &proof];
+        assert_eq!(
+            output_values(&[&["verify", "--blob", &blob], &verify_args[..]].concat()),
+            ["true"]
+        );
+        assert_eq!(
+            output_values(&[
+                "batch-verify",
+                "--blobs",
+                &blob,
+                "--commitments",
+                &commitment,
+                "--proofs",
+                &proof
+            ]),
+            ["true"]
+        );
+
+        let z = format!("0x{}02", "00".repeat(31));
+        let [proof, y] =
+            <[String; 2]>::try_from(output_values(&["prove", "--blob", &blob, "--z", &z])).unwrap();
+        assert_eq!(
+            output_values(&[
+                "verify",
+                "--commitment",
+                &commitment,
+                "--z",
+                &z,
+                "--y",
+                &y,
+                "--proof",
+                &proof
+            ]),
+            ["true"]
+        );
+    }
+
+    #[test]
+    fn invalid_input_outputs_null_() {
+        let blob = write_file("invalid_blob.bin", [0xffu8; BYTES_PER_BLOB]);
+        let output = kzg_cli(&["commit", "--blob", &blob]);
+        assert!(!output.status.success());
+        assert!(String::from_utf8(output.stdout)
+            .unwrap()
+            .ends_with("\noutput: null\n"));
+
+        // values of the wrong length are rejected before running the operation
+        let output = kzg_cli(&["commit", "--blob", "0x00"]);
+        assert!(!output.status.success());
+        assert!(output.stdout.is_empty());
+    }
+
+    #[test]
+    fn recover_cells_() {
+        let mut rng = rand::thread_rng();
+        let blob = write_file(
+            "recover_blob.bin",
+            generate_random_blob_bytes(&mut rng).as_slice(),
+        );
+
+        let values = output_values(&["compute-cells", "--blob", &blob, "--with-proofs"]);
+        assert_eq!(values.len(), 2 * CELLS_PER_EXT_BLOB);
+
+        let cell_indices = (0..CELLS_PER_EXT_BLOB).step_by(2).collect::<Vec<_>>();
+        let cells = cell_indices
+            .iter()
+            .map(|&i| format!("\"{}\"", values[i]))
+            .collect::<Vec<_>>();
+        let cells = write_file("recover_cells.json", format!("[{}]", cells.join(", ")));
+        let cell_indices = cell_indices
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        assert_eq!(
+            output_values(&[
+                "recover-cells",
+                "--cell-indices",
+                &cell_indices,
+                "--cells",
+                &cells
+            ]),
+            values
+        );
+    }
+}
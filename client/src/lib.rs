@@ -0,0 +1,277 @@
+//! Client of the local KZG service (`kzg-server`).
+//!
+//! [`KzgClient`] implements [`KzgApi`], so it can replace an in-process `DynKzg` without loading
+//! the trusted setup in every process. Connections are opened on demand and reused, so concurrent
+//! calls from several threads run in parallel and can be coalesced by the server.
+
+pub mod protocol;
+
+use std::{
+    fmt,
+    io::{self, Read, Write},
+    net::{SocketAddr, TcpStream},
+    path::PathBuf,
+    str::FromStr,
+    sync::Mutex,
+};
+
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+
+pub use kzg::eth::KzgApi;
+use kzg::eth::{
+    eip_7594::CellsKzgProofs, BYTES_PER_BLOB, BYTES_PER_CELL, BYTES_PER_COMMITMENT,
+    BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
+};
+
+use crate::protocol::{decode_response, read_frame, write_frame, Output, Request};
+
+/// Address of the service: a Unix socket path, or a TCP socket address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Address {
+    #[cfg(unix)]
+    Unix(PathBuf),
+    Tcp(SocketAddr),
+}
+
+impl FromStr for Address {
+    type Err = String;
+
+    /// Parses `host:port` as a TCP address, anything else as a Unix socket path.
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        if let Ok(address) = address.parse() {
+            return Ok(Address::Tcp(address));
+        }
+
+        #[cfg(unix)]
+        {
+            Ok(Address::Unix(PathBuf::from(address)))
+        }
+        #[cfg(not(unix))]
+        {
+            Err(format!("Invalid TCP address \"{address}\""))
+        }
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(unix)]
+            Address::Unix(path) => write!(f, "{}", path.display()),
+            Address::Tcp(address) => write!(f, "{address}"),
+        }
+    }
+}
+
+enum Connection {
+    #[cfg(unix)]
+    Unix(UnixStream),
+    Tcp(TcpStream),
+}
+
+impl Connection {
+    fn open(address: &Address) -> io::Result<Self> {
+        match address {
+            #[cfg(unix)]
+            Address::Unix(path) => UnixStream::connect(path).map(Connection::Unix),
+            Address::Tcp(address) => {
+                let stream = TcpStream::connect(address)?;
+                stream.set_nodelay(true)?;
+                Ok(Connection::Tcp(stream))
+            }
+        }
+    }
+
+    fn call(&mut self, request: &[u8]) -> io::Result<Vec<u8>> {
+        write_frame(self, request)?;
+        read_frame(self)?.ok_or_else(|| io::ErrorKind::UnexpectedEof.into())
+    }
+}
+
+impl Read for Connection {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            #[cfg(unix)]
+            Connection::Unix(stream) => stream.read(buf),
+            Connection::Tcp(stream) => stream.read(buf),
+        }
+    }
+}
+
+impl Write for Connection {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            #[cfg(unix)]
+            Connection::Unix(stream) => stream.write(buf),
+            Connection::Tcp(stream) => stream.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            #[cfg(unix)]
+            Connection::Unix(stream) => stream.flush(),
+            Connection::Tcp(stream) => stream.flush(),
+        }
+    }
+}
+
+/// Connection pool to the local KZG service.
+pub struct KzgClient {
+    address: Address,
+    idle: Mutex<Vec<Connection>>,
+}
+
+impl KzgClient {
+    /// Connects to the service, failing early if it is not reachable.
+    pub fn connect(address: Address) -> Result<Self, String> {
+        let connection = Connection::open(&address)
+            .map_err(|err| format!("Unable to connect to KZG service at {address}: {err}"))?;
+
+        Ok(Self {
+            address,
+            idle: Mutex::new(vec![connection]),
+        })
+    }
+
+    pub fn address(&self) -> &Address {
+        &self.address
+    }
+
+    fn call<T: Output>(&self, request: Request<'_>) -> Result<T, String> {
+        let request = request.encode();
+
+        // an idle connection may have been closed by a restarted server, so retry once on a fresh
+        // connection (all methods are free of side effects)
+        let pooled = self.idle.lock().unwrap().pop();
+        let response = match pooled.map(|mut connection| (connection.call(&request), connection)) {
+            Some((Ok(response), connection)) => Ok((response, connection)),
+            _ => Connection::open(&self.address).and_then(|mut connection| {
+                connection
+                    .call(&request)
+                    .map(|response| (response, connection))
+            }),
+        };
+
+        let (response, connection) =
+            response.map_err(|err| format!("KZG service at {} failed: {err}", self.address))?;
+        self.idle.lock().unwrap().push(connection);
+
+        decode_response(&response)
+    }
+}
+
+impl fmt::Debug for KzgClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KzgClient")
+            .field("address", &self.address)
+            .finish_non_exhaustive()
+    }
+}
+
+impl KzgApi for KzgClient {
+    fn blob_to_kzg_commitment(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<[u8; BYTES_PER_COMMITMENT], String> {
+        self.call(Request::BlobToKzgCommitment { blob })
+    }
+
+    fn compute_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        z: &[u8; BYTES_PER_FIELD_ELEMENT],
+    ) -> Result<([u8; BYTES_PER_PROOF], [u8; BYTES_PER_FIELD_ELEMENT]), String> {
+        self.call(Request::ComputeKzgProof { blob, z })
+    }
+
+    fn compute_blob_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+    ) -> Result<[u8; BYTES_PER_PROOF], String> {
+        self.call(Request::ComputeBlobKzgProof { blob, commitment })
+    }
+
+    fn verify_kzg_proof(
+        &self,
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+        z: &[u8; BYTES_PER_FIELD_ELEMENT],
+        y: &[u8; BYTES_PER_FIELD_ELEMENT],
+        proof: &[u8; BYTES_PER_PROOF],
+    ) -> Result<bool, String> {
+        self.call(Request::VerifyKzgProof {
+            commitment,
+            z,
+            y,
+            proof,
+        })
+    }
+
+    fn verify_blob_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+        proof: &[u8; BYTES_PER_PROOF],
+    ) -> Result<bool, String> {
+        self.call(Request::VerifyBlobKzgProof {
+            blob,
+            commitment,
+            proof,
+        })
+    }
+
+    fn verify_blob_kzg_proof_batch(
+        &self,
+        blobs: &[[u8; BYTES_PER_BLOB]],
+        commitments: &[[u8; BYTES_PER_COMMITMENT]],
+        proofs: &[[u8; BYTES_PER_PROOF]],
+    ) -> Result<bool, String> {
+        self.call(Request::VerifyBlobKzgProofBatch {
+            blobs,
+            commitments,
+            proofs,
+        })
+    }
+
+    fn compute_cells(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<Vec<[u8; BYTES_PER_CELL]>, String> {
+        self.call(Request::ComputeCells { blob })
+    }
+
+    fn compute_cells_and_kzg_proofs(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<CellsKzgProofs, String> {
+        self.call(Request::ComputeCellsAndKzgProofs { blob })
+    }
+
+    fn recover_cells_and_kzg_proofs(
+        &self,
+        cell_indices: &[usize],
+        cells: &[[u8; BYTES_PER_CELL]],
+    ) -> Result<CellsKzgProofs, String> {
+        self.call(Request::RecoverCellsAndKzgProofs {
+            cell_indices: cell_indices.to_vec(),
+            cells,
+        })
+    }
+
+    fn verify_cell_kzg_proof_batch(
+        &self,
+        commitments: &[[u8; BYTES_PER_COMMITMENT]],
+        cell_indices: &[usize],
+        cells: &[[u8; BYTES_PER_CELL]],
+        proofs: &[[u8; BYTES_PER_PROOF]],
+    ) -> Result<bool, String> {
+        self.call(Request::VerifyCellKzgProofBatch {
+            commitments,
+            cell_indices: cell_indices.to_vec(),
+            cells,
+            proofs,
+        })
+    }
+}
@@ -0,0 +1,428 @@
+//! Wire format of the local KZG service.
+//!
+//! Every message is a frame: its length as a little-endian `u32`, followed by the body. A request
+//! body is the method byte followed by the arguments, a response body is `0` followed by the
+//! result, or `1` followed by the UTF-8 error message. Lists are prefixed with their length as a
+//! `u32`, cell indices are `u64`.
+
+use std::io::{self, Read, Write};
+
+use kzg::eth::{
+    BYTES_PER_BLOB, BYTES_PER_CELL, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
+};
+
+/// Upper bound on the frame length, large enough for a batch of 128 blobs or 16384 cells.
+pub const MAX_FRAME_LEN: usize = 64 << 20;
+
+/// Writes `body` as a single frame.
+pub fn write_frame(stream: &mut impl Write, body: &[u8]) -> io::Result<()> {
+    if body.len() > MAX_FRAME_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Frame of {} bytes exceeds the limit", body.len()),
+        ));
+    }
+
+    let mut frame = Vec::with_capacity(4 + body.len());
+    frame.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    frame.extend_from_slice(body);
+    stream.write_all(&frame)?;
+    stream.flush()
+}
+
+/// Reads the body of the next frame, or `None` if the stream was closed between frames.
+pub fn read_frame(stream: &mut impl Read) -> io::Result<Option<Vec<u8>>> {
+    let mut len = [0u8; 4];
+    match stream.read_exact(&mut len) {
+        Ok(()) => {}
+        Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(err) => return Err(err),
+    }
+
+    let len = u32::from_le_bytes(len) as usize;
+    if len > MAX_FRAME_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Frame of {len} bytes exceeds the limit"),
+        ));
+    }
+
+    // grows with the received data instead of trusting the length prefix
+    let mut body = Vec::new();
+    stream.take(len as u64).read_to_end(&mut body)?;
+    if body.len() != len {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+
+    Ok(Some(body))
+}
+
+#[derive(Default)]
+pub struct Writer(Vec<u8>);
+
+impl Writer {
+    pub fn u8(&mut self, value: u8) {
+        self.0.push(value);
+    }
+
+    pub fn bytes(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+
+    fn count(&mut self, len: usize) {
+        self.bytes(&(len as u32).to_le_bytes());
+    }
+
+    pub fn list<const N: usize>(&mut self, values: &[[u8; N]]) {
+        self.count(values.len());
+        self.bytes(values.as_flattened());
+    }
+
+    pub fn indices(&mut self, indices: &[usize]) {
+        self.count(indices.len());
+        for &index in indices {
+            self.bytes(&(index as u64).to_le_bytes());
+        }
+    }
+
+    pub fn finish(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+pub struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    pub fn new(body: &'a [u8]) -> Self {
+        Self(body)
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        if len > self.0.len() {
+            return Err("Unexpected end of message".to_string());
+        }
+
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    pub fn u8(&mut self) -> Result<u8, String> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    pub fn array<const N: usize>(&mut self) -> Result<&'a [u8; N], String> {
+        self.take(N).map(|bytes| bytes.try_into().unwrap())
+    }
+
+    fn count(&mut self) -> Result<usize, String> {
+        self.array::<4>()
+            .map(|bytes| u32::from_le_bytes(*bytes) as usize)
+    }
+
+    pub fn list<const N: usize>(&mut self) -> Result<&'a [[u8; N]], String> {
+        let len = self.count()?;
+        let bytes = self.take(
+            len.checked_mul(N)
+                .ok_or_else(|| "Unexpected end of message".to_string())?,
+        )?;
+
+        Ok(bytes.as_chunks::<N>().0)
+    }
+
+    pub fn indices(&mut self) -> Result<Vec<usize>, String> {
+        let len = self.count()?;
+        let bytes = self.take(
+            len.checked_mul(8)
+                .ok_or_else(|| "Unexpected end of message".to_string())?,
+        )?;
+
+        bytes
+            .as_chunks::<8>()
+            .0
+            .iter()
+            .map(|index| {
+                usize::try_from(u64::from_le_bytes(*index))
+                    .map_err(|_| "Cell index out of range".to_string())
+            })
+            .collect()
+    }
+
+    pub fn finish(self) -> Result<(), String> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err("Unexpected trailing bytes in message".to_string())
+        }
+    }
+}
+
+/// Request for one of the [`kzg::eth::KzgApi`] methods, borrowing the arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Request<'a> {
+    BlobToKzgCommitment {
+        blob: &'a [u8; BYTES_PER_BLOB],
+    },
+    ComputeKzgProof {
+        blob: &'a [u8; BYTES_PER_BLOB],
+        z: &'a [u8; BYTES_PER_FIELD_ELEMENT],
+    },
+    ComputeBlobKzgProof {
+        blob: &'a [u8; BYTES_PER_BLOB],
+        commitment: &'a [u8; BYTES_PER_COMMITMENT],
+    },
+    VerifyKzgProof {
+        commitment: &'a [u8; BYTES_PER_COMMITMENT],
+        z: &'a [u8; BYTES_PER_FIELD_ELEMENT],
+        y: &'a [u8; BYTES_PER_FIELD_ELEMENT],
+        proof: &'a [u8; BYTES_PER_PROOF],
+    },
+    VerifyBlobKzgProof {
+        blob: &'a [u8; BYTES_PER_BLOB],
+        commitment: &'a [u8; BYTES_PER_COMMITMENT],
+        proof: &'a [u8; BYTES_PER_PROOF],
+    },
+    VerifyBlobKzgProofBatch {
+        blobs: &'a [[u8; BYTES_PER_BLOB]],
+        commitments: &'a [[u8; BYTES_PER_COMMITMENT]],
+        proofs: &'a [[u8; BYTES_PER_PROOF]],
+    },
+    ComputeCells {
+        blob: &'a [u8; BYTES_PER_BLOB],
+    },
+    ComputeCellsAndKzgProofs {
+        blob: &'a [u8; BYTES_PER_BLOB],
+    },
+    RecoverCellsAndKzgProofs {
+        cell_indices: Vec<usize>,
+        cells: &'a [[u8; BYTES_PER_CELL]],
+    },
+    VerifyCellKzgProofBatch {
+        commitments: &'a [[u8; BYTES_PER_COMMITMENT]],
+        cell_indices: Vec<usize>,
+        cells: &'a [[u8; BYTES_PER_CELL]],
+        proofs: &'a [[u8; BYTES_PER_PROOF]],
+    },
+}
+
+impl<'a> Request<'a> {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut writer = Writer::default();
+
+        match self {
+            Request::BlobToKzgCommitment { blob } => {
+                writer.u8(0);
+                writer.bytes(*blob);
+            }
+            Request::ComputeKzgProof { blob, z } => {
+                writer.u8(1);
+                writer.bytes(*blob);
+                writer.bytes(*z);
+            }
+            Request::ComputeBlobKzgProof { blob, commitment } => {
+                writer.u8(2);
+                writer.bytes(*blob);
+                writer.bytes(*commitment);
+            }
+            Request::VerifyKzgProof {
+                commitment,
+                z,
+                y,
+                proof,
+            } => {
+                writer.u8(3);
+                writer.bytes(*commitment);
+                writer.bytes(*z);
+                writer.bytes(*y);
+                writer.bytes(*proof);
+            }
+            Request::VerifyBlobKzgProof {
+                blob,
+                commitment,
+                proof,
+            } => {
+                writer.u8(4);
+                writer.bytes(*blob);
+                writer.bytes(*commitment);
+                writer.bytes(*proof);
+            }
+            Request::VerifyBlobKzgProofBatch {
+                blobs,
+                commitments,
+                proofs,
+            } => {
+                writer.u8(5);
+                writer.list(blobs);
+                writer.list(commitments);
+                writer.list(proofs);
+            }
+            Request::ComputeCells { blob } => {
+                writer.u8(6);
+                writer.bytes(*blob);
+            }
+            Request::ComputeCellsAndKzgProofs { blob } => {
+                writer.u8(7);
+                writer.bytes(*blob);
+            }
+            Request::RecoverCellsAndKzgProofs {
+                cell_indices,
+                cells,
+            } => {
+                writer.u8(8);
+                writer.indices(cell_indices);
+                writer.list(cells);
+            }
+            Request::VerifyCellKzgProofBatch {
+                commitments,
+                cell_indices,
+                cells,
+                proofs,
+            } => {
+                writer.u8(9);
+                writer.list(commitments);
+                writer.indices(cell_indices);
+                writer.list(cells);
+                writer.list(proofs);
+            }
+        }
+
+        writer.finish()
+    }
+
+    pub fn decode(body: &'a [u8]) -> Result<Self, String> {
+        let mut reader = Reader::new(body);
+
+        let request = match reader.u8()? {
+            0 => Request::BlobToKzgCommitment {
+                blob: reader.array()?,
+            },
+            1 => Request::ComputeKzgProof {
+                blob: reader.array()?,
+                z: reader.array()?,
+            },
+            2 => Request::ComputeBlobKzgProof {
+                blob: reader.array()?,
+                commitment: reader.array()?,
+            },
+            3 => Request::VerifyKzgProof {
+                commitment: reader.array()?,
+                z: reader.array()?,
+                y: reader.array()?,
+                proof: reader.array()?,
+            },
+            4 => Request::VerifyBlobKzgProof {
+                blob: reader.array()?,
+                commitment: reader.array()?,
+                proof: reader.array()?,
+            },
+            5 => Request::VerifyBlobKzgProofBatch {
+                blobs: reader.list()?,
+                commitments: reader.list()?,
+                proofs: reader.list()?,
+            },
+            6 => Request::ComputeCells {
+                blob: reader.array()?,
+            },
+            7 => Request::ComputeCellsAndKzgProofs {
+                blob: reader.array()?,
+            },
+            8 => Request::RecoverCellsAndKzgProofs {
+                cell_indices: reader.indices()?,
+                cells: reader.list()?,
+            },
+            9 => Request::VerifyCellKzgProofBatch {
+                commitments: reader.list()?,
+                cell_indices: reader.indices()?,
+                cells: reader.list()?,
+                proofs: reader.list()?,
+            },
+            method => return Err(format!("Unknown method {method}")),
+        };
+
+        reader.finish()?;
+        Ok(request)
+    }
+}
+
+/// Result of a request, as returned by the [`kzg::eth::KzgApi`] methods.
+pub trait Output: Sized {
+    fn encode(&self, writer: &mut Writer);
+
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, String>;
+}
+
+impl Output for bool {
+    fn encode(&self, writer: &mut Writer) {
+        writer.u8(*self as u8);
+    }
+
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, String> {
+        match reader.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => Err(format!("Invalid boolean {value}")),
+        }
+    }
+}
+
+impl<const N: usize> Output for [u8; N] {
+    fn encode(&self, writer: &mut Writer) {
+        writer.bytes(self);
+    }
+
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, String> {
+        reader.array().copied()
+    }
+}
+
+impl<const N: usize> Output for Vec<[u8; N]> {
+    fn encode(&self, writer: &mut Writer) {
+        writer.list(self);
+    }
+
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, String> {
+        reader.list().map(<[_]>::to_vec)
+    }
+}
+
+impl<A: Output, B: Output> Output for (A, B) {
+    fn encode(&self, writer: &mut Writer) {
+        self.0.encode(writer);
+        self.1.encode(writer);
+    }
+
+    fn decode(reader: &mut Reader<'_>) -> Result<Self, String> {
+        Ok((A::decode(reader)?, B::decode(reader)?))
+    }
+}
+
+pub fn encode_response<T: Output>(result: &Result<T, String>) -> Vec<u8> {
+    let mut writer = Writer::default();
+
+    match result {
+        Ok(output) => {
+            writer.u8(0);
+            output.encode(&mut writer);
+        }
+        Err(err) => {
+            writer.u8(1);
+            writer.bytes(err.as_bytes());
+        }
+    }
+
+    writer.finish()
+}
+
+pub fn decode_response<T: Output>(body: &[u8]) -> Result<T, String> {
+    let mut reader = Reader::new(body);
+
+    match reader.u8()? {
+        0 => {
+            let output = T::decode(&mut reader)?;
+            reader.finish()?;
+            Ok(output)
+        }
+        1 => Err(String::from_utf8_lossy(reader.0).into_owned()),
+        status => Err(format!("Invalid response status {status}")),
+    }
+}
@@ -460,6 +460,52 @@ pub unsafe extern "C" fn verify_blob_kzg_proof_batch(
     CKzgRet::Ok
 }
 
+/// # Safety
+#[cfg(feature = "c_bindings")]
+#[no_mangle]
+pub unsafe extern "C" fn verify_kzg_proof_batch(
+    ok: *mut bool,
+    commitments_bytes: *const Bytes48,
+    zs_bytes: *const Bytes32,
+    ys_bytes: *const Bytes32,
+    proofs_bytes: *const Bytes48,
+    n: usize,
+    s: &CKZGSettings,
+) -> CKzgRet {
+    use kzg::eip_4844::verify_kzg_proof_batch_raw;
+
+    let commitments = core::slice::from_raw_parts(commitments_bytes, n)
+        .iter()
+        .map(|c| c.bytes)
+        .collect::<Vec<_>>();
+    let zs = core::slice::from_raw_parts(zs_bytes, n)
+        .iter()
+        .map(|z| z.bytes)
+        .collect::<Vec<_>>();
+    let ys = core::slice::from_raw_parts(ys_bytes, n)
+        .iter()
+        .map(|y| y.bytes)
+        .collect::<Vec<_>>();
+    let proofs = core::slice::from_raw_parts(proofs_bytes, n)
+        .iter()
+        .map(|p| p.bytes)
+        .collect::<Vec<_>>();
+
+    *ok = false;
+
+    let settings: CtKZGSettings = handle_ckzg_badargs!(s.try_into());
+
+    *ok = handle_ckzg_badargs!(verify_kzg_proof_batch_raw(
+        &commitments,
+        &zs,
+        &ys,
+        &proofs,
+        &settings
+    ));
+
+    CKzgRet::Ok
+}
+
 /// # Safety
 #[cfg(feature = "c_bindings")]
 #[no_mangle]
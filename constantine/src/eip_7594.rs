@@ -9,6 +9,7 @@ use crate::types::g1::CtG1;
 use crate::types::g1::CtG1Affine;
 use crate::types::g1::CtG1ProjAddAffine;
 use crate::types::g2::CtG2;
+use crate::types::gt::CtGT;
 use crate::types::kzg_settings::CtKZGSettings;
 use crate::types::poly::CtPoly;
 
@@ -21,6 +22,7 @@ impl EcBackend for CtBackend {
     type G1ProjAddAffine = CtG1ProjAddAffine;
     type G1 = CtG1;
     type G2 = CtG2;
+    type GT = CtGT;
     type Poly = CtPoly;
     type FFTSettings = CtFFTSettings;
     type KZGSettings = CtKZGSettings;
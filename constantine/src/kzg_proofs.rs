@@ -1,5 +1,7 @@
 extern crate alloc;
 
+use alloc::vec;
+
 use crate::types::fp::CtFp;
 use crate::types::g1::CtG1;
 use crate::types::{fr::CtFr, g1::CtG1Affine};
@@ -12,7 +14,7 @@ use constantine_sys as constantine;
 use kzg::G1Affine;
 
 #[cfg(not(feature = "constantine_msm"))]
-use kzg::msm::msm_impls::msm;
+use kzg::msm::msm_impls::{msm, msm_nbits};
 
 #[cfg(not(feature = "constantine_msm"))]
 use crate::types::g1::CtG1ProjAddAffine;
@@ -20,8 +22,9 @@ use crate::types::g1::CtG1ProjAddAffine;
 use kzg::msm::precompute::PrecomputationTable;
 
 use crate::types::g2::CtG2;
+use crate::types::gt::CtGT;
 
-use kzg::PairingVerify;
+use kzg::{Pairing, PairingVerify, GT};
 
 impl PairingVerify<CtG1, CtG2> for CtG1 {
     fn verify(a1: &CtG1, a2: &CtG2, b1: &CtG1, b2: &CtG2) -> bool {
@@ -29,6 +32,39 @@ impl PairingVerify<CtG1, CtG2> for CtG1 {
     }
 }
 
+impl Pairing<CtG1, CtG2, CtGT> for CtG1 {
+    fn miller_loop(a: &CtG1, b: &CtG2) -> CtGT {
+        let mut aa = blst::blst_p1_affine::default();
+        let mut bb = blst::blst_p2_affine::default();
+        unsafe {
+            blst::blst_p1_to_affine(&mut aa, ptr_transmute(&a.0));
+            blst::blst_p2_to_affine(&mut bb, ptr_transmute(&b.0));
+        }
+        CtGT(blst::blst_fp12::miller_loop(&bb, &aa))
+    }
+
+    fn final_exp(f: &CtGT) -> CtGT {
+        CtGT(f.0.final_exp())
+    }
+
+    fn multi_miller_loop(a: &[CtG1], b: &[CtG2]) -> CtGT {
+        let len = a.len().min(b.len());
+        if len == 0 {
+            return CtGT::one();
+        }
+
+        let mut aa = vec![blst::blst_p1_affine::default(); len];
+        let mut bb = vec![blst::blst_p2_affine::default(); len];
+        for i in 0..len {
+            unsafe {
+                blst::blst_p1_to_affine(&mut aa[i], ptr_transmute(&a[i].0));
+                blst::blst_p2_to_affine(&mut bb[i], ptr_transmute(&b[i].0));
+            }
+        }
+        CtGT(blst::blst_fp12::miller_loop_n(&bb, &aa))
+    }
+}
+
 pub fn g1_linear_combination(
     out: &mut CtG1,
     points: &[CtG1],
@@ -82,6 +118,22 @@ pub fn g1_linear_combination(
     }
 }
 
+#[cfg(not(feature = "constantine_msm"))]
+pub fn g1_linear_combination_short(
+    out: &mut CtG1,
+    points: &[CtG1],
+    scalars: &[CtFr],
+    len: usize,
+    scalar_bits: usize,
+) {
+    *out = msm_nbits::<CtG1, CtFp, CtG1Affine, CtG1ProjAddAffine, CtFr>(
+        points,
+        scalars,
+        len,
+        scalar_bits,
+    );
+}
+
 pub fn pairings_verify(a1: &CtG1, a2: &CtG2, b1: &CtG1, b2: &CtG2) -> bool {
     // FIXME: Remove usage of BLST version, though not sure if there's a constantine version of multi miller loop
     let mut aa1 = blst::blst_p1_affine::default();
@@ -16,6 +16,8 @@ use core::{
 };
 
 use crate::kzg_proofs::g1_linear_combination;
+#[cfg(not(feature = "constantine_msm"))]
+use crate::kzg_proofs::g1_linear_combination_short;
 use crate::types::fp::CtFp;
 use crate::types::fr::CtFr;
 
@@ -151,6 +153,36 @@ impl G1 for CtG1 {
             })
     }
 
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, String> {
+        bytes
+            .try_into()
+            .map_err(|_| {
+                format!(
+                    "Invalid byte length. Expected {}, got {}",
+                    BYTES_PER_G1,
+                    bytes.len()
+                )
+            })
+            .and_then(|bytes: &[u8; BYTES_PER_G1]| {
+                let mut tmp = bls12_381_g1_aff::default();
+                let mut g1 = bls12_381_g1_jac::default();
+                unsafe {
+                    // Skips the subgroup check, but the point is still on the curve
+                    let res = constantine::ctt_bls12_381_deserialize_g1_compressed_unchecked(
+                        &mut tmp,
+                        bytes.as_ptr(),
+                    );
+                    if res != ctt_codec_ecc_status::cttCodecEcc_Success
+                        && res != ctt_codec_ecc_status::cttCodecEcc_PointAtInfinity
+                    {
+                        return Err("Failed to uncompress".to_string());
+                    }
+                    ctt_bls12_381_g1_jac_from_affine(&mut g1, &tmp);
+                }
+                Ok(CtG1(g1))
+            })
+    }
+
     fn from_hex(hex: &str) -> Result<Self, String> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
@@ -285,6 +317,13 @@ impl G1LinComb<CtFr, CtFp, CtG1Affine, CtG1ProjAddAffine> for CtG1 {
         g1_linear_combination(&mut out, points, scalars, len, precomputation);
         out
     }
+
+    #[cfg(not(feature = "constantine_msm"))]
+    fn g1_lincomb_short(points: &[Self], scalars: &[CtFr], len: usize, scalar_bits: usize) -> Self {
+        let mut out = CtG1::default();
+        g1_linear_combination_short(&mut out, points, scalars, len, scalar_bits);
+        out
+    }
 }
 
 impl G1GetFp<CtFp> for CtG1 {
@@ -450,11 +489,40 @@ impl G1Affine<CtG1, CtFp> for CtG1Affine {
     }
 
     fn to_bytes_uncompressed(&self) -> [u8; 96] {
-        todo!()
+        let mut output = [0u8; 96];
+        let aff = blst::blst_p1_affine {
+            x: blst::blst_fp {
+                l: unsafe { core::mem::transmute::<[usize; 6], [u64; 6]>(self.0.x.limbs) },
+            },
+            y: blst::blst_fp {
+                l: unsafe { core::mem::transmute::<[usize; 6], [u64; 6]>(self.0.y.limbs) },
+            },
+        };
+
+        unsafe {
+            blst::blst_p1_affine_serialize(output.as_mut_ptr(), &aff);
+        }
+
+        output
     }
 
-    fn from_bytes_uncompressed(_bytes: [u8; 96]) -> Result<Self, String> {
-        todo!()
+    fn from_bytes_uncompressed(bytes: [u8; 96]) -> Result<Self, String> {
+        let mut aff = blst::blst_p1_affine::default();
+
+        let res = unsafe { blst::blst_p1_deserialize(&mut aff, bytes.as_ptr()) };
+
+        if res == blst::BLST_ERROR::BLST_SUCCESS {
+            Ok(Self(bls12_381_g1_aff {
+                x: bls12_381_fp {
+                    limbs: unsafe { core::mem::transmute::<[u64; 6], [usize; 6]>(aff.x.l) },
+                },
+                y: bls12_381_fp {
+                    limbs: unsafe { core::mem::transmute::<[u64; 6], [usize; 6]>(aff.y.l) },
+                },
+            }))
+        } else {
+            Err("Failed to deserialize point".to_string())
+        }
     }
 }
 
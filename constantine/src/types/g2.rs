@@ -150,6 +150,36 @@ impl G2 for CtG2 {
             })
     }
 
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, String> {
+        bytes
+            .try_into()
+            .map_err(|_| {
+                format!(
+                    "Invalid byte length. Expected {}, got {}",
+                    BYTES_PER_G2,
+                    bytes.len()
+                )
+            })
+            .and_then(|bytes: &[u8; BYTES_PER_G2]| {
+                let mut tmp = bls12_381_g2_aff::default();
+                let mut g2 = bls12_381_g2_jac::default();
+                unsafe {
+                    // Skips the subgroup check, but the point is still on the curve
+                    let res = constantine::ctt_bls12_381_deserialize_g2_compressed_unchecked(
+                        &mut tmp,
+                        bytes.as_ptr(),
+                    );
+                    if res != ctt_codec_ecc_status::cttCodecEcc_Success
+                        && res != ctt_codec_ecc_status::cttCodecEcc_PointAtInfinity
+                    {
+                        return Err("Failed to uncompress".to_string());
+                    }
+                    ctt_bls12_381_g2_jac_from_affine(&mut g2, &tmp);
+                }
+                Ok(CtG2(g2))
+            })
+    }
+
     fn to_bytes(&self) -> [u8; 96] {
         let mut out = [0u8; BYTES_PER_G2];
         let mut tmp = bls12_381_g2_aff::default();
@@ -168,6 +198,18 @@ impl G2 for CtG2 {
         Self(result)
     }
 
+    fn is_valid(&self) -> bool {
+        let mut affine = bls12_381_g2_aff::default();
+        unsafe {
+            constantine::ctt_bls12_381_g2_jac_affine(&mut affine, &self.0);
+            matches!(
+                constantine::ctt_bls12_381_validate_g2(&affine),
+                ctt_codec_ecc_status::cttCodecEcc_Success
+                    | ctt_codec_ecc_status::cttCodecEcc_PointAtInfinity
+            )
+        }
+    }
+
     fn dbl(&self) -> Self {
         let mut result = bls12_381_g2_jac::default();
         unsafe {
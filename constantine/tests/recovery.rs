@@ -26,4 +26,14 @@ mod tests {
     fn more_than_half_missing_() {
         more_than_half_missing::<CtFr, CtFFTSettings, CtPoly, CtPoly>();
     }
+
+    #[test]
+    fn decode_with_errors_() {
+        decode_with_errors::<CtFr, CtFFTSettings>();
+    }
+
+    #[test]
+    fn erasure_coder_() {
+        erasure_coder::<CtFr, CtFFTSettings, CtPoly>();
+    }
 }
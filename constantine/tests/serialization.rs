@@ -0,0 +1,25 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use kzg_bench::tests::serialization::*;
+    use rust_kzg_constantine::types::{fr::CtFr, g1::CtG1, g2::CtG2};
+
+    #[test]
+    pub fn fr_serde_works_() {
+        fr_serde_works::<CtFr>();
+    }
+
+    #[test]
+    pub fn g1_serde_works_() {
+        g1_serde_works::<CtFr, CtG1>();
+    }
+
+    #[test]
+    pub fn g2_serde_works_() {
+        g2_serde_works::<CtFr, CtG2>();
+    }
+
+    #[test]
+    pub fn byte_containers_serde_works_() {
+        byte_containers_serde_works::<CtFr, CtG1>();
+    }
+}
@@ -0,0 +1,593 @@
+//! Runtime-dispatched KZG facade.
+//!
+//! [`DynKzg`] wraps the trusted setup of any backend enabled at compile time and exposes the
+//! byte-level EIP-4844 and EIP-7594 APIs, so the backend can be picked from configuration
+//! (e.g. `DynKzg::load_trusted_setup_file("blst", path)`) instead of a type parameter.
+
+use std::{fmt, fs, str::FromStr};
+
+use kzg::{
+    eip_4844::{
+        blob_to_kzg_commitment_raw, compute_blob_kzg_proof_raw_with_validation,
+        compute_kzg_proof_raw, load_trusted_setup_rust, load_trusted_setup_string,
+        verify_blob_kzg_proof_batch_raw_with_validation, verify_blob_kzg_proof_raw_with_validation,
+        verify_kzg_proof_batch_raw_with_validation, verify_kzg_proof_raw_with_validation,
+        BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
+    },
+    eth::{
+        eip_7594::{
+            compute_cells_and_kzg_proofs_raw, compute_cells_raw, recover_cells_and_kzg_proofs_raw,
+            verify_cell_kzg_proof_batch_raw_with_validation, CellsKzgProofs,
+        },
+        BYTES_PER_CELL,
+    },
+    EcBackend, Fr, G1,
+};
+
+pub use kzg::{eth::KzgApi, PointValidation};
+
+#[cfg(not(any(
+    feature = "blst",
+    feature = "arkworks3",
+    feature = "arkworks4",
+    feature = "arkworks5",
+    feature = "constantine",
+    feature = "mcl",
+    feature = "zkcrypto"
+)))]
+compile_error!("rust-kzg-dyn requires at least one backend feature to be enabled");
+
+/// Backends that can be selected at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Backend {
+    #[cfg(feature = "blst")]
+    Blst,
+    #[cfg(feature = "arkworks3")]
+    Arkworks3,
+    #[cfg(feature = "arkworks4")]
+    Arkworks4,
+    #[cfg(feature = "arkworks5")]
+    Arkworks5,
+    #[cfg(feature = "constantine")]
+    Constantine,
+    #[cfg(feature = "mcl")]
+    Mcl,
+    #[cfg(feature = "zkcrypto")]
+    Zkcrypto,
+}
+
+impl Backend {
+    /// All backends compiled into this build, in preference order.
+    pub const ENABLED: &'static [Backend] = &[
+        #[cfg(feature = "blst")]
+        Backend::Blst,
+        #[cfg(feature = "arkworks3")]
+        Backend::Arkworks3,
+        #[cfg(feature = "arkworks4")]
+        Backend::Arkworks4,
+        #[cfg(feature = "arkworks5")]
+        Backend::Arkworks5,
+        #[cfg(feature = "constantine")]
+        Backend::Constantine,
+        #[cfg(feature = "mcl")]
+        Backend::Mcl,
+        #[cfg(feature = "zkcrypto")]
+        Backend::Zkcrypto,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "blst")]
+            Backend::Blst => "blst",
+            #[cfg(feature = "arkworks3")]
+            Backend::Arkworks3 => "arkworks3",
+            #[cfg(feature = "arkworks4")]
+            Backend::Arkworks4 => "arkworks4",
+            #[cfg(feature = "arkworks5")]
+            Backend::Arkworks5 => "arkworks5",
+            #[cfg(feature = "constantine")]
+            Backend::Constantine => "constantine",
+            #[cfg(feature = "mcl")]
+            Backend::Mcl => "mcl",
+            #[cfg(feature = "zkcrypto")]
+            Backend::Zkcrypto => "zkcrypto",
+        }
+    }
+}
+
+impl FromStr for Backend {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Backend::ENABLED
+            .iter()
+            .find(|backend| backend.name().eq_ignore_ascii_case(name))
+            .copied()
+            .ok_or_else(|| {
+                format!(
+                    "Unknown or disabled backend \"{}\", available backends: {}",
+                    name,
+                    Backend::ENABLED
+                        .iter()
+                        .map(Backend::name)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Trusted setup of a single statically known backend.
+struct BackendSettings<B: EcBackend> {
+    settings: B::KZGSettings,
+    validation: PointValidation,
+}
+
+impl<B: EcBackend> BackendSettings<B>
+where
+    B::Fr: Copy,
+{
+    fn load(
+        g1_monomial_bytes: &[u8],
+        g1_lagrange_bytes: &[u8],
+        g2_monomial_bytes: &[u8],
+    ) -> Result<Self, String> {
+        let settings = load_trusted_setup_rust::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(g1_monomial_bytes, g1_lagrange_bytes, g2_monomial_bytes)?;
+
+        Ok(Self {
+            settings,
+            validation: PointValidation::Checked,
+        })
+    }
+}
+
+impl<B: EcBackend> KzgApi for BackendSettings<B>
+where
+    B::Fr: Copy,
+    B::G1: Copy,
+    B::KZGSettings: Send + Sync,
+{
+    fn blob_to_kzg_commitment(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<[u8; BYTES_PER_COMMITMENT], String> {
+        blob_to_kzg_commitment_raw::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(*blob, &self.settings)
+        .map(|commitment| commitment.to_bytes())
+    }
+
+    fn compute_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        z: &[u8; BYTES_PER_FIELD_ELEMENT],
+    ) -> Result<([u8; BYTES_PER_PROOF], [u8; BYTES_PER_FIELD_ELEMENT]), String> {
+        compute_kzg_proof_raw::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(*blob, *z, &self.settings)
+        .map(|(proof, y)| (proof.to_bytes(), y.to_bytes()))
+    }
+
+    fn compute_blob_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+    ) -> Result<[u8; BYTES_PER_PROOF], String> {
+        compute_blob_kzg_proof_raw_with_validation::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(*blob, *commitment, &self.settings, self.validation)
+        .map(|proof| proof.to_bytes())
+    }
+
+    fn verify_kzg_proof(
+        &self,
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+        z: &[u8; BYTES_PER_FIELD_ELEMENT],
+        y: &[u8; BYTES_PER_FIELD_ELEMENT],
+        proof: &[u8; BYTES_PER_PROOF],
+    ) -> Result<bool, String> {
+        verify_kzg_proof_raw_with_validation::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(*commitment, *z, *y, *proof, &self.settings, self.validation)
+    }
+
+    fn verify_blob_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+        proof: &[u8; BYTES_PER_PROOF],
+    ) -> Result<bool, String> {
+        verify_blob_kzg_proof_raw_with_validation::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(*blob, *commitment, *proof, &self.settings, self.validation)
+    }
+
+    fn verify_blob_kzg_proof_batch(
+        &self,
+        blobs: &[[u8; BYTES_PER_BLOB]],
+        commitments: &[[u8; BYTES_PER_COMMITMENT]],
+        proofs: &[[u8; BYTES_PER_PROOF]],
+    ) -> Result<bool, String> {
+        verify_blob_kzg_proof_batch_raw_with_validation::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(blobs, commitments, proofs, &self.settings, self.validation)
+    }
+
+    fn compute_cells(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<Vec<[u8; BYTES_PER_CELL]>, String> {
+        compute_cells_raw::<B>(*blob, &self.settings)
+    }
+
+    fn compute_cells_and_kzg_proofs(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<CellsKzgProofs, String> {
+        compute_cells_and_kzg_proofs_raw::<B>(*blob, &self.settings)
+    }
+
+    fn recover_cells_and_kzg_proofs(
+        &self,
+        cell_indices: &[usize],
+        cells: &[[u8; BYTES_PER_CELL]],
+    ) -> Result<CellsKzgProofs, String> {
+        recover_cells_and_kzg_proofs_raw::<B>(cell_indices, cells, &self.settings)
+    }
+
+    fn verify_cell_kzg_proof_batch(
+        &self,
+        commitments: &[[u8; BYTES_PER_COMMITMENT]],
+        cell_indices: &[usize],
+        cells: &[[u8; BYTES_PER_CELL]],
+        proofs: &[[u8; BYTES_PER_PROOF]],
+    ) -> Result<bool, String> {
+        verify_cell_kzg_proof_batch_raw_with_validation::<B>(
+            commitments,
+            cell_indices,
+            cells,
+            proofs,
+            &self.settings,
+            self.validation,
+        )
+    }
+}
+
+/// [`KzgApi`] of a backend, with configurable checks of the points it decodes.
+trait DynBackend: KzgApi {
+    fn set_point_validation(&mut self, validation: PointValidation);
+
+    fn verify_kzg_proof_batch(
+        &self,
+        commitments: &[[u8; BYTES_PER_COMMITMENT]],
+        zs: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+        ys: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+        proofs: &[[u8; BYTES_PER_PROOF]],
+    ) -> Result<bool, String>;
+}
+
+impl<B: EcBackend> DynBackend for BackendSettings<B>
+where
+    B::Fr: Copy,
+    B::G1: Copy,
+    B::KZGSettings: Send + Sync,
+{
+    fn set_point_validation(&mut self, validation: PointValidation) {
+        self.validation = validation;
+    }
+
+    fn verify_kzg_proof_batch(
+        &self,
+        commitments: &[[u8; BYTES_PER_COMMITMENT]],
+        zs: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+        ys: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+        proofs: &[[u8; BYTES_PER_PROOF]],
+    ) -> Result<bool, String> {
+        verify_kzg_proof_batch_raw_with_validation::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(commitments, zs, ys, proofs, &self.settings, self.validation)
+    }
+}
+
+/// KZG settings of a backend chosen at runtime.
+pub struct DynKzg {
+    backend: Backend,
+    validation: PointValidation,
+    inner: Box<dyn DynBackend>,
+}
+
+impl DynKzg {
+    /// Loads the trusted setup from its serialized points into the given backend.
+    pub fn load_trusted_setup(
+        backend: Backend,
+        g1_monomial_bytes: &[u8],
+        g1_lagrange_bytes: &[u8],
+        g2_monomial_bytes: &[u8],
+    ) -> Result<Self, String> {
+        fn boxed<B: EcBackend + 'static>(
+            g1_monomial_bytes: &[u8],
+            g1_lagrange_bytes: &[u8],
+            g2_monomial_bytes: &[u8],
+        ) -> Result<Box<dyn DynBackend>, String>
+        where
+            B::Fr: Copy,
+            B::G1: Copy,
+            B::KZGSettings: Send + Sync,
+        {
+            BackendSettings::<B>::load(g1_monomial_bytes, g1_lagrange_bytes, g2_monomial_bytes)
+                .map(|settings| Box::new(settings) as Box<dyn DynBackend>)
+        }
+
+        let inner = match backend {
+            #[cfg(feature = "blst")]
+            Backend::Blst => boxed::<rust_kzg_blst::eip_7594::BlstBackend>(
+                g1_monomial_bytes,
+                g1_lagrange_bytes,
+                g2_monomial_bytes,
+            ),
+            #[cfg(feature = "arkworks3")]
+            Backend::Arkworks3 => boxed::<rust_kzg_arkworks3::eip_7594::ArkBackend>(
+                g1_monomial_bytes,
+                g1_lagrange_bytes,
+                g2_monomial_bytes,
+            ),
+            #[cfg(feature = "arkworks4")]
+            Backend::Arkworks4 => boxed::<rust_kzg_arkworks4::eip_7594::ArkBackend>(
+                g1_monomial_bytes,
+                g1_lagrange_bytes,
+                g2_monomial_bytes,
+            ),
+            #[cfg(feature = "arkworks5")]
+            Backend::Arkworks5 => boxed::<rust_kzg_arkworks5::eip_7594::ArkBackend>(
+                g1_monomial_bytes,
+                g1_lagrange_bytes,
+                g2_monomial_bytes,
+            ),
+            #[cfg(feature = "constantine")]
+            Backend::Constantine => boxed::<rust_kzg_constantine::eip_7594::CtBackend>(
+                g1_monomial_bytes,
+                g1_lagrange_bytes,
+                g2_monomial_bytes,
+            ),
+            #[cfg(feature = "mcl")]
+            Backend::Mcl => boxed::<rust_kzg_mcl::eip_7594::MclBackend>(
+                g1_monomial_bytes,
+                g1_lagrange_bytes,
+                g2_monomial_bytes,
+            ),
+            #[cfg(feature = "zkcrypto")]
+            Backend::Zkcrypto => boxed::<rust_kzg_zkcrypto::eip_7594::ZBackend>(
+                g1_monomial_bytes,
+                g1_lagrange_bytes,
+                g2_monomial_bytes,
+            ),
+        }?;
+
+        Ok(Self {
+            backend,
+            validation: PointValidation::Checked,
+            inner,
+        })
+    }
+
+    /// Loads the trusted setup from the text format used by c-kzg-4844 (`trusted_setup.txt`).
+    pub fn load_trusted_setup_string(backend: Backend, contents: &str) -> Result<Self, String> {
+        let (g1_monomial_bytes, g1_lagrange_bytes, g2_monomial_bytes) =
+            load_trusted_setup_string(contents)?;
+
+        Self::load_trusted_setup(
+            backend,
+            &g1_monomial_bytes,
+            &g1_lagrange_bytes,
+            &g2_monomial_bytes,
+        )
+    }
+
+    /// Loads the trusted setup file into the backend with the given name, e.g. `"blst"`.
+    pub fn load_trusted_setup_file(backend: &str, filepath: &str) -> Result<Self, String> {
+        let backend = backend.parse()?;
+        let contents =
+            fs::read_to_string(filepath).map_err(|_| "Unable to read file".to_string())?;
+
+        Self::load_trusted_setup_string(backend, &contents)
+    }
+
+    pub fn backend(&self) -> Backend {
+        self.backend
+    }
+
+    /// Sets how commitments and proofs passed to the verification functions and
+    /// `compute_blob_kzg_proof` are checked. [`PointValidation::Trusted`] skips the subgroup checks,
+    /// so it must only be used for points that were checked before, e.g. when reloaded from our
+    /// own storage.
+    pub fn with_point_validation(mut self, validation: PointValidation) -> Self {
+        self.validation = validation;
+        self.inner.set_point_validation(validation);
+        self
+    }
+
+    pub fn point_validation(&self) -> PointValidation {
+        self.validation
+    }
+
+    /// Verifies independent openings `(commitments[i], zs[i], ys[i], proofs[i])` at arbitrary
+    /// points with a single pairing check.
+    pub fn verify_kzg_proof_batch(
+        &self,
+        commitments: &[[u8; BYTES_PER_COMMITMENT]],
+        zs: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+        ys: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+        proofs: &[[u8; BYTES_PER_PROOF]],
+    ) -> Result<bool, String> {
+        self.inner
+            .verify_kzg_proof_batch(commitments, zs, ys, proofs)
+    }
+}
+
+impl fmt::Debug for DynKzg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynKzg")
+            .field("backend", &self.backend)
+            .field("validation", &self.validation)
+            .finish_non_exhaustive()
+    }
+}
+
+impl KzgApi for DynKzg {
+    fn blob_to_kzg_commitment(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<[u8; BYTES_PER_COMMITMENT], String> {
+        self.inner.blob_to_kzg_commitment(blob)
+    }
+
+    fn compute_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        z: &[u8; BYTES_PER_FIELD_ELEMENT],
+    ) -> Result<([u8; BYTES_PER_PROOF], [u8; BYTES_PER_FIELD_ELEMENT]), String> {
+        self.inner.compute_kzg_proof(blob, z)
+    }
+
+    fn compute_blob_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+    ) -> Result<[u8; BYTES_PER_PROOF], String> {
+        self.inner.compute_blob_kzg_proof(blob, commitment)
+    }
+
+    fn verify_kzg_proof(
+        &self,
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+        z: &[u8; BYTES_PER_FIELD_ELEMENT],
+        y: &[u8; BYTES_PER_FIELD_ELEMENT],
+        proof: &[u8; BYTES_PER_PROOF],
+    ) -> Result<bool, String> {
+        self.inner.verify_kzg_proof(commitment, z, y, proof)
+    }
+
+    fn verify_blob_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+        proof: &[u8; BYTES_PER_PROOF],
+    ) -> Result<bool, String> {
+        self.inner.verify_blob_kzg_proof(blob, commitment, proof)
+    }
+
+    fn verify_blob_kzg_proof_batch(
+        &self,
+        blobs: &[[u8; BYTES_PER_BLOB]],
+        commitments: &[[u8; BYTES_PER_COMMITMENT]],
+        proofs: &[[u8; BYTES_PER_PROOF]],
+    ) -> Result<bool, String> {
+        self.inner
+            .verify_blob_kzg_proof_batch(blobs, commitments, proofs)
+    }
+
+    fn compute_cells(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<Vec<[u8; BYTES_PER_CELL]>, String> {
+        self.inner.compute_cells(blob)
+    }
+
+    fn compute_cells_and_kzg_proofs(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<CellsKzgProofs, String> {
+        self.inner.compute_cells_and_kzg_proofs(blob)
+    }
+
+    fn recover_cells_and_kzg_proofs(
+        &self,
+        cell_indices: &[usize],
+        cells: &[[u8; BYTES_PER_CELL]],
+    ) -> Result<CellsKzgProofs, String> {
+        self.inner.recover_cells_and_kzg_proofs(cell_indices, cells)
+    }
+
+    fn verify_cell_kzg_proof_batch(
+        &self,
+        commitments: &[[u8; BYTES_PER_COMMITMENT]],
+        cell_indices: &[usize],
+        cells: &[[u8; BYTES_PER_CELL]],
+        proofs: &[[u8; BYTES_PER_PROOF]],
+    ) -> Result<bool, String> {
+        self.inner
+            .verify_cell_kzg_proof_batch(commitments, cell_indices, cells, proofs)
+    }
+}
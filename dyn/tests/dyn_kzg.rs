@@ -0,0 +1,171 @@
+#[cfg(test)]
+mod tests {
+    use kzg::eth::CELLS_PER_EXT_BLOB;
+    use kzg_bench::tests::{
+        eip_4844::generate_random_blob_bytes,
+        utils::{get_trusted_setup_path, hex_to_bytes, G1_NOT_IN_SUBGROUP},
+    };
+    use rust_kzg_dyn::{Backend, DynKzg, KzgApi, PointValidation};
+
+    fn load(backend: Backend) -> DynKzg {
+        DynKzg::load_trusted_setup_file(backend.name(), &get_trusted_setup_path()).unwrap()
+    }
+
+    #[test]
+    fn backend_from_name_() {
+        for backend in Backend::ENABLED {
+            assert_eq!(backend.name().parse::<Backend>(), Ok(*backend));
+            assert_eq!(
+                backend.name().to_uppercase().parse::<Backend>(),
+                Ok(*backend)
+            );
+        }
+
+        assert!("unknown".parse::<Backend>().is_err());
+        assert!(DynKzg::load_trusted_setup_file("unknown", &get_trusted_setup_path()).is_err());
+    }
+
+    #[test]
+    fn blob_kzg_proof_round_trip_() {
+        let mut rng = rand::thread_rng();
+        let blob = generate_random_blob_bytes(&mut rng);
+
+        for backend in Backend::ENABLED {
+            let kzg = load(*backend);
+            assert_eq!(kzg.backend(), *backend);
+
+            let commitment = kzg.blob_to_kzg_commitment(&blob).unwrap();
+            let proof = kzg.compute_blob_kzg_proof(&blob, &commitment).unwrap();
+            assert!(kzg
+                .verify_blob_kzg_proof(&blob, &commitment, &proof)
+                .unwrap());
+            assert!(kzg
+                .verify_blob_kzg_proof_batch(&[blob], &[commitment], &[proof])
+                .unwrap());
+
+            let mut other_blob = blob;
+            other_blob[1] ^= 1;
+            assert!(!kzg
+                .verify_blob_kzg_proof(&other_blob, &commitment, &proof)
+                .unwrap());
+        }
+    }
+
+    #[test]
+    fn cells_round_trip_() {
+        let mut rng = rand::thread_rng();
+        let blob = generate_random_blob_bytes(&mut rng);
+
+        for backend in Backend::ENABLED {
+            let kzg = load(*backend);
+
+            let commitment = kzg.blob_to_kzg_commitment(&blob).unwrap();
+            let (cells, proofs) = kzg.compute_cells_and_kzg_proofs(&blob).unwrap();
+            assert_eq!(kzg.compute_cells(&blob).unwrap(), cells);
+
+            let cell_indices = (0..CELLS_PER_EXT_BLOB).collect::<Vec<_>>();
+            assert!(
+                kzg.verify_cell_kzg_proof_batch(
+                    &vec![commitment; CELLS_PER_EXT_BLOB],
+                    &cell_indices,
+                    &cells,
+                    &proofs,
+                )
+                .unwrap(),
+                "cell proofs rejected by {backend}"
+            );
+
+            let half = CELLS_PER_EXT_BLOB / 2;
+            let (recovered_cells, recovered_proofs) = kzg
+                .recover_cells_and_kzg_proofs(&cell_indices[half..], &cells[half..])
+                .unwrap();
+            assert_eq!(recovered_cells, cells);
+            assert_eq!(recovered_proofs, proofs);
+        }
+    }
+
+    #[test]
+    fn kzg_proof_batch_() {
+        let mut rng = rand::thread_rng();
+        let blobs = [
+            generate_random_blob_bytes(&mut rng),
+            generate_random_blob_bytes(&mut rng),
+        ];
+        let zs = [[0u8; 32], {
+            let mut z = [0u8; 32];
+            z[31] = 7;
+            z
+        }];
+
+        for backend in Backend::ENABLED {
+            let kzg = load(*backend);
+            let mut commitments = Vec::new();
+            let mut ys = Vec::new();
+            let mut proofs = Vec::new();
+            for (blob, z) in blobs.iter().zip(zs.iter()) {
+                let (proof, y) = kzg.compute_kzg_proof(blob, z).unwrap();
+                commitments.push(kzg.blob_to_kzg_commitment(blob).unwrap());
+                ys.push(y);
+                proofs.push(proof);
+            }
+
+            assert!(
+                kzg.verify_kzg_proof_batch(&commitments, &zs, &ys, &proofs)
+                    .unwrap(),
+                "openings rejected by {backend}"
+            );
+            ys.swap(0, 1);
+            assert!(!kzg
+                .verify_kzg_proof_batch(&commitments, &zs, &ys, &proofs)
+                .unwrap());
+            assert!(kzg
+                .verify_kzg_proof_batch(&commitments, &zs[1..], &ys, &proofs)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn trusted_point_validation_() {
+        let mut rng = rand::thread_rng();
+        let blob = generate_random_blob_bytes(&mut rng);
+        let not_in_subgroup = hex_to_bytes(G1_NOT_IN_SUBGROUP);
+
+        for backend in Backend::ENABLED {
+            let kzg = load(*backend);
+            let commitment = kzg.blob_to_kzg_commitment(&blob).unwrap();
+            let proof = kzg.compute_blob_kzg_proof(&blob, &commitment).unwrap();
+            assert!(kzg
+                .verify_blob_kzg_proof(&blob, &not_in_subgroup, &proof)
+                .is_err());
+
+            let kzg = kzg.with_point_validation(PointValidation::Trusted);
+            assert_eq!(kzg.point_validation(), PointValidation::Trusted);
+            assert!(kzg
+                .verify_blob_kzg_proof(&blob, &commitment, &proof)
+                .unwrap());
+            assert_eq!(
+                kzg.verify_blob_kzg_proof(&blob, &not_in_subgroup, &proof),
+                Ok(false),
+                "{backend}"
+            );
+        }
+    }
+
+    #[test]
+    fn backends_agree_() {
+        let mut rng = rand::thread_rng();
+        let blob = generate_random_blob_bytes(&mut rng);
+
+        let results = Backend::ENABLED
+            .iter()
+            .map(|backend| {
+                let kzg = load(*backend);
+                let commitment = kzg.blob_to_kzg_commitment(&blob).unwrap();
+                let proof = kzg.compute_blob_kzg_proof(&blob, &commitment).unwrap();
+                (commitment, proof)
+            })
+            .collect::<Vec<_>>();
+
+        assert!(results.windows(2).all(|pair| pair[0] == pair[1]));
+    }
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_kzg_fuzz::c_bindings::{check, CBindingsInput};
+
+fuzz_target!(|input: CBindingsInput| {
+    check(&input);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_kzg_fuzz::decoding::{check, DecodingInput};
+
+fuzz_target!(|input: DecodingInput| {
+    check(&input);
+});
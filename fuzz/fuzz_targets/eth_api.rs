@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_kzg_fuzz::eth::{check, Call};
+
+fuzz_target!(|call: Call| {
+    check(&call);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_kzg_fuzz::setup::{check, SetupInput};
+
+fuzz_target!(|input: SetupInput| {
+    check(&input);
+});
@@ -0,0 +1,204 @@
+//! Calls of the C binding entry points in `kzg::eth::c_bindings`, compared across backends.
+//!
+//! The exported `extern "C"` symbols of different backends collide, so the generic functions
+//! they wrap are called directly. The settings in the c-kzg-4844 layout are loaded through the
+//! `load_trusted_setup` exported by `blst`. Those collide with the symbols of the official
+//! bindings, so this module requires feature `c_bindings` and cannot be built with `ckzg`.
+
+use std::{mem::MaybeUninit, sync::OnceLock};
+
+use kzg::{
+    eip_4844::load_trusted_setup_string,
+    eth::{
+        c_bindings::{self, Blob, Bytes48, CKZGSettings, CKzgRet, Cell, KZGProof},
+        BYTES_PER_CELL, BYTES_PER_PROOF, CELLS_PER_EXT_BLOB,
+    },
+    EcBackend,
+};
+use libfuzzer_sys::arbitrary::{self, Arbitrary, Unstructured};
+use rust_kzg_blst::eip_4844::load_trusted_setup;
+
+use crate::{eth::Call, trusted_setup, Backends};
+
+/// Settings loaded by `load_trusted_setup`, intentionally leaked for the life of the fuzz process.
+struct Settings(CKZGSettings);
+
+// The settings are never modified after construction.
+unsafe impl Send for Settings {}
+unsafe impl Sync for Settings {}
+
+impl Settings {
+    fn load() -> Self {
+        let (g1_monomial, g1_lagrange, g2_monomial) =
+            load_trusted_setup_string(trusted_setup()).expect("failed to parse trusted setup");
+
+        let mut settings = MaybeUninit::<CKZGSettings>::uninit();
+        let ret = unsafe {
+            load_trusted_setup(
+                settings.as_mut_ptr(),
+                g1_monomial.as_ptr(),
+                g1_monomial.len() as u64,
+                g1_lagrange.as_ptr(),
+                g1_lagrange.len() as u64,
+                g2_monomial.as_ptr(),
+                g2_monomial.len() as u64,
+                0,
+            )
+        };
+        assert_eq!(ret, CKzgRet::Ok, "failed to load trusted setup");
+
+        Self(unsafe { settings.assume_init() })
+    }
+}
+
+/// Loaded once and shared by all iterations.
+fn settings() -> &'static CKZGSettings {
+    static SETTINGS: OnceLock<Settings> = OnceLock::new();
+    &SETTINGS.get_or_init(Settings::load).0
+}
+
+#[derive(Debug)]
+pub struct CBindingsInput {
+    pub call: Call,
+    /// Pass a null pointer instead of the cells output buffer (only where allowed).
+    pub null_cells: bool,
+    /// Pass a null pointer instead of the proofs output buffer.
+    pub null_proofs: bool,
+}
+
+impl<'a> Arbitrary<'a> for CBindingsInput {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self {
+            call: Call::arbitrary_cells_call(u)?,
+            null_cells: u.ratio(1, 4)?,
+            null_proofs: u.ratio(1, 4)?,
+        })
+    }
+}
+
+pub struct CBindingsOutcome {
+    ret: CKzgRet,
+    /// Outputs, compared only if the call succeeded.
+    cells: Option<Vec<[u8; BYTES_PER_CELL]>>,
+    proofs: Option<Vec<[u8; BYTES_PER_PROOF]>>,
+    ok: Option<bool>,
+}
+
+fn output<T: Copy>(null: bool, zero: T) -> Option<Vec<T>> {
+    (!null).then(|| vec![zero; CELLS_PER_EXT_BLOB])
+}
+
+fn output_ptr<T, U>(output: &mut Option<Vec<T>>) -> *mut U {
+    output
+        .as_mut()
+        .map_or(core::ptr::null_mut(), |output| output.as_mut_ptr().cast())
+}
+
+/// Calls the entry point of backend `B`. Byte arrays are passed in place of the `#[repr(C)]`
+/// wrappers, which have the same layout.
+pub fn run<B: EcBackend>(input: &CBindingsInput) -> CBindingsOutcome
+where
+    B::KZGSettings: for<'a> TryFrom<&'a CKZGSettings, Error = String>,
+{
+    let s: *const CKZGSettings = settings();
+    let mut ok = None;
+
+    let (ret, mut cells, mut proofs) = match &input.call {
+        Call::ComputeCellsAndKzgProofs { blob } => {
+            let mut cells = output(input.null_cells, [0u8; BYTES_PER_CELL]);
+            let mut proofs = output(input.null_proofs, [0u8; BYTES_PER_PROOF]);
+            let ret = unsafe {
+                c_bindings::compute_cells_and_kzg_proofs::<B, B::KZGSettings>(
+                    output_ptr::<_, Cell>(&mut cells),
+                    output_ptr::<_, KZGProof>(&mut proofs),
+                    blob.as_ptr().cast::<Blob>(),
+                    s,
+                )
+            };
+
+            (ret, cells, proofs)
+        }
+        Call::RecoverCellsAndKzgProofs {
+            cell_indices,
+            cells: input_cells,
+        } => {
+            let num_cells = cell_indices.len().min(input_cells.len());
+            let cell_indices = cell_indices.iter().map(|&i| i as u64).collect::<Vec<_>>();
+            // the cells output is required
+            let mut cells = output(false, [0u8; BYTES_PER_CELL]);
+            let mut proofs = output(input.null_proofs, [0u8; BYTES_PER_PROOF]);
+            let ret = unsafe {
+                c_bindings::recover_cells_and_kzg_proofs::<B, B::KZGSettings>(
+                    output_ptr::<_, Cell>(&mut cells),
+                    output_ptr::<_, KZGProof>(&mut proofs),
+                    cell_indices.as_ptr(),
+                    input_cells.as_ptr().cast::<Cell>(),
+                    num_cells as u64,
+                    s,
+                )
+            };
+
+            (ret, cells, proofs)
+        }
+        Call::VerifyCellKzgProofBatch {
+            commitments,
+            cell_indices,
+            cells,
+            proofs,
+        } => {
+            let num_cells = commitments
+                .len()
+                .min(cell_indices.len())
+                .min(cells.len())
+                .min(proofs.len());
+            let cell_indices = cell_indices.iter().map(|&i| i as u64).collect::<Vec<_>>();
+            let mut result = false;
+            let ret = unsafe {
+                c_bindings::verify_cell_kzg_proof_batch::<B, B::KZGSettings>(
+                    &mut result,
+                    commitments.as_ptr().cast::<Bytes48>(),
+                    cell_indices.as_ptr(),
+                    cells.as_ptr().cast::<Cell>(),
+                    proofs.as_ptr().cast::<Bytes48>(),
+                    num_cells as u64,
+                    s,
+                )
+            };
+            ok = Some(result);
+
+            (ret, None, None)
+        }
+        call => unreachable!("{} has no C binding", call.name()),
+    };
+
+    if ret != CKzgRet::Ok {
+        (cells, proofs, ok) = (None, None, None);
+    }
+
+    CBindingsOutcome {
+        ret,
+        cells,
+        proofs,
+        ok,
+    }
+}
+
+/// Fuzz target body: the entry points must not panic, and all backends must return the same
+/// status code and outputs.
+pub fn check(input: &CBindingsInput) {
+    let backends: Backends<CBindingsInput, CBindingsOutcome> = generic_backends!(run);
+
+    let expected = backends[0].1(input);
+    for (name, run) in &backends[1..] {
+        let received = run(input);
+        let call = input.call.name();
+
+        assert_eq!(expected.ret, received.ret, "backend: {name}, {call}");
+        assert_eq!(expected.ok, received.ok, "backend: {name}, {call}");
+        assert_eq!(expected.proofs, received.proofs, "backend: {name}, {call}");
+        assert!(
+            expected.cells == received.cells,
+            "backend: {name}, {call}: cells differ"
+        );
+    }
+}
@@ -0,0 +1,182 @@
+//! [`KzgApi`] implementation over the official c-kzg-4844 Rust bindings, used as an independent
+//! reference in differential fuzzing.
+
+use c_kzg::{Blob, Bytes32, Bytes48, Cell, KzgSettings};
+use kzg::eth::{
+    eip_7594::CellsKzgProofs, BYTES_PER_BLOB, BYTES_PER_CELL, BYTES_PER_COMMITMENT,
+    BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
+};
+use rust_kzg_dyn::KzgApi;
+
+pub struct CKzg {
+    settings: KzgSettings,
+}
+
+impl CKzg {
+    pub fn load_trusted_setup_string(contents: &str) -> Result<Self, String> {
+        KzgSettings::parse_kzg_trusted_setup(contents, 0)
+            .map(|settings| Self { settings })
+            .map_err(|e| e.to_string())
+    }
+}
+
+fn blobs(blobs: &[[u8; BYTES_PER_BLOB]]) -> Vec<Blob> {
+    blobs.iter().map(|blob| Blob::new(*blob)).collect()
+}
+
+fn bytes48(bytes: &[[u8; 48]]) -> Vec<Bytes48> {
+    bytes.iter().map(|bytes| Bytes48::new(*bytes)).collect()
+}
+
+fn cells(cells: &[[u8; BYTES_PER_CELL]]) -> Vec<Cell> {
+    cells.iter().map(|cell| Cell::new(*cell)).collect()
+}
+
+fn indices(cell_indices: &[usize]) -> Vec<u64> {
+    cell_indices.iter().map(|&index| index as u64).collect()
+}
+
+impl KzgApi for CKzg {
+    fn blob_to_kzg_commitment(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<[u8; BYTES_PER_COMMITMENT], String> {
+        self.settings
+            .blob_to_kzg_commitment(&Blob::new(*blob))
+            .map(|commitment| commitment.to_bytes().into_inner())
+            .map_err(|e| e.to_string())
+    }
+
+    fn compute_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        z: &[u8; BYTES_PER_FIELD_ELEMENT],
+    ) -> Result<([u8; BYTES_PER_PROOF], [u8; BYTES_PER_FIELD_ELEMENT]), String> {
+        self.settings
+            .compute_kzg_proof(&Blob::new(*blob), &Bytes32::new(*z))
+            .map(|(proof, y)| (proof.to_bytes().into_inner(), *y))
+            .map_err(|e| e.to_string())
+    }
+
+    fn compute_blob_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+    ) -> Result<[u8; BYTES_PER_PROOF], String> {
+        self.settings
+            .compute_blob_kzg_proof(&Blob::new(*blob), &Bytes48::new(*commitment))
+            .map(|proof| proof.to_bytes().into_inner())
+            .map_err(|e| e.to_string())
+    }
+
+    fn verify_kzg_proof(
+        &self,
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+        z: &[u8; BYTES_PER_FIELD_ELEMENT],
+        y: &[u8; BYTES_PER_FIELD_ELEMENT],
+        proof: &[u8; BYTES_PER_PROOF],
+    ) -> Result<bool, String> {
+        self.settings
+            .verify_kzg_proof(
+                &Bytes48::new(*commitment),
+                &Bytes32::new(*z),
+                &Bytes32::new(*y),
+                &Bytes48::new(*proof),
+            )
+            .map_err(|e| e.to_string())
+    }
+
+    fn verify_blob_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+        proof: &[u8; BYTES_PER_PROOF],
+    ) -> Result<bool, String> {
+        self.settings
+            .verify_blob_kzg_proof(
+                &Blob::new(*blob),
+                &Bytes48::new(*commitment),
+                &Bytes48::new(*proof),
+            )
+            .map_err(|e| e.to_string())
+    }
+
+    fn verify_blob_kzg_proof_batch(
+        &self,
+        blobs_bytes: &[[u8; BYTES_PER_BLOB]],
+        commitments: &[[u8; BYTES_PER_COMMITMENT]],
+        proofs: &[[u8; BYTES_PER_PROOF]],
+    ) -> Result<bool, String> {
+        self.settings
+            .verify_blob_kzg_proof_batch(
+                &blobs(blobs_bytes),
+                &bytes48(commitments),
+                &bytes48(proofs),
+            )
+            .map_err(|e| e.to_string())
+    }
+
+    fn compute_cells(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<Vec<[u8; BYTES_PER_CELL]>, String> {
+        self.settings
+            .compute_cells(&Blob::new(*blob))
+            .map(|cells| cells.iter().map(|cell| cell.to_bytes()).collect())
+            .map_err(|e| e.to_string())
+    }
+
+    fn compute_cells_and_kzg_proofs(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<CellsKzgProofs, String> {
+        self.settings
+            .compute_cells_and_kzg_proofs(&Blob::new(*blob))
+            .map(|(cells, proofs)| {
+                (
+                    cells.iter().map(|cell| cell.to_bytes()).collect(),
+                    proofs
+                        .iter()
+                        .map(|proof| proof.to_bytes().into_inner())
+                        .collect(),
+                )
+            })
+            .map_err(|e| e.to_string())
+    }
+
+    fn recover_cells_and_kzg_proofs(
+        &self,
+        cell_indices: &[usize],
+        cells_bytes: &[[u8; BYTES_PER_CELL]],
+    ) -> Result<CellsKzgProofs, String> {
+        self.settings
+            .recover_cells_and_kzg_proofs(&indices(cell_indices), &cells(cells_bytes))
+            .map(|(cells, proofs)| {
+                (
+                    cells.iter().map(|cell| cell.to_bytes()).collect(),
+                    proofs
+                        .iter()
+                        .map(|proof| proof.to_bytes().into_inner())
+                        .collect(),
+                )
+            })
+            .map_err(|e| e.to_string())
+    }
+
+    fn verify_cell_kzg_proof_batch(
+        &self,
+        commitments: &[[u8; BYTES_PER_COMMITMENT]],
+        cell_indices: &[usize],
+        cells_bytes: &[[u8; BYTES_PER_CELL]],
+        proofs: &[[u8; BYTES_PER_PROOF]],
+    ) -> Result<bool, String> {
+        self.settings
+            .verify_cell_kzg_proof_batch(
+                &bytes48(commitments),
+                &indices(cell_indices),
+                &cells(cells_bytes),
+                &bytes48(proofs),
+            )
+            .map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,182 @@
+//! Decoding of untrusted bytes into field elements, points and blobs, compared across backends.
+
+use kzg::{
+    eip_4844::{
+        bytes_to_blob, BYTES_PER_BLOB, BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2,
+    },
+    EcBackend, Fr, G1Affine, G2Mul, G1, G2,
+};
+use libfuzzer_sys::arbitrary::{self, Arbitrary, Unstructured};
+use rust_kzg_blst::types::{fr::FsFr, g1::FsG1Affine, g2::FsG2};
+
+use crate::{
+    encoding::{self, g1_generator, g1_off_subgroup, g2_generator, g2_off_subgroup, FieldElement},
+    Backends,
+};
+
+#[derive(Debug)]
+pub struct DecodingInput {
+    pub field_elements: Vec<FieldElement>,
+    pub g1: Vec<[u8; BYTES_PER_G1]>,
+    pub g2: Vec<[u8; BYTES_PER_G2]>,
+    /// Field elements of a blob; all other elements of the blob are zero. `None` makes the
+    /// blob one field element too short.
+    pub blob: Option<Vec<(usize, FieldElement)>>,
+}
+
+impl<'a> Arbitrary<'a> for DecodingInput {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut field_elements = Vec::new();
+        for _ in 0..u.int_in_range(0u8..=8)? {
+            field_elements.push(if u.arbitrary()? {
+                FsFr::arbitrary(u)?.to_bytes()
+            } else {
+                encoding::field_element(u, true)?
+            });
+        }
+
+        let mut g1 = Vec::new();
+        for _ in 0..u.int_in_range(0u8..=8)? {
+            let valid = FsG1Affine::arbitrary(u)?.to_proj().to_bytes();
+            g1.push(if u.arbitrary()? {
+                valid
+            } else {
+                encoding::point(u, &valid, &g1_generator())?
+            });
+        }
+
+        let mut g2 = Vec::new();
+        for _ in 0..u.int_in_range(0u8..=4)? {
+            let valid = FsG2::generator().mul(&FsFr::arbitrary(u)?).to_bytes();
+            g2.push(if u.arbitrary()? {
+                valid
+            } else {
+                encoding::point(u, &valid, &g2_generator())?
+            });
+        }
+
+        let blob = if u.ratio(1, 16)? {
+            None
+        } else {
+            let mut elements = Vec::new();
+            for _ in 0..u.int_in_range(0u8..=4)? {
+                let index = u.choose_index(BYTES_PER_BLOB / BYTES_PER_FIELD_ELEMENT)?;
+                let adversarial = u.arbitrary()?;
+                elements.push((index, encoding::field_element(u, adversarial)?));
+            }
+
+            Some(elements)
+        };
+
+        Ok(Self {
+            field_elements,
+            g1,
+            g2,
+            blob,
+        })
+    }
+}
+
+impl DecodingInput {
+    fn blob_bytes(&self) -> Vec<u8> {
+        match &self.blob {
+            Some(elements) => {
+                let mut bytes = vec![0u8; BYTES_PER_BLOB];
+                for (index, value) in elements {
+                    bytes[index * BYTES_PER_FIELD_ELEMENT..(index + 1) * BYTES_PER_FIELD_ELEMENT]
+                        .copy_from_slice(value);
+                }
+
+                bytes
+            }
+            None => vec![0u8; BYTES_PER_BLOB - BYTES_PER_FIELD_ELEMENT],
+        }
+    }
+}
+
+/// Re-encoded values, or `None` where decoding failed.
+pub struct DecodingOutcome {
+    pub field_elements: Vec<Option<FieldElement>>,
+    pub g1: Vec<Option<[u8; BYTES_PER_G1]>>,
+    pub g2: Vec<Option<[u8; BYTES_PER_G2]>>,
+    pub blob: Option<Vec<FieldElement>>,
+}
+
+pub fn decode<B: EcBackend>(input: &DecodingInput) -> DecodingOutcome {
+    DecodingOutcome {
+        field_elements: input
+            .field_elements
+            .iter()
+            .map(|bytes| B::Fr::from_bytes(bytes).ok().map(|fr| fr.to_bytes()))
+            .collect(),
+        g1: input
+            .g1
+            .iter()
+            .map(|bytes| B::G1::from_bytes(bytes).ok().map(|g1| g1.to_bytes()))
+            .collect(),
+        g2: input
+            .g2
+            .iter()
+            .map(|bytes| B::G2::from_bytes(bytes).ok().map(|g2| g2.to_bytes()))
+            .collect(),
+        blob: bytes_to_blob::<B::Fr>(&input.blob_bytes())
+            .ok()
+            .map(|blob| blob.iter().map(|fr| fr.to_bytes()).collect()),
+    }
+}
+
+/// Fuzz target body: decoding must not panic, and all backends must accept the same encodings
+/// and re-encode them identically.
+pub fn check(input: &DecodingInput) {
+    let backends: Backends<DecodingInput, DecodingOutcome> = generic_backends!(decode);
+    let g1_off_subgroup = input.g1.iter().map(g1_off_subgroup).collect::<Vec<_>>();
+    let g2_off_subgroup = input.g2.iter().map(g2_off_subgroup).collect::<Vec<_>>();
+
+    let expected = backends[0].1(input);
+    for (name, decode) in &backends[1..] {
+        let received = decode(input);
+
+        assert_eq!(
+            expected.field_elements, received.field_elements,
+            "backend: {name}, field elements"
+        );
+        assert_eq!(
+            masked(&expected.g1, &g1_off_subgroup),
+            masked(&received.g1, &g1_off_subgroup),
+            "backend: {name}, G1 points"
+        );
+        assert_eq!(
+            masked(&expected.g2, &g2_off_subgroup),
+            masked(&received.g2, &g2_off_subgroup),
+            "backend: {name}, G2 points"
+        );
+        assert!(
+            expected.blob == received.blob,
+            "backend: {name}, blob: expected {}, received {}",
+            describe_blob(&expected.blob),
+            describe_blob(&received.blob)
+        );
+    }
+}
+
+/// Replaces results for encodings of points outside of the subgroup with `None`.
+fn masked<T: Copy>(points: &[Option<T>], off_subgroup: &[bool]) -> Vec<Option<T>> {
+    points
+        .iter()
+        .zip(off_subgroup)
+        .map(|(point, &off_subgroup)| if off_subgroup { None } else { *point })
+        .collect()
+}
+
+fn describe_blob(blob: &Option<Vec<FieldElement>>) -> String {
+    match blob {
+        Some(blob) => format!(
+            "{} field elements, first non-zero: {:?}",
+            blob.len(),
+            blob.iter()
+                .position(|fr| fr.iter().any(|&byte| byte != 0))
+                .map(|index| (index, hex::encode(blob[index])))
+        ),
+        None => "rejected".to_string(),
+    }
+}
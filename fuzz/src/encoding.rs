@@ -0,0 +1,129 @@
+//! Generators of valid and adversarial encodings of field elements and compressed points.
+
+use kzg::{eip_4844::BYTES_PER_FIELD_ELEMENT, G1, G2};
+use libfuzzer_sys::arbitrary::{self, Unstructured};
+use rust_kzg_blst::types::{g1::FsG1, g2::FsG2};
+
+pub type FieldElement = [u8; BYTES_PER_FIELD_ELEMENT];
+
+/// Scalar field modulus, big-endian.
+pub const BLS_MODULUS: FieldElement = [
+    0x73, 0xed, 0xa7, 0x53, 0x29, 0x9d, 0x7d, 0x48, 0x33, 0x39, 0xd8, 0x08, 0x09, 0xa1, 0xd8, 0x05,
+    0x53, 0xbd, 0xa4, 0x02, 0xff, 0xfe, 0x5b, 0xfe, 0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01,
+];
+
+pub fn g1_generator() -> [u8; 48] {
+    FsG1::generator().to_bytes()
+}
+
+pub fn g2_generator() -> [u8; 96] {
+    FsG2::generator().to_bytes()
+}
+
+/// Whether `bytes` encode a G1 point on the curve, but outside of the prime order subgroup.
+/// Only some backends check the subgroup in `G1::from_bytes` (the Ethereum APIs check it
+/// separately), so acceptance of such encodings is not compared.
+pub fn g1_off_subgroup(bytes: &[u8; 48]) -> bool {
+    FsG1::from_bytes(bytes).is_ok_and(|point| !point.is_valid())
+}
+
+/// Same as [`g1_off_subgroup`], for G2.
+pub fn g2_off_subgroup(bytes: &[u8; 96]) -> bool {
+    FsG2::from_bytes(bytes).is_ok_and(|point| unsafe { !blst::blst_p2_in_g2(&point.0) })
+}
+
+/// Canonical field element when `adversarial` is false. Otherwise also zero, one, values
+/// around the modulus, or arbitrary (most likely non-canonical) bytes.
+pub fn field_element(u: &mut Unstructured, adversarial: bool) -> arbitrary::Result<FieldElement> {
+    let mut value = [0u8; BYTES_PER_FIELD_ELEMENT];
+    let kind = if adversarial {
+        u.int_in_range(0u8..=7)?
+    } else {
+        7
+    };
+
+    match kind {
+        0 => {}
+        1 => value[BYTES_PER_FIELD_ELEMENT - 1] = 1,
+        2 => value = sub_small(&BLS_MODULUS, 1),
+        3 => value = BLS_MODULUS,
+        4 => value = add_small(&BLS_MODULUS, u.arbitrary()?),
+        5 => value = [0xff; BYTES_PER_FIELD_ELEMENT],
+        6 => u.fill_buffer(&mut value)?,
+        _ => {
+            u.fill_buffer(&mut value)?;
+            value[0] &= 0x3f;
+        }
+    }
+
+    Ok(value)
+}
+
+/// Compressed G1 or G2 point, derived from the valid point `base`: infinity (with or without
+/// trailing garbage), `generator`, `base` with flags changed, `base` with the x coordinate
+/// changed (very likely off the curve or off the subgroup), or arbitrary bytes.
+pub fn point<const N: usize>(
+    u: &mut Unstructured,
+    base: &[u8; N],
+    generator: &[u8; N],
+) -> arbitrary::Result<[u8; N]> {
+    let mut point = [0u8; N];
+
+    match u.int_in_range(0u8..=7)? {
+        0 => point[0] = 0xc0,
+        1 => {
+            point[0] = 0xc0;
+            point[u.choose_index(N)?] |= u.int_in_range(1u8..=0xff)?;
+        }
+        2 => point = *generator,
+        3 => {
+            // negation of the point
+            point = *base;
+            point[0] ^= 0x20;
+        }
+        4 => {
+            // without compression flag
+            point = *base;
+            point[0] &= 0x7f;
+        }
+        5 => {
+            // with infinity flag
+            point = *base;
+            point[0] |= 0x40;
+        }
+        6 => {
+            point = *base;
+            point[N - 1] ^= u.int_in_range(1u8..=0xff)?;
+        }
+        _ => {
+            u.fill_buffer(&mut point)?;
+            point[0] = (point[0] & 0x1f) | 0x80;
+        }
+    }
+
+    Ok(point)
+}
+
+fn add_small(value: &FieldElement, n: u8) -> FieldElement {
+    let mut result = *value;
+    let mut carry = n as u16;
+    for byte in result.iter_mut().rev() {
+        let sum = *byte as u16 + carry;
+        *byte = sum as u8;
+        carry = sum >> 8;
+    }
+
+    result
+}
+
+fn sub_small(value: &FieldElement, n: u8) -> FieldElement {
+    let mut result = *value;
+    let mut borrow = n as i16;
+    for byte in result.iter_mut().rev() {
+        let diff = *byte as i16 - borrow;
+        *byte = diff.rem_euclid(256) as u8;
+        borrow = if diff < 0 { 1 } else { 0 };
+    }
+
+    result
+}
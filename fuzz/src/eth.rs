@@ -0,0 +1,882 @@
+//! Differential testing of the byte-level Ethereum (EIP-4844 and EIP-7594) APIs.
+//!
+//! Every backend enabled through crate features, plus the official c-kzg-4844 bindings with
+//! feature `ckzg`, executes the same [`Call`]. Results are compared against the first
+//! implementation (`blst`). On disagreement the input is shrunk while the same implementation
+//! still disagrees, saved to a file, and reported.
+
+use std::{
+    fmt::{self, Debug, Formatter},
+    fs::File,
+    io::{self, Write},
+    path::PathBuf,
+    sync::OnceLock,
+};
+
+use kzg::eth::{
+    eip_7594::CellsKzgProofs, BYTES_PER_BLOB, BYTES_PER_CELL, BYTES_PER_COMMITMENT,
+    BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF, CELLS_PER_EXT_BLOB, FIELD_ELEMENTS_PER_BLOB,
+    FIELD_ELEMENTS_PER_CELL,
+};
+use libfuzzer_sys::arbitrary::{self, Arbitrary, Unstructured};
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use rust_kzg_dyn::{Backend, DynKzg, KzgApi};
+use sha2::Digest;
+
+use crate::{
+    encoding::{self, g1_generator, FieldElement},
+    print_saved_case, trusted_setup,
+};
+
+type Blob = [u8; BYTES_PER_BLOB];
+type Cell = [u8; BYTES_PER_CELL];
+type G1Bytes = [u8; BYTES_PER_COMMITMENT];
+
+/// Upper bound on the number of shrinking attempts, as each attempt runs the call again.
+const MAX_SHRINK_ATTEMPTS: usize = 256;
+
+pub struct Implementation {
+    name: &'static str,
+    api: Box<dyn KzgApi>,
+}
+
+impl Implementation {
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn run(&self, call: &Call) -> Outcome {
+        let api = self.api.as_ref();
+
+        match call {
+            Call::BlobToKzgCommitment { blob } => {
+                api.blob_to_kzg_commitment(blob).map(Outcome::Commitment)
+            }
+            Call::ComputeKzgProof { blob, z } => api
+                .compute_kzg_proof(blob, z)
+                .map(|(proof, y)| Outcome::ProofAndEvaluation(proof, y)),
+            Call::ComputeBlobKzgProof { blob, commitment } => api
+                .compute_blob_kzg_proof(blob, commitment)
+                .map(Outcome::Proof),
+            Call::VerifyKzgProof {
+                commitment,
+                z,
+                y,
+                proof,
+            } => api
+                .verify_kzg_proof(commitment, z, y, proof)
+                .map(Outcome::Verified),
+            Call::VerifyBlobKzgProof {
+                blob,
+                commitment,
+                proof,
+            } => api
+                .verify_blob_kzg_proof(blob, commitment, proof)
+                .map(Outcome::Verified),
+            Call::VerifyBlobKzgProofBatch {
+                blobs,
+                commitments,
+                proofs,
+            } => api
+                .verify_blob_kzg_proof_batch(blobs, commitments, proofs)
+                .map(Outcome::Verified),
+            Call::ComputeCells { blob } => api.compute_cells(blob).map(Outcome::Cells),
+            Call::ComputeCellsAndKzgProofs { blob } => api
+                .compute_cells_and_kzg_proofs(blob)
+                .map(Outcome::CellsAndProofs),
+            Call::RecoverCellsAndKzgProofs {
+                cell_indices,
+                cells,
+            } => api
+                .recover_cells_and_kzg_proofs(cell_indices, cells)
+                .map(Outcome::CellsAndProofs),
+            Call::VerifyCellKzgProofBatch {
+                commitments,
+                cell_indices,
+                cells,
+                proofs,
+            } => api
+                .verify_cell_kzg_proof_batch(commitments, cell_indices, cells, proofs)
+                .map(Outcome::Verified),
+        }
+        .unwrap_or(Outcome::Rejected)
+    }
+}
+
+#[cfg(feature = "ckzg")]
+fn ckzg_implementation(contents: &str) -> Option<Implementation> {
+    let api = crate::ckzg::CKzg::load_trusted_setup_string(contents)
+        .expect("failed to load trusted setup into ckzg");
+
+    Some(Implementation {
+        name: "ckzg",
+        api: Box::new(api),
+    })
+}
+
+#[cfg(not(feature = "ckzg"))]
+fn ckzg_implementation(_contents: &str) -> Option<Implementation> {
+    None
+}
+
+static IMPLEMENTATIONS: OnceLock<Vec<Implementation>> = OnceLock::new();
+
+/// All enabled implementations, `blst` first.
+pub fn implementations() -> &'static [Implementation] {
+    IMPLEMENTATIONS.get_or_init(|| {
+        let contents = trusted_setup();
+
+        Backend::ENABLED
+            .iter()
+            .map(|backend| Implementation {
+                name: backend.name(),
+                api: Box::new(
+                    DynKzg::load_trusted_setup_string(*backend, contents)
+                        .expect("failed to load trusted setup"),
+                ),
+            })
+            .chain(ckzg_implementation(contents))
+            .collect()
+    })
+}
+
+fn reference() -> &'static Implementation {
+    &implementations()[0]
+}
+
+/// Result of a [`Call`], normalized so that implementations can be compared.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Commitment(G1Bytes),
+    ProofAndEvaluation([u8; BYTES_PER_PROOF], FieldElement),
+    Proof([u8; BYTES_PER_PROOF]),
+    Verified(bool),
+    Cells(Vec<Cell>),
+    CellsAndProofs(CellsKzgProofs),
+    /// Input was rejected. Error messages are not compared, as they differ between
+    /// implementations.
+    Rejected,
+}
+
+impl Debug for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Commitment(commitment) => write!(f, "commitment 0x{}", hex::encode(commitment)),
+            Self::ProofAndEvaluation(proof, y) => {
+                write!(f, "proof 0x{}, y 0x{}", hex::encode(proof), hex::encode(y))
+            }
+            Self::Proof(proof) => write!(f, "proof 0x{}", hex::encode(proof)),
+            Self::Verified(verified) => write!(f, "verified {verified}"),
+            Self::Cells(cells) => write!(f, "{} cells, digest 0x{}", cells.len(), digest(cells)),
+            Self::CellsAndProofs((cells, proofs)) => write!(
+                f,
+                "{} cells and {} proofs, digest 0x{}",
+                cells.len(),
+                proofs.len(),
+                digest(
+                    cells
+                        .iter()
+                        .map(|cell| cell.as_slice())
+                        .chain(proofs.iter().map(|proof| proof.as_slice()))
+                )
+            ),
+            Self::Rejected => write!(f, "rejected"),
+        }
+    }
+}
+
+fn digest<T: AsRef<[u8]>>(items: impl IntoIterator<Item = T>) -> String {
+    let mut hasher = sha2::Sha256::new();
+    for item in items {
+        hasher.update(item);
+    }
+
+    hex::encode(&hasher.finalize()[0..8])
+}
+
+/// Single invocation of a byte-level API.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Call {
+    BlobToKzgCommitment {
+        blob: Box<Blob>,
+    },
+    ComputeKzgProof {
+        blob: Box<Blob>,
+        z: FieldElement,
+    },
+    ComputeBlobKzgProof {
+        blob: Box<Blob>,
+        commitment: G1Bytes,
+    },
+    VerifyKzgProof {
+        commitment: G1Bytes,
+        z: FieldElement,
+        y: FieldElement,
+        proof: G1Bytes,
+    },
+    VerifyBlobKzgProof {
+        blob: Box<Blob>,
+        commitment: G1Bytes,
+        proof: G1Bytes,
+    },
+    VerifyBlobKzgProofBatch {
+        blobs: Vec<Blob>,
+        commitments: Vec<G1Bytes>,
+        proofs: Vec<G1Bytes>,
+    },
+    ComputeCells {
+        blob: Box<Blob>,
+    },
+    ComputeCellsAndKzgProofs {
+        blob: Box<Blob>,
+    },
+    RecoverCellsAndKzgProofs {
+        cell_indices: Vec<usize>,
+        cells: Vec<Cell>,
+    },
+    VerifyCellKzgProofBatch {
+        commitments: Vec<G1Bytes>,
+        cell_indices: Vec<usize>,
+        cells: Vec<Cell>,
+        proofs: Vec<G1Bytes>,
+    },
+}
+
+impl Call {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::BlobToKzgCommitment { .. } => "blob_to_kzg_commitment",
+            Self::ComputeKzgProof { .. } => "compute_kzg_proof",
+            Self::ComputeBlobKzgProof { .. } => "compute_blob_kzg_proof",
+            Self::VerifyKzgProof { .. } => "verify_kzg_proof",
+            Self::VerifyBlobKzgProof { .. } => "verify_blob_kzg_proof",
+            Self::VerifyBlobKzgProofBatch { .. } => "verify_blob_kzg_proof_batch",
+            Self::ComputeCells { .. } => "compute_cells",
+            Self::ComputeCellsAndKzgProofs { .. } => "compute_cells_and_kzg_proofs",
+            Self::RecoverCellsAndKzgProofs { .. } => "recover_cells_and_kzg_proofs",
+            Self::VerifyCellKzgProofBatch { .. } => "verify_cell_kzg_proof_batch",
+        }
+    }
+
+    /// Smaller variants of this call: list entries removed (at the same position of every
+    /// list, so that lengths stay consistent), and blob chunks or cells zeroed.
+    fn shrink_candidates(&self) -> Vec<Call> {
+        let mut candidates = Vec::new();
+
+        match self {
+            Self::BlobToKzgCommitment { blob } => {
+                for blob in shrink_blob(blob) {
+                    candidates.push(Self::BlobToKzgCommitment { blob });
+                }
+            }
+            Self::ComputeKzgProof { blob, z } => {
+                for blob in shrink_blob(blob) {
+                    candidates.push(Self::ComputeKzgProof { blob, z: *z });
+                }
+            }
+            Self::ComputeBlobKzgProof { blob, commitment } => {
+                for blob in shrink_blob(blob) {
+                    candidates.push(Self::ComputeBlobKzgProof {
+                        blob,
+                        commitment: *commitment,
+                    });
+                }
+            }
+            Self::VerifyKzgProof { .. } => {}
+            Self::VerifyBlobKzgProof {
+                blob,
+                commitment,
+                proof,
+            } => {
+                for blob in shrink_blob(blob) {
+                    candidates.push(Self::VerifyBlobKzgProof {
+                        blob,
+                        commitment: *commitment,
+                        proof: *proof,
+                    });
+                }
+            }
+            Self::VerifyBlobKzgProofBatch {
+                blobs,
+                commitments,
+                proofs,
+            } => {
+                let len = blobs.len().max(commitments.len()).max(proofs.len());
+                for i in 0..len {
+                    candidates.push(Self::VerifyBlobKzgProofBatch {
+                        blobs: remove_at(blobs, i),
+                        commitments: remove_at(commitments, i),
+                        proofs: remove_at(proofs, i),
+                    });
+                }
+
+                for (i, blob) in blobs.iter().enumerate() {
+                    for shrunk in shrink_blob(blob) {
+                        let mut blobs = blobs.clone();
+                        blobs[i] = *shrunk;
+                        candidates.push(Self::VerifyBlobKzgProofBatch {
+                            blobs,
+                            commitments: commitments.clone(),
+                            proofs: proofs.clone(),
+                        });
+                    }
+                }
+            }
+            Self::ComputeCells { blob } => {
+                for blob in shrink_blob(blob) {
+                    candidates.push(Self::ComputeCells { blob });
+                }
+            }
+            Self::ComputeCellsAndKzgProofs { blob } => {
+                for blob in shrink_blob(blob) {
+                    candidates.push(Self::ComputeCellsAndKzgProofs { blob });
+                }
+            }
+            Self::RecoverCellsAndKzgProofs {
+                cell_indices,
+                cells,
+            } => {
+                let len = cell_indices.len().max(cells.len());
+                for i in 0..len {
+                    candidates.push(Self::RecoverCellsAndKzgProofs {
+                        cell_indices: remove_at(cell_indices, i),
+                        cells: remove_at(cells, i),
+                    });
+                }
+
+                for cells in zero_cells(cells) {
+                    candidates.push(Self::RecoverCellsAndKzgProofs {
+                        cell_indices: cell_indices.clone(),
+                        cells,
+                    });
+                }
+            }
+            Self::VerifyCellKzgProofBatch {
+                commitments,
+                cell_indices,
+                cells,
+                proofs,
+            } => {
+                let len = commitments
+                    .len()
+                    .max(cell_indices.len())
+                    .max(cells.len())
+                    .max(proofs.len());
+                for i in 0..len {
+                    candidates.push(Self::VerifyCellKzgProofBatch {
+                        commitments: remove_at(commitments, i),
+                        cell_indices: remove_at(cell_indices, i),
+                        cells: remove_at(cells, i),
+                        proofs: remove_at(proofs, i),
+                    });
+                }
+
+                for cells in zero_cells(cells) {
+                    candidates.push(Self::VerifyCellKzgProofBatch {
+                        commitments: commitments.clone(),
+                        cell_indices: cell_indices.clone(),
+                        cells,
+                        proofs: proofs.clone(),
+                    });
+                }
+            }
+        }
+
+        candidates.retain(|candidate| candidate != self);
+        candidates
+    }
+
+    fn write_to(&self, w: &mut impl Write) -> io::Result<()> {
+        fn field(w: &mut impl Write, name: &str, bytes: &[u8]) -> io::Result<()> {
+            writeln!(w, "{name}: 0x{}", hex::encode(bytes))
+        }
+
+        fn fields<T: AsRef<[u8]>>(w: &mut impl Write, name: &str, items: &[T]) -> io::Result<()> {
+            for item in items {
+                field(w, name, item.as_ref())?;
+            }
+
+            Ok(())
+        }
+
+        writeln!(w, "call: {}", self.name())?;
+
+        match self {
+            Self::BlobToKzgCommitment { blob }
+            | Self::ComputeCells { blob }
+            | Self::ComputeCellsAndKzgProofs { blob } => field(w, "blob", blob.as_slice()),
+            Self::ComputeKzgProof { blob, z } => {
+                field(w, "blob", blob.as_slice())?;
+                field(w, "z", z)
+            }
+            Self::ComputeBlobKzgProof { blob, commitment } => {
+                field(w, "blob", blob.as_slice())?;
+                field(w, "commitment", commitment)
+            }
+            Self::VerifyKzgProof {
+                commitment,
+                z,
+                y,
+                proof,
+            } => {
+                field(w, "commitment", commitment)?;
+                field(w, "z", z)?;
+                field(w, "y", y)?;
+                field(w, "proof", proof)
+            }
+            Self::VerifyBlobKzgProof {
+                blob,
+                commitment,
+                proof,
+            } => {
+                field(w, "blob", blob.as_slice())?;
+                field(w, "commitment", commitment)?;
+                field(w, "proof", proof)
+            }
+            Self::VerifyBlobKzgProofBatch {
+                blobs,
+                commitments,
+                proofs,
+            } => {
+                fields(w, "blob", blobs)?;
+                fields(w, "commitment", commitments)?;
+                fields(w, "proof", proofs)
+            }
+            Self::RecoverCellsAndKzgProofs {
+                cell_indices,
+                cells,
+            } => {
+                writeln!(w, "cell_indices: {cell_indices:?}")?;
+                fields(w, "cell", cells)
+            }
+            Self::VerifyCellKzgProofBatch {
+                commitments,
+                cell_indices,
+                cells,
+                proofs,
+            } => {
+                fields(w, "commitment", commitments)?;
+                writeln!(w, "cell_indices: {cell_indices:?}")?;
+                fields(w, "cell", cells)?;
+                fields(w, "proof", proofs)
+            }
+        }
+    }
+}
+
+impl Debug for Call {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(self.name())
+            .field(&"Failing test case was saved to a file in current working directory")
+            .finish()
+    }
+}
+
+fn remove_at<T: Clone>(items: &[T], index: usize) -> Vec<T> {
+    let mut items = items.to_vec();
+    if index < items.len() {
+        items.remove(index);
+    }
+
+    items
+}
+
+/// Blob with each aligned chunk of field elements zeroed, from halves down to cell-sized
+/// chunks.
+fn shrink_blob(blob: &Blob) -> Vec<Box<Blob>> {
+    let mut shrunk = Vec::new();
+    let mut chunk = FIELD_ELEMENTS_PER_BLOB / 2;
+
+    while chunk >= FIELD_ELEMENTS_PER_CELL {
+        for start in (0..FIELD_ELEMENTS_PER_BLOB).step_by(chunk) {
+            let range = start * BYTES_PER_FIELD_ELEMENT..(start + chunk) * BYTES_PER_FIELD_ELEMENT;
+            if blob[range.clone()].iter().any(|&byte| byte != 0) {
+                let mut blob = Box::new(*blob);
+                blob[range].fill(0);
+                shrunk.push(blob);
+            }
+        }
+
+        chunk /= 2;
+    }
+
+    shrunk
+}
+
+fn zero_cells(cells: &[Cell]) -> Vec<Vec<Cell>> {
+    (0..cells.len())
+        .filter(|&i| cells[i].iter().any(|&byte| byte != 0))
+        .map(|i| {
+            let mut cells = cells.to_vec();
+            cells[i] = [0; BYTES_PER_CELL];
+            cells
+        })
+        .collect()
+}
+
+pub struct Disagreement {
+    pub implementation: &'static str,
+    pub expected: Outcome,
+    pub received: Outcome,
+}
+
+fn compare(
+    call: &Call,
+    expected: &Outcome,
+    implementation: &Implementation,
+) -> Option<Disagreement> {
+    let received = implementation.run(call);
+
+    (received != *expected).then(|| Disagreement {
+        implementation: implementation.name(),
+        expected: expected.clone(),
+        received,
+    })
+}
+
+/// Runs the call on every implementation and returns the first one disagreeing with `blst`.
+pub fn find_disagreement(call: &Call) -> Option<Disagreement> {
+    let expected = reference().run(call);
+
+    implementations()[1..]
+        .iter()
+        .find_map(|implementation| compare(call, &expected, implementation))
+}
+
+/// Greedily shrinks the call while the implementation from `disagreement` keeps disagreeing.
+pub fn minimize(mut call: Call, mut disagreement: Disagreement) -> (Call, Disagreement) {
+    let implementation = implementations()
+        .iter()
+        .find(|implementation| implementation.name() == disagreement.implementation)
+        .expect("disagreeing implementation must exist");
+
+    let mut attempts = 0;
+    'shrink: while attempts < MAX_SHRINK_ATTEMPTS {
+        for candidate in call.shrink_candidates() {
+            attempts += 1;
+            if attempts > MAX_SHRINK_ATTEMPTS {
+                break 'shrink;
+            }
+
+            let expected = reference().run(&candidate);
+            if let Some(found) = compare(&candidate, &expected, implementation) {
+                call = candidate;
+                disagreement = found;
+                continue 'shrink;
+            }
+        }
+
+        break;
+    }
+
+    (call, disagreement)
+}
+
+pub fn save_case(call: &Call, disagreement: &Disagreement) -> io::Result<PathBuf> {
+    let mut contents = Vec::new();
+    call.write_to(&mut contents)?;
+
+    let path = PathBuf::from(format!(
+        "./failing_eth_case_{}.txt",
+        digest([contents.as_slice()])
+    ));
+    let mut w = File::create(&path)?;
+    writeln!(
+        w,
+        "# {} disagrees with {}",
+        disagreement.implementation,
+        reference().name()
+    )?;
+    writeln!(w, "# expected: {:?}", disagreement.expected)?;
+    writeln!(w, "# received: {:?}", disagreement.received)?;
+    w.write_all(&contents)?;
+
+    print_saved_case(&path);
+
+    Ok(path)
+}
+
+/// Fuzz target body: panics with the first disagreeing implementation and the minimized input.
+pub fn check(call: &Call) {
+    let Some(disagreement) = find_disagreement(call) else {
+        return;
+    };
+
+    let (call, disagreement) = minimize(call.clone(), disagreement);
+    let path = save_case(&call, &disagreement).expect("failed to save test case");
+
+    panic!(
+        "{} disagrees with {} on {}: expected {:?}, received {:?} (minimized input in {})",
+        disagreement.implementation,
+        reference().name(),
+        call.name(),
+        disagreement.expected,
+        disagreement.received,
+        path.display()
+    );
+}
+
+/// Builds calls from fuzzer input. Blobs are expanded from a seed, and calls start from honest
+/// values computed by `blst`. In adversarial calls, some of the values are then replaced.
+struct Generator<'a, 'b> {
+    u: &'a mut Unstructured<'b>,
+    adversarial: bool,
+}
+
+impl Generator<'_, '_> {
+    fn mutate(&mut self) -> arbitrary::Result<bool> {
+        Ok(self.adversarial && self.u.ratio(1, 8)?)
+    }
+
+    fn field_element(&mut self) -> arbitrary::Result<FieldElement> {
+        encoding::field_element(self.u, self.adversarial)
+    }
+
+    fn blob(&mut self) -> arbitrary::Result<Box<Blob>> {
+        let mut blob = Box::new([0u8; BYTES_PER_BLOB]);
+
+        if !self.u.ratio(1, 8)? {
+            let mut rng = ChaCha20Rng::seed_from_u64(self.u.arbitrary()?);
+            for element in blob.chunks_exact_mut(BYTES_PER_FIELD_ELEMENT) {
+                rng.fill(element);
+                element[0] &= 0x3f;
+            }
+        }
+
+        while self.mutate()? {
+            let index = self.u.choose_index(FIELD_ELEMENTS_PER_BLOB)?;
+            let value = self.field_element()?;
+            blob[index * BYTES_PER_FIELD_ELEMENT..(index + 1) * BYTES_PER_FIELD_ELEMENT]
+                .copy_from_slice(&value);
+        }
+
+        Ok(blob)
+    }
+
+    fn g1(&mut self, honest: Option<G1Bytes>) -> arbitrary::Result<G1Bytes> {
+        match honest {
+            Some(honest) if !self.mutate()? => Ok(honest),
+            _ => encoding::point(
+                self.u,
+                &honest.unwrap_or_else(g1_generator),
+                &g1_generator(),
+            ),
+        }
+    }
+
+    fn cell_index(&mut self, honest: usize) -> arbitrary::Result<usize> {
+        if !self.mutate()? {
+            return Ok(honest);
+        }
+
+        Ok(match self.u.int_in_range(0u8..=2)? {
+            0 => CELLS_PER_EXT_BLOB,
+            1 => usize::MAX,
+            _ => self.u.choose_index(CELLS_PER_EXT_BLOB)?,
+        })
+    }
+
+    fn cell(&mut self, honest: &Cell) -> arbitrary::Result<Cell> {
+        let mut cell = *honest;
+        if self.mutate()? {
+            let index = self.u.choose_index(FIELD_ELEMENTS_PER_CELL)?;
+            let value = self.field_element()?;
+            cell[index * BYTES_PER_FIELD_ELEMENT..(index + 1) * BYTES_PER_FIELD_ELEMENT]
+                .copy_from_slice(&value);
+        }
+
+        Ok(cell)
+    }
+
+    fn commitment(&mut self, blob: &Blob) -> arbitrary::Result<G1Bytes> {
+        let honest = reference().api.blob_to_kzg_commitment(blob).ok();
+        self.g1(honest)
+    }
+
+    fn cells_and_proofs(&mut self, blob: &Blob) -> CellsKzgProofs {
+        reference()
+            .api
+            .compute_cells_and_kzg_proofs(blob)
+            .unwrap_or_else(|_| {
+                (
+                    vec![[0; BYTES_PER_CELL]; CELLS_PER_EXT_BLOB],
+                    vec![g1_generator(); CELLS_PER_EXT_BLOB],
+                )
+            })
+    }
+
+    /// Call of the API with index `kind`, in the order of [`Call`] variants.
+    fn call(&mut self, kind: u8) -> arbitrary::Result<Call> {
+        Ok(match kind {
+            0 => Call::BlobToKzgCommitment { blob: self.blob()? },
+            1 => Call::ComputeKzgProof {
+                blob: self.blob()?,
+                z: self.field_element()?,
+            },
+            2 => {
+                let blob = self.blob()?;
+                let commitment = self.commitment(&blob)?;
+                Call::ComputeBlobKzgProof { blob, commitment }
+            }
+            3 => {
+                let blob = self.blob()?;
+                let z = self.field_element()?;
+                let commitment = self.commitment(&blob)?;
+                let (proof, y) = reference()
+                    .api
+                    .compute_kzg_proof(&blob, &z)
+                    .map_or((None, None), |(proof, y)| (Some(proof), Some(y)));
+                let y = match y {
+                    Some(y) if !self.mutate()? => y,
+                    _ => self.field_element()?,
+                };
+                let z = if self.mutate()? {
+                    self.field_element()?
+                } else {
+                    z
+                };
+
+                Call::VerifyKzgProof {
+                    commitment,
+                    z,
+                    y,
+                    proof: self.g1(proof)?,
+                }
+            }
+            4 => {
+                let blob = self.blob()?;
+                let commitment = self.commitment(&blob)?;
+                let proof = reference()
+                    .api
+                    .compute_blob_kzg_proof(&blob, &commitment)
+                    .ok();
+
+                Call::VerifyBlobKzgProof {
+                    blob,
+                    commitment,
+                    proof: self.g1(proof)?,
+                }
+            }
+            5 => {
+                let mut blobs = Vec::new();
+                let mut commitments = Vec::new();
+                let mut proofs = Vec::new();
+
+                for _ in 0..self.u.int_in_range(0u8..=4)? {
+                    let blob = self.blob()?;
+                    let commitment = self.commitment(&blob)?;
+                    let proof = reference()
+                        .api
+                        .compute_blob_kzg_proof(&blob, &commitment)
+                        .ok();
+
+                    blobs.push(*blob);
+                    commitments.push(commitment);
+                    proofs.push(self.g1(proof)?);
+                }
+
+                if self.mutate()? {
+                    commitments.pop();
+                }
+
+                Call::VerifyBlobKzgProofBatch {
+                    blobs,
+                    commitments,
+                    proofs,
+                }
+            }
+            6 => Call::ComputeCells { blob: self.blob()? },
+            7 => Call::ComputeCellsAndKzgProofs { blob: self.blob()? },
+            8 => {
+                let blob = self.blob()?;
+                let (honest_cells, _) = self.cells_and_proofs(&blob);
+
+                let mut indices = (0..CELLS_PER_EXT_BLOB).collect::<Vec<_>>();
+                indices.shuffle(&mut ChaCha20Rng::seed_from_u64(self.u.arbitrary()?));
+                // at least half of the cells are needed for recovery
+                let min_count = if self.adversarial {
+                    0
+                } else {
+                    CELLS_PER_EXT_BLOB / 2
+                };
+                indices.truncate(self.u.int_in_range(min_count..=CELLS_PER_EXT_BLOB)?);
+                // indices must be in ascending order
+                if !self.adversarial || self.u.arbitrary()? {
+                    indices.sort_unstable();
+                }
+
+                let mut cell_indices = Vec::with_capacity(indices.len());
+                let mut cells = Vec::with_capacity(indices.len());
+                for index in indices {
+                    cell_indices.push(self.cell_index(index)?);
+                    cells.push(self.cell(&honest_cells[index])?);
+                }
+
+                if !cell_indices.is_empty() && self.mutate()? {
+                    // duplicate entry
+                    cell_indices.push(cell_indices[0]);
+                    cells.push(cells[0]);
+                }
+
+                Call::RecoverCellsAndKzgProofs {
+                    cell_indices,
+                    cells,
+                }
+            }
+            _ => {
+                let mut honest = Vec::new();
+                for _ in 0..self.u.int_in_range(1u8..=2)? {
+                    let blob = self.blob()?;
+                    let commitment = reference().api.blob_to_kzg_commitment(&blob).ok();
+                    honest.push((commitment, self.cells_and_proofs(&blob)));
+                }
+
+                let mut commitments = Vec::new();
+                let mut cell_indices = Vec::new();
+                let mut cells = Vec::new();
+                let mut proofs = Vec::new();
+                for _ in 0..self.u.int_in_range(0u8..=8)? {
+                    let (commitment, (blob_cells, blob_proofs)) =
+                        &honest[self.u.choose_index(honest.len())?];
+                    let index = self.u.choose_index(CELLS_PER_EXT_BLOB)?;
+
+                    commitments.push(self.g1(*commitment)?);
+                    cell_indices.push(self.cell_index(index)?);
+                    cells.push(self.cell(&blob_cells[index])?);
+                    proofs.push(self.g1(Some(blob_proofs[index]))?);
+                }
+
+                if self.mutate()? {
+                    proofs.pop();
+                }
+
+                Call::VerifyCellKzgProofBatch {
+                    commitments,
+                    cell_indices,
+                    cells,
+                    proofs,
+                }
+            }
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Call {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let adversarial = u.arbitrary()?;
+        let kind = u.int_in_range(0u8..=9)?;
+        Generator { u, adversarial }.call(kind)
+    }
+}
+
+impl Call {
+    /// Arbitrary call of one of the EIP-7594 APIs that take or return cells and proofs:
+    /// `compute_cells_and_kzg_proofs`, `recover_cells_and_kzg_proofs`, or
+    /// `verify_cell_kzg_proof_batch`.
+    pub fn arbitrary_cells_call(u: &mut Unstructured) -> arbitrary::Result<Self> {
+        let adversarial = u.arbitrary()?;
+        let kind = u.int_in_range(7u8..=9)?;
+        Generator { u, adversarial }.call(kind)
+    }
+}
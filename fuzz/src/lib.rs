@@ -1,13 +1,14 @@
 use std::{
     env,
-    fs::File,
+    fs::{self, File},
     io::{self, Write},
     iter,
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::OnceLock,
 };
 
 use kzg::{
+    eip_4844::TRUSTED_SETUP_PATH,
     msm::{
         msm_impls::{msm, pippenger},
         precompute::{precompute, PrecomputationTable},
@@ -23,6 +24,65 @@ use rust_kzg_blst::types::{
 };
 use sha2::Digest;
 
+/// Instantiations of a function taking `&T` for every enabled backend, as `(name, function)`
+/// pairs.
+type Backends<T, R> = Vec<(&'static str, fn(&T) -> R)>;
+
+/// Instantiates a function generic over `EcBackend` for every enabled backend, as
+/// [`Backends`].
+macro_rules! generic_backends {
+    ($f:ident) => {
+        vec![
+            ("blst", $f::<rust_kzg_blst::eip_7594::BlstBackend>),
+            #[cfg(feature = "arkworks3")]
+            ("arkworks3", $f::<rust_kzg_arkworks3::eip_7594::ArkBackend>),
+            #[cfg(feature = "arkworks4")]
+            ("arkworks4", $f::<rust_kzg_arkworks4::eip_7594::ArkBackend>),
+            #[cfg(feature = "arkworks5")]
+            ("arkworks5", $f::<rust_kzg_arkworks5::eip_7594::ArkBackend>),
+            #[cfg(feature = "constantine")]
+            (
+                "constantine",
+                $f::<rust_kzg_constantine::eip_7594::CtBackend>,
+            ),
+            #[cfg(feature = "mcl")]
+            ("mcl", $f::<rust_kzg_mcl::eip_7594::MclBackend>),
+            #[cfg(feature = "zkcrypto")]
+            ("zkcrypto", $f::<rust_kzg_zkcrypto::eip_7594::ZBackend>),
+        ]
+    };
+}
+
+#[cfg(all(feature = "c_bindings", feature = "ckzg"))]
+compile_error!("feature `c_bindings` exports C symbols that collide with the ones of `ckzg`");
+
+#[cfg(feature = "c_bindings")]
+pub mod c_bindings;
+#[cfg(feature = "ckzg")]
+pub mod ckzg;
+pub mod decoding;
+pub mod encoding;
+pub mod eth;
+pub mod setup;
+
+static TRUSTED_SETUP: OnceLock<String> = OnceLock::new();
+/// Contents of the trusted setup file set in environment variable `TRUSTED_SETUP`, or of the
+/// copy in `kzg-bench` by default.
+pub fn trusted_setup() -> &'static str {
+    TRUSTED_SETUP.get_or_init(|| {
+        let path = env::var("TRUSTED_SETUP").unwrap_or_else(|_| {
+            format!(
+                "{}/../kzg-bench/{}",
+                env!("CARGO_MANIFEST_DIR"),
+                TRUSTED_SETUP_PATH
+            )
+        });
+
+        fs::read_to_string(&path)
+            .unwrap_or_else(|_| panic!("failed to read trusted setup from {path}"))
+    })
+}
+
 static NPOINTS: OnceLock<usize> = OnceLock::new();
 pub fn get_npoints() -> usize {
     NPOINTS
@@ -455,20 +515,7 @@ impl Backend {
         writeln!(w, "\tassert!(expected.equals(&received));")?;
         writeln!(w, "}}")?;
 
-        let str = format!(
-            "│ Failing test case successfully saved to {} │",
-            failing_case_path.display()
-        );
-        let strlen = str.chars().count();
-        println!(
-            "\n\n┌{}┐",
-            iter::repeat('─').take(strlen - 2).collect::<String>()
-        );
-        println!("{}", str);
-        println!(
-            "└{}┘\n\n",
-            iter::repeat('─').take(strlen - 2).collect::<String>()
-        );
+        print_saved_case(&failing_case_path);
 
         Ok(())
     }
@@ -622,3 +669,20 @@ impl Backend {
         }
     }
 }
+
+fn print_saved_case(path: &Path) {
+    let str = format!(
+        "│ Failing test case successfully saved to {} │",
+        path.display()
+    );
+    let strlen = str.chars().count();
+    println!(
+        "\n\n┌{}┐",
+        iter::repeat('─').take(strlen - 2).collect::<String>()
+    );
+    println!("{}", str);
+    println!(
+        "└{}┘\n\n",
+        iter::repeat('─').take(strlen - 2).collect::<String>()
+    );
+}
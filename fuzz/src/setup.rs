@@ -0,0 +1,314 @@
+//! Parsing of mutated trusted setup files.
+//!
+//! The text parser (`load_trusted_setup_string`) is shared by all backends, so only the
+//! backend-specific part of loading is compared: decoding of every point that differs from the
+//! original setup, and the pairing check that rejects setups not in Lagrange form.
+
+use std::{fmt::Write, sync::OnceLock};
+
+use kzg::{
+    eip_4844::{
+        load_trusted_setup_string, BYTES_PER_G1, BYTES_PER_G2, FIELD_ELEMENTS_PER_BLOB,
+        TRUSTED_SETUP_NUM_G2_POINTS,
+    },
+    EcBackend, PairingVerify, G1, G2,
+};
+use libfuzzer_sys::arbitrary::{self, Arbitrary, Unstructured};
+
+use crate::{
+    encoding::{self, g1_generator, g1_off_subgroup, g2_generator, g2_off_subgroup},
+    trusted_setup, Backends,
+};
+
+type Setup = (Vec<u8>, Vec<u8>, Vec<u8>);
+
+/// Index of the first G1 point line (in Lagrange form); the two lines before hold point counts.
+const G1_LAGRANGE_LINE: usize = 2;
+const G2_MONOMIAL_LINE: usize = G1_LAGRANGE_LINE + FIELD_ELEMENTS_PER_BLOB;
+const G1_MONOMIAL_LINE: usize = G2_MONOMIAL_LINE + TRUSTED_SETUP_NUM_G2_POINTS;
+
+/// Upper bound on the number of changed points compared per group. Removed or duplicated lines
+/// shift all following points, which are valid, but slow to decode with every backend.
+const MAX_CHANGED_POINTS: usize = 64;
+
+fn original_lines() -> &'static [&'static str] {
+    static LINES: OnceLock<Vec<&'static str>> = OnceLock::new();
+    LINES.get_or_init(|| trusted_setup().lines().collect())
+}
+
+fn original_setup() -> &'static Setup {
+    static SETUP: OnceLock<Setup> = OnceLock::new();
+    SETUP.get_or_init(|| {
+        load_trusted_setup_string(trusted_setup()).expect("failed to parse trusted setup")
+    })
+}
+
+#[derive(Debug)]
+enum Mutation {
+    /// Replace a point with another (valid or adversarial) encoding of the same size.
+    Point {
+        line: usize,
+        hex: String,
+    },
+    Line {
+        line: usize,
+        text: String,
+    },
+    Remove {
+        line: usize,
+    },
+    Duplicate {
+        line: usize,
+    },
+    Swap {
+        line: usize,
+        other: usize,
+    },
+    Insert {
+        line: usize,
+        position: usize,
+        text: String,
+    },
+    Truncate {
+        len: usize,
+    },
+}
+
+impl<'a> Arbitrary<'a> for Mutation {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let lines = original_lines();
+
+        Ok(match u.int_in_range(0u8..=6)? {
+            0 => {
+                let line = u.int_in_range(G1_LAGRANGE_LINE..=lines.len() - 1)?;
+                let original =
+                    hex::decode(lines[line]).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+                let bytes = if (G2_MONOMIAL_LINE..G1_MONOMIAL_LINE).contains(&line) {
+                    let original: [u8; BYTES_PER_G2] = original
+                        .try_into()
+                        .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+                    encoding::point(u, &original, &g2_generator())?.to_vec()
+                } else {
+                    let original: [u8; BYTES_PER_G1] = original
+                        .try_into()
+                        .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+                    encoding::point(u, &original, &g1_generator())?.to_vec()
+                };
+
+                Mutation::Point {
+                    line,
+                    hex: hex::encode(bytes),
+                }
+            }
+            1 => Mutation::Line {
+                line: u.choose_index(lines.len())?,
+                text: u.arbitrary()?,
+            },
+            2 => Mutation::Remove {
+                line: u.choose_index(lines.len())?,
+            },
+            3 => Mutation::Duplicate {
+                line: u.choose_index(lines.len())?,
+            },
+            4 => Mutation::Swap {
+                line: u.choose_index(lines.len())?,
+                other: u.choose_index(lines.len())?,
+            },
+            5 => Mutation::Insert {
+                line: u.choose_index(lines.len())?,
+                position: u.arbitrary()?,
+                text: (*u.choose(&[" ", "\t", "\r", "\n", "0x", "-", "é", "+1"])?).to_string(),
+            },
+            _ => Mutation::Truncate {
+                len: u.arbitrary()?,
+            },
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct SetupInput {
+    mutations: Vec<Mutation>,
+}
+
+impl<'a> Arbitrary<'a> for SetupInput {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut mutations = Vec::new();
+        for _ in 0..u.int_in_range(1u8..=4)? {
+            mutations.push(u.arbitrary()?);
+        }
+
+        Ok(Self { mutations })
+    }
+}
+
+impl SetupInput {
+    pub fn contents(&self) -> String {
+        let mut lines = original_lines()
+            .iter()
+            .map(|line| line.to_string())
+            .collect::<Vec<_>>();
+        let mut truncate = None;
+
+        for mutation in &self.mutations {
+            match mutation {
+                Mutation::Point { line, hex } => lines[*line] = hex.clone(),
+                Mutation::Line { line, text } => lines[*line] = text.clone(),
+                Mutation::Remove { line } => {
+                    if *line < lines.len() {
+                        lines.remove(*line);
+                    }
+                }
+                Mutation::Duplicate { line } => {
+                    if *line < lines.len() {
+                        lines.insert(*line, lines[*line].clone());
+                    }
+                }
+                Mutation::Swap { line, other } => {
+                    if *line < lines.len() && *other < lines.len() {
+                        lines.swap(*line, *other);
+                    }
+                }
+                Mutation::Insert {
+                    line,
+                    position,
+                    text,
+                } => {
+                    if let Some(line) = lines.get_mut(*line) {
+                        let mut position = position % (line.len() + 1);
+                        while !line.is_char_boundary(position) {
+                            position -= 1;
+                        }
+                        line.insert_str(position, text);
+                    }
+                }
+                Mutation::Truncate { len } => truncate = Some(*len),
+            }
+        }
+
+        let mut contents = lines.iter().fold(String::new(), |mut contents, line| {
+            let _ = writeln!(contents, "{line}");
+            contents
+        });
+        if let Some(len) = truncate {
+            let mut len = len % (contents.len() + 1);
+            while !contents.is_char_boundary(len) {
+                len -= 1;
+            }
+            contents.truncate(len);
+        }
+
+        contents
+    }
+}
+
+type LagrangeCheckPoints = (
+    [u8; BYTES_PER_G1],
+    [u8; BYTES_PER_G1],
+    [u8; BYTES_PER_G2],
+    [u8; BYTES_PER_G2],
+);
+
+/// Points of a parsed setup that differ from the original setup (except for points outside of
+/// the subgroup, see [`g1_off_subgroup`]), and the points used by the Lagrange form check.
+pub struct SetupPoints {
+    g1: Vec<[u8; BYTES_PER_G1]>,
+    g2: Vec<[u8; BYTES_PER_G2]>,
+    /// `None` if one of the points is outside of the subgroup.
+    lagrange_check: Option<LagrangeCheckPoints>,
+}
+
+impl SetupPoints {
+    fn new(setup: &Setup) -> Self {
+        fn changed<const N: usize>(bytes: &[u8], original: &[u8]) -> Vec<[u8; N]> {
+            bytes
+                .chunks_exact(N)
+                .zip(original.chunks_exact(N))
+                .filter(|(point, original)| point != original)
+                .map(|(point, _)| point.try_into().unwrap())
+                .take(MAX_CHANGED_POINTS)
+                .collect()
+        }
+
+        let (g1_monomial, g1_lagrange, g2_monomial) = setup;
+        let original = original_setup();
+
+        let mut g1 = changed(g1_monomial, &original.0);
+        g1.extend(changed::<BYTES_PER_G1>(g1_lagrange, &original.1));
+        g1.retain(|point| !g1_off_subgroup(point));
+        let mut g2 = changed(g2_monomial, &original.2);
+        g2.retain(|point| !g2_off_subgroup(point));
+
+        let lagrange_check: LagrangeCheckPoints = (
+            g1_lagrange[0..BYTES_PER_G1].try_into().unwrap(),
+            g1_lagrange[BYTES_PER_G1..2 * BYTES_PER_G1]
+                .try_into()
+                .unwrap(),
+            g2_monomial[0..BYTES_PER_G2].try_into().unwrap(),
+            g2_monomial[BYTES_PER_G2..2 * BYTES_PER_G2]
+                .try_into()
+                .unwrap(),
+        );
+        let (lagrange_0, lagrange_1, g2_0, g2_1) = &lagrange_check;
+        let off_subgroup = g1_off_subgroup(lagrange_0)
+            || g1_off_subgroup(lagrange_1)
+            || g2_off_subgroup(g2_0)
+            || g2_off_subgroup(g2_1);
+
+        Self {
+            g1,
+            g2,
+            lagrange_check: (!off_subgroup).then_some(lagrange_check),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct SetupOutcome {
+    g1: Vec<bool>,
+    g2: Vec<bool>,
+    /// `None` if one of the points could not be decoded or is outside of the subgroup.
+    in_lagrange_form: Option<bool>,
+}
+
+pub fn validate<B: EcBackend>(points: &SetupPoints) -> SetupOutcome {
+    let in_lagrange_form = points.lagrange_check.as_ref().and_then(|points| {
+        let (lagrange_0, lagrange_1, g2_0, g2_1) = points;
+        let lagrange_0 = B::G1::from_bytes(lagrange_0).ok()?;
+        let lagrange_1 = B::G1::from_bytes(lagrange_1).ok()?;
+        let g2_0 = B::G2::from_bytes(g2_0).ok()?;
+        let g2_1 = B::G2::from_bytes(g2_1).ok()?;
+
+        Some(!B::G1::verify(&lagrange_1, &g2_0, &lagrange_0, &g2_1))
+    });
+
+    SetupOutcome {
+        g1: points
+            .g1
+            .iter()
+            .map(|bytes| B::G1::from_bytes(bytes).is_ok())
+            .collect(),
+        g2: points
+            .g2
+            .iter()
+            .map(|bytes| B::G2::from_bytes(bytes).is_ok())
+            .collect(),
+        in_lagrange_form,
+    }
+}
+
+/// Fuzz target body: parsing must not panic, and all backends must agree on which points of the
+/// parsed setup are valid.
+pub fn check(input: &SetupInput) {
+    let Ok(setup) = load_trusted_setup_string(&input.contents()) else {
+        return;
+    };
+
+    let backends: Backends<SetupPoints, SetupOutcome> = generic_backends!(validate);
+    let points = SetupPoints::new(&setup);
+
+    let expected = backends[0].1(&points);
+    for (name, validate) in &backends[1..] {
+        assert_eq!(expected, validate(&points), "backend: {name}");
+    }
+}
@@ -92,6 +92,20 @@ pub fn bench_eip_7594<B: EcBackend>(
         });
     });
 
+    let mut group = c.benchmark_group("compute_cell_kzg_proofs (cells)");
+    for count in [1, 8, 16, 32, 128] {
+        let blob = bytes_to_blob(blobs.first().unwrap()).unwrap();
+        let cell_indices = (0..count).collect::<Vec<_>>();
+
+        group.bench_function(BenchmarkId::from_parameter(count), |b| {
+            b.iter(|| {
+                <B::KZGSettings as DAS<B>>::compute_cell_kzg_proofs(&ts, &blob, &cell_indices)
+                    .unwrap();
+            });
+        });
+    }
+    group.finish();
+
     let mut group = c.benchmark_group("recover_cells_and_kzg_proofs (% missing)");
     for i in [2, 4, 8] {
         let percent_missing = 100.0 / (i as f64);
@@ -0,0 +1,12 @@
+#![allow(dead_code)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct Test {
+    pub custody_group: u64,
+    pub result: Vec<u64>,
+}
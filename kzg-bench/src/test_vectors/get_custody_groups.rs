@@ -0,0 +1,34 @@
+#![allow(dead_code)]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct Test {
+    /// The node ID as a decimal uint256, too large for any integer type
+    node_id: String,
+    pub custody_group_count: u64,
+    pub result: Vec<u64>,
+}
+
+impl Test {
+    /// Returns the node ID in big-endian bytes.
+    pub fn get_node_id_bytes(&self) -> Result<[u8; 32], String> {
+        let mut bytes = [0u8; 32];
+        for digit in self.node_id.chars() {
+            let mut carry = digit.to_digit(10).ok_or("Invalid node ID")?;
+            for byte in bytes.iter_mut().rev() {
+                let value = *byte as u32 * 10 + carry;
+                *byte = value as u8;
+                carry = value >> 8;
+            }
+            if carry != 0 {
+                return Err("Node ID does not fit 256 bits".to_string());
+            }
+        }
+        Ok(bytes)
+    }
+}
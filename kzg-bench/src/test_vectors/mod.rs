@@ -13,3 +13,7 @@ pub mod compute_cells_and_kzg_proofs;
 pub mod compute_verify_cell_kzg_proof_batch_challenge;
 pub mod recover_cells_and_kzg_proofs;
 pub mod verify_cell_kzg_proof_batch;
+
+/* networking, generated locally rather than taken from a spec tests release */
+pub mod compute_columns_for_custody_group;
+pub mod get_custody_groups;
@@ -1,9 +1,12 @@
 use kzg::{
     msm::precompute::{precompute, PrecomputationTable},
-    Fr, G1Affine, G1Fp, G1GetFp, G1Mul, G1ProjAddAffine, G2Mul, G1, G2,
+    Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul, G1ProjAddAffine, G2Mul, GTPow, Pairing, G1, G2,
+    GT,
 };
 use std::convert::TryInto;
 
+use super::utils::{hex_to_bytes, G1_NOT_IN_SUBGROUP, G1_NOT_ON_CURVE};
+
 pub fn log_2_byte_works(log_2_byte: &dyn Fn(u8) -> usize) {
     assert_eq!(0, log_2_byte(0x01));
     assert_eq!(7, log_2_byte(0x80));
@@ -180,6 +183,27 @@ pub fn g1_identity_is_identity<TG1: G1>() {
     assert!(actual.equals(&TG1::generator()));
 }
 
+pub fn g1_from_bytes_batch_works<TFr: Fr, TG1: G1 + G1Mul<TFr>>() {
+    let points = [
+        TG1::generator().mul(&TFr::from_u64(3)),
+        TG1::identity(),
+        TG1::generator().mul(&TFr::from_u64(5)),
+        TG1::generator().mul(&TFr::from_u64(3)),
+    ];
+    let mut bytes = points.each_ref().map(TG1::to_bytes);
+
+    let decoded = TG1::from_bytes_batch(&bytes).unwrap();
+    assert_eq!(decoded.len(), points.len());
+    assert!(decoded.iter().zip(&points).all(|(a, b)| a.equals(b)));
+    assert!(TG1::from_bytes_batch(&[]).unwrap().is_empty());
+
+    for invalid in [G1_NOT_IN_SUBGROUP, G1_NOT_ON_CURVE] {
+        bytes[2] = hex_to_bytes(invalid);
+        let err = TG1::from_bytes_batch(&bytes).unwrap_err();
+        assert!(err.contains("index 2"), "{err}");
+    }
+}
+
 #[allow(clippy::type_complexity)]
 pub fn g1_make_linear_combination<
     TFr: Fr,
@@ -386,6 +410,46 @@ pub fn g1_small_linear_combination<
     }
 }
 
+pub fn g1_short_linear_combination<
+    TFr: Fr,
+    TG1: G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine> + Copy,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>() {
+    let len: usize = 4096;
+    let points = (0..len)
+        .map(|i| {
+            if i % 10 == 0 {
+                TG1::zero()
+            } else {
+                TG1::rand()
+            }
+        })
+        .collect::<Vec<_>>();
+
+    for scalar_bits in [64, 128] {
+        let scalars = (0..len)
+            .map(|_| {
+                let mut limbs = [rand::random::<u64>(), rand::random::<u64>(), 0, 0];
+                if scalar_bits == 64 {
+                    limbs[1] = 0;
+                }
+                TFr::from_u64_arr(&limbs)
+            })
+            .collect::<Vec<_>>();
+
+        for size in [0, 1, 7, 8, 31, 32, 127, len] {
+            let full = TG1::g1_lincomb(&points[..size], &scalars[..size], size, None);
+            let short = TG1::g1_lincomb_short(&points[..size], &scalars[..size], size, scalar_bits);
+            assert!(
+                full.equals(&short),
+                "short msm of {size} points with {scalar_bits}-bit scalars should match full-width msm"
+            );
+        }
+    }
+}
+
 pub fn pairings_work<TFr: Fr, TG1: G1 + G1Mul<TFr>, TG2: G2 + G2Mul<TFr>>(
     pairings_verify: &dyn Fn(&TG1, &TG2, &TG1, &TG2) -> bool,
 ) {
@@ -403,3 +467,115 @@ pub fn pairings_work<TFr: Fr, TG1: G1 + G1Mul<TFr>, TG2: G2 + G2Mul<TFr>>(
     assert!(pairings_verify(&g1_3, &g2_5, &g1_5, &g2_3));
     assert!(!pairings_verify(&g1_3, &g2_3, &g1_5, &g2_5));
 }
+
+pub fn gt_pairing_is_bilinear<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + Pairing<TG1, TG2, TGT>,
+    TG2: G2 + G2Mul<TFr>,
+    TGT: GT + GTPow<TFr>,
+>() {
+    let three = TFr::from_u64(3);
+    let five = TFr::from_u64(5);
+    let fifteen = TFr::from_u64(15);
+
+    let e = TG1::pairing(&TG1::generator(), &TG2::generator());
+    let e_3_5 = TG1::pairing(&TG1::generator().mul(&three), &TG2::generator().mul(&five));
+    let e_15_1 = TG1::pairing(&TG1::generator().mul(&fifteen), &TG2::generator());
+
+    assert!(!e.is_one());
+    assert!(e_3_5.equals(&e.pow(&fifteen)));
+    assert!(e_3_5.equals(&e_15_1));
+    assert!(e.pow(&TFr::zero()).is_one());
+    assert!(e.pow(&TFr::one()).equals(&e));
+
+    // e(-P, Q) is the inverse of e(P, Q)
+    let e_neg = TG1::pairing(&TG1::negative_generator(), &TG2::generator());
+    assert!(e_neg.equals(&e.inverse()));
+    assert!(e_neg.mul(&e).is_one());
+
+    // Pairing with the identity gives the identity
+    assert!(TG1::pairing(&TG1::identity(), &TG2::generator()).is_one());
+}
+
+pub fn gt_miller_loop_and_final_exp_work<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + Pairing<TG1, TG2, TGT>,
+    TG2: G2 + G2Mul<TFr>,
+    TGT: GT + GTPow<TFr>,
+>() {
+    let a = TG1::generator().mul(&TFr::from_u64(7));
+    let b = TG2::generator().mul(&TFr::from_u64(11));
+
+    let e = TG1::pairing(&a, &b);
+    assert!(TG1::final_exp(&TG1::miller_loop(&a, &b)).equals(&e));
+
+    // e(7 * g1, 11 * g2) * e(-77 * g1, g2) == 1
+    let c = TG1::generator().mul(&TFr::from_u64(77).negate());
+    let f = TG1::multi_miller_loop(&[a.clone(), c.clone()], &[b.clone(), TG2::generator()]);
+    assert!(TG1::final_exp(&f).is_one());
+
+    let f = TG1::miller_loop(&a, &b).mul(&TG1::miller_loop(&c, &TG2::generator()));
+    assert!(TG1::final_exp(&f).is_one());
+
+    assert!(TG1::multi_miller_loop(&[], &[]).is_one());
+}
+
+pub fn gt_arithmetic_works<
+    TFr: Fr,
+    TG1: G1 + Pairing<TG1, TG2, TGT>,
+    TG2: G2,
+    TGT: GT + GTPow<TFr>,
+>() {
+    let e = TG1::pairing(&TG1::generator(), &TG2::generator());
+
+    assert!(e.sqr().equals(&e.mul(&e)));
+    assert!(e.sqr().equals(&e.pow(&TFr::from_u64(2))));
+    assert!(e.mul(&e.inverse()).is_one());
+    assert!(e.mul(&TGT::one()).equals(&e));
+    assert!(TGT::one().inverse().is_one());
+    assert!(e.pow(&TFr::from_u64(5)).equals(&e.sqr().sqr().mul(&e)));
+
+    // The target group has order r, so e^(r - 1) == e^-1
+    assert!(e.pow(&TFr::one().negate()).equals(&e.inverse()));
+}
+
+pub fn gt_bytes_roundtrip<TG1: G1 + Pairing<TG1, TG2, TGT>, TG2: G2, TGT: GT>() {
+    // e(g1, g2), serialized as 12 big-endian Fp coefficients
+    let expected = hex::decode(GT_GENERATOR_HEX).unwrap();
+
+    let e = TG1::pairing(&TG1::generator(), &TG2::generator());
+    assert_eq!(e.to_bytes().as_slice(), expected.as_slice());
+
+    let decoded = TGT::from_bytes(&expected).unwrap();
+    assert!(decoded.equals(&e));
+    assert!(TGT::from_bytes(&TGT::one().to_bytes()).unwrap().is_one());
+
+    assert!(TGT::from_bytes(&expected[1..]).is_err());
+
+    // Miller loop outputs are not in the target group
+    let f = TG1::miller_loop(&TG1::generator(), &TG2::generator());
+    assert!(TGT::from_bytes(&f.to_bytes()).is_err());
+
+    // Coefficients must be canonical
+    let mut non_canonical = expected.clone();
+    non_canonical[..48].fill(0xff);
+    assert!(TGT::from_bytes(&non_canonical).is_err());
+
+    // Zero is not in the target group
+    assert!(TGT::from_bytes(&[0u8; 576]).is_err());
+}
+
+const GT_GENERATOR_HEX: &str = concat!(
+    "1250ebd871fc0a92a7b2d83168d0d727272d441befa15c503dd8e90ce98db3e7b6d194f60839c508a84305aaca1789b6",
+    "089a1c5b46e5110b86750ec6a532348868a84045483c92b7af5af689452eafabf1a8943e50439f1d59882a98eaa0170f",
+    "1368bb445c7c2d209703f239689ce34c0378a68e72a6b3b216da0e22a5031b54ddff57309396b38c881c4c849ec23e87",
+    "193502b86edb8857c273fa075a50512937e0794e1e65a7617c90d8bd66065b1fffe51d7a579973b1315021ec3c19934f",
+    "01b2f522473d171391125ba84dc4007cfbf2f8da752f7c74185203fcca589ac719c34dffbbaad8431dad1c1fb597aaa5",
+    "018107154f25a764bd3c79937a45b84546da634b8f6be14a8061e55cceba478b23f7dacaa35c8ca78beae9624045b4b6",
+    "19f26337d205fb469cd6bd15c3d5a04dc88784fbb3d0b2dbdea54d43b2b73f2cbb12d58386a8703e0f948226e47ee89d",
+    "06fba23eb7c5af0d9f80940ca771b6ffd5857baaf222eb95a7d2809d61bfe02e1bfd1b68ff02f0b8102ae1c2d5d5ab1a",
+    "11b8b424cd48bf38fcef68083b0b0ec5c81a93b330ee1a677d0d15ff7b984e8978ef48881e32fac91b93b47333e2ba57",
+    "03350f55a7aefcd3c31b4fcb6ce5771cc6a0e9786ab5973320c806ad360829107ba810c5a09ffdd9be2291a0c25a99a2",
+    "04c581234d086a9902249b64728ffd21a189e87935a954051c7cdba7b3872629a4fafc05066245cb9108f0242d0fe3ef",
+    "0f41e58663bf08cf068672cbd01a7ec73baca4d72ca93544deff686bfd6df543d48eaa24afe47e1efde449383b676631",
+);
@@ -1,12 +1,12 @@
 use std::{ffi::CString, fs::File, io::Read, path::PathBuf, ptr::null_mut};
 
-use kzg::eth::c_bindings::{Blob, Bytes48, CKZGSettings, KZGCommitment, KZGProof};
+use kzg::eth::c_bindings::{Blob, Bytes48, CKZGSettings, Cell, KZGCommitment, KZGProof};
 use kzg::{
     eip_4844::{
         load_trusted_setup_string, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1,
         BYTES_PER_G2, BYTES_PER_PROOF,
     },
-    eth::c_bindings::CKzgRet,
+    eth::{c_bindings::CKzgRet, BYTES_PER_BLOB, BYTES_PER_CELL, CELLS_PER_EXT_BLOB},
 };
 use libc::FILE;
 
@@ -647,3 +647,90 @@ pub fn compute_blob_kzg_proof_zero_input_test(
 
     assert_eq!(out, CKzgRet::Ok);
 }
+
+#[allow(clippy::type_complexity)]
+pub fn recover_cells_test(
+    compute_cells_and_kzg_proofs: unsafe extern "C" fn(
+        cells: *mut Cell,
+        proofs: *mut KZGProof,
+        blob: *const Blob,
+        settings: *const CKZGSettings,
+    ) -> CKzgRet,
+    recover_cells: unsafe extern "C" fn(
+        recovered_cells: *mut Cell,
+        cell_indices: *const u64,
+        cells: *const Cell,
+        num_cells: u64,
+        s: *const CKZGSettings,
+    ) -> CKzgRet,
+    cells_to_blob: unsafe extern "C" fn(blob: *mut Blob, cells: *const Cell) -> CKzgRet,
+    load_trusted_setup_file: unsafe extern "C" fn(
+        out: *mut CKZGSettings,
+        in_: *mut FILE,
+    ) -> CKzgRet,
+) {
+    let settings = get_ckzg_settings(load_trusted_setup_file);
+
+    let mut rng = rand::thread_rng();
+    let blob = Blob {
+        bytes: generate_random_blob_bytes(&mut rng),
+    };
+
+    let new_cells = || {
+        (0..CELLS_PER_EXT_BLOB)
+            .map(|_| Cell {
+                bytes: [0; BYTES_PER_CELL],
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut cells = new_cells();
+    let out =
+        unsafe { compute_cells_and_kzg_proofs(cells.as_mut_ptr(), null_mut(), &blob, &settings) };
+    assert_eq!(out, CKzgRet::Ok);
+
+    // Keep every other cell
+    let cell_indices = (0..CELLS_PER_EXT_BLOB as u64)
+        .step_by(2)
+        .collect::<Vec<_>>();
+    let partial_cells = cells
+        .iter()
+        .step_by(2)
+        .map(|cell| Cell { bytes: cell.bytes })
+        .collect::<Vec<_>>();
+
+    let mut recovered_cells = new_cells();
+    let out = unsafe {
+        recover_cells(
+            recovered_cells.as_mut_ptr(),
+            cell_indices.as_ptr(),
+            partial_cells.as_ptr(),
+            cell_indices.len() as u64,
+            &settings,
+        )
+    };
+    assert_eq!(out, CKzgRet::Ok);
+    assert!(recovered_cells
+        .iter()
+        .zip(&cells)
+        .all(|(recovered, cell)| recovered.bytes == cell.bytes));
+
+    let mut recovered_blob = Blob {
+        bytes: [0; BYTES_PER_BLOB],
+    };
+    let out = unsafe { cells_to_blob(&mut recovered_blob, recovered_cells.as_ptr()) };
+    assert_eq!(out, CKzgRet::Ok);
+    assert_eq!(recovered_blob.bytes, blob.bytes);
+
+    // Not enough cells to recover the blob
+    let out = unsafe {
+        recover_cells(
+            recovered_cells.as_mut_ptr(),
+            cell_indices.as_ptr(),
+            partial_cells.as_ptr(),
+            cell_indices.len() as u64 - 1,
+            &settings,
+        )
+    };
+    assert_eq!(out, CKzgRet::BadArgs);
+}
@@ -535,6 +535,162 @@ pub fn verify_kzg_proof_batch_fails_with_incorrect_proof_test<
     assert!(!result);
 }
 
+#[allow(clippy::type_complexity)]
+pub fn verify_kzg_proof_openings_batch_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, String>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
+    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> Result<(TG1, TFr), String>,
+    verify_kzg_proof_batch: &dyn Fn(
+        &[TG1],
+        &[TFr],
+        &[TFr],
+        &[TG1],
+        &TKZGSettings,
+    ) -> Result<bool, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    const N_SAMPLES: usize = 6;
+
+    let mut commitments: Vec<TG1> = Vec::with_capacity(N_SAMPLES);
+    let mut zs: Vec<TFr> = Vec::with_capacity(N_SAMPLES);
+    let mut ys: Vec<TFr> = Vec::with_capacity(N_SAMPLES);
+    let mut proofs: Vec<TG1> = Vec::with_capacity(N_SAMPLES);
+
+    // Open every other blob at two random points
+    let mut blob = Vec::new();
+    let mut commitment = TG1::identity();
+    for i in 0..N_SAMPLES {
+        if i % 2 == 0 {
+            let blob_bytes = generate_random_blob_bytes(&mut rng);
+            blob = bytes_to_blob(&blob_bytes).unwrap();
+            commitment = blob_to_kzg_commitment(&blob, &ts).unwrap();
+        }
+
+        let z = TFr::from_bytes(&generate_random_field_element_bytes(&mut rng)).unwrap();
+        let (proof, y) = compute_kzg_proof(&blob, &z, &ts).unwrap();
+
+        commitments.push(commitment.clone());
+        zs.push(z);
+        ys.push(y);
+        proofs.push(proof);
+    }
+
+    // This should still work with zero openings
+    for count in 0..(N_SAMPLES + 1) {
+        let result = verify_kzg_proof_batch(
+            &commitments[0..count],
+            &zs[0..count],
+            &ys[0..count],
+            &proofs[0..count],
+            &ts,
+        )
+        .unwrap();
+        assert!(result);
+    }
+
+    // Swap the evaluations of two openings of the same blob
+    let mut wrong_ys = ys.clone();
+    wrong_ys.swap(2, 3);
+    let result = verify_kzg_proof_batch(&commitments, &zs, &wrong_ys, &proofs, &ts).unwrap();
+    assert!(!result);
+
+    assert!(verify_kzg_proof_batch(&commitments, &zs[1..], &ys, &proofs, &ts).is_err());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn compute_and_verify_kzg_multi_proof_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+    blob_to_kzg_commitment: &dyn Fn(&[TFr], &TKZGSettings) -> Result<TG1, String>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
+    compute_kzg_proof: &dyn Fn(&[TFr], &TFr, &TKZGSettings) -> Result<(TG1, TFr), String>,
+    compute_kzg_multi_proof: &dyn Fn(
+        &[TFr],
+        &[TFr],
+        &TKZGSettings,
+    ) -> Result<(TG1, Vec<TFr>), String>,
+    verify_kzg_multi_proof: &dyn Fn(
+        &TG1,
+        &[TFr],
+        &[TFr],
+        &TG1,
+        &TKZGSettings,
+    ) -> Result<bool, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let blob_bytes = generate_random_blob_bytes(&mut rng);
+    let blob = bytes_to_blob(&blob_bytes).unwrap();
+    let commitment = blob_to_kzg_commitment(&blob, &ts).unwrap();
+
+    // Mix points of the evaluation domain, i.e. field elements of the blob, with random ones
+    let roots_of_unity = ts.get_fft_settings().get_brp_roots_of_unity();
+    let mut zs = vec![roots_of_unity[0], roots_of_unity[17]];
+    for _ in 0..4 {
+        zs.push(TFr::from_bytes(&generate_random_field_element_bytes(&mut rng)).unwrap());
+    }
+
+    let (proof, ys) = compute_kzg_multi_proof(&blob, &zs, &ts).unwrap();
+    assert_eq!(ys.len(), zs.len());
+    assert!(ys[0].equals(&blob[0]));
+    assert!(ys[1].equals(&blob[17]));
+    for (z, y) in zs.iter().zip(ys.iter()) {
+        assert!(compute_kzg_proof(&blob, z, &ts).unwrap().1.equals(y));
+    }
+    assert!(verify_kzg_multi_proof(&commitment, &zs, &ys, &proof, &ts).unwrap());
+
+    // The order of the points does not matter
+    let mut shuffled_zs = zs.clone();
+    let mut shuffled_ys = ys.clone();
+    shuffled_zs.swap(0, 4);
+    shuffled_ys.swap(0, 4);
+    assert!(verify_kzg_multi_proof(&commitment, &shuffled_zs, &shuffled_ys, &proof, &ts).unwrap());
+
+    // A single point is an ordinary opening
+    let (single_proof, single_ys) = compute_kzg_multi_proof(&blob, &zs[2..3], &ts).unwrap();
+    assert!(single_proof.equals(&compute_kzg_proof(&blob, &zs[2], &ts).unwrap().0));
+    assert!(
+        verify_kzg_multi_proof(&commitment, &zs[2..3], &single_ys, &single_proof, &ts).unwrap()
+    );
+
+    let mut wrong_ys = ys.clone();
+    wrong_ys[3] = wrong_ys[3].add(&TFr::one());
+    assert!(!verify_kzg_multi_proof(&commitment, &zs, &wrong_ys, &proof, &ts).unwrap());
+    assert!(!verify_kzg_multi_proof(&commitment, &zs[1..], &ys[1..], &proof, &ts).unwrap());
+
+    assert!(verify_kzg_multi_proof(&commitment, &zs, &ys[1..], &proof, &ts).is_err());
+    assert!(compute_kzg_multi_proof(&blob, &[], &ts).is_err());
+    assert!(compute_kzg_multi_proof(&blob, &[zs[1], zs[2], zs[1]], &ts).is_err());
+    let too_many_zs = (0..ts.get_g2_monomial().len())
+        .map(|i| roots_of_unity[i])
+        .collect::<Vec<_>>();
+    assert!(compute_kzg_multi_proof(&blob, &too_many_zs, &ts).is_err());
+    assert!(compute_kzg_multi_proof(&blob, &too_many_zs[1..], &ts).is_ok());
+}
+
 const BLOB_TO_KZG_COMMITMENT_TESTS: &str = "src/test_vectors/blob_to_kzg_commitment/*/*/*";
 const COMPUTE_KZG_PROOF_TESTS: &str = "src/test_vectors/compute_kzg_proof/*/*/*";
 const COMPUTE_BLOB_KZG_PROOF_TESTS: &str = "src/test_vectors/compute_blob_kzg_proof/*/*/*";
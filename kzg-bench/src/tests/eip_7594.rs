@@ -1,13 +1,53 @@
-use super::utils::{get_manifest_dir, get_trusted_setup_path};
+use super::{
+    eip_4844::generate_random_blob_bytes,
+    kzg_proofs::SECRET,
+    utils::{get_manifest_dir, get_trusted_setup_path, hex_to_bytes, G1_NOT_IN_SUBGROUP},
+};
 use crate::test_vectors::{
-    compute_cells, compute_cells_and_kzg_proofs, compute_verify_cell_kzg_proof_batch_challenge,
+    compute_cells, compute_cells_and_kzg_proofs, compute_columns_for_custody_group,
+    compute_verify_cell_kzg_proof_batch_challenge, get_custody_groups,
     recover_cells_and_kzg_proofs, verify_cell_kzg_proof_batch,
 };
 use kzg::{
-    eth::{self, FIELD_ELEMENTS_PER_CELL},
-    EcBackend, Fr, DAS, G1,
+    common_utils::reverse_bit_order,
+    eip_4844::{
+        blob_to_kzg_commitment_raw, blob_to_kzg_commitment_rust, bytes_to_blob,
+        compute_blob_kzg_proof_raw, compute_blob_kzg_proof_rust,
+        compute_blob_kzg_proof_with_transcript, compute_challenge_rust,
+        compute_challenge_with_transcript, compute_kzg_proof_rust,
+        verify_blob_kzg_proof_batch_randomized, verify_blob_kzg_proof_batch_raw_with_validation,
+        verify_blob_kzg_proof_batch_rust, verify_blob_kzg_proof_batch_with_transcript,
+        verify_blob_kzg_proof_raw_with_validation, verify_blob_kzg_proof_rust,
+        verify_blob_kzg_proof_with_transcript, verify_kzg_proof_raw_with_validation,
+        BYTES_PER_FIELD_ELEMENT,
+    },
+    eth::{
+        self,
+        batch_verifier::BatchVerifier,
+        custody, das_2d,
+        eip_7594::{
+            cells_to_blob, compute_cell_kzg_proofs_raw, compute_cells_and_kzg_proofs_raw,
+            recover_cells_correcting_errors_raw, recover_cells_raw,
+            verify_cell_kzg_proof_batch_raw_with_validation,
+        },
+        point_proofs::{
+            compute_all_blob_point_proofs_raw, verify_all_blob_point_proofs,
+            verify_all_blob_point_proofs_raw, BlobPointProofSettings,
+        },
+        sampling::{availability_confidence, DasSampler, InMemoryNetwork},
+        sidecar::{
+            verify_data_column_sidecar_kzg_proofs, BeaconBlockHeader, BlobSidecar,
+            DataColumnSidecar, SignedBeaconBlockHeader, BYTES_PER_SIGNATURE,
+            KZG_COMMITMENTS_INCLUSION_PROOF_DEPTH, KZG_COMMITMENT_INCLUSION_PROOF_DEPTH,
+        },
+        FIELD_ELEMENTS_PER_CELL,
+    },
+    new_das_settings,
+    transcript::{Sha256Transcript, Transcript},
+    EcBackend, FFTSettings, Fr, G1Affine, G1LinComb, KZGSettings, PointValidation, DAS, G1, G2,
 };
-use std::{fs, path::PathBuf};
+use rand::rngs::{OsRng, StdRng};
+use std::{collections::HashSet, fs, path::PathBuf};
 
 const COMPUTE_CELLS_AND_KZG_PROOFS_TEST_VECTORS: &str =
     "src/test_vectors/compute_cells_and_kzg_proofs/*/*/*";
@@ -19,6 +59,12 @@ const VERIFY_CELL_KZG_PROOF_BATCH_TEST_VECTORS: &str =
 const COMPUTE_VERIFY_CELL_KZG_PROOF_BATCH_CHALLENGE: &str =
     "src/test_vectors/compute_verify_cell_kzg_proof_batch_challenge/*/*/*";
 
+// Not from a consensus-spec-tests release: generated locally with the reference functions of the
+// Fulu `das-core.md`, in the layout of `tests/fulu/networking`.
+const GET_CUSTODY_GROUPS_TEST_VECTORS: &str = "src/test_vectors/get_custody_groups/generated/*/*";
+const COMPUTE_COLUMNS_FOR_CUSTODY_GROUP_TEST_VECTORS: &str =
+    "src/test_vectors/compute_columns_for_custody_group/generated/*/*";
+
 #[allow(clippy::type_complexity)]
 pub fn test_vectors_compute_cells_and_kzg_proofs<B: EcBackend>(
     load_trusted_setup: &dyn Fn(&str) -> Result<B::KZGSettings, String>,
@@ -466,3 +512,1452 @@ pub fn test_vectors_compute_verify_cell_kzg_proof_batch_challenge<B: EcBackend>(
         }
     }
 }
+
+#[allow(clippy::type_complexity)]
+pub fn batch_verifier_reports_failed_items_test<B: EcBackend>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<B::KZGSettings, String>,
+) where
+    B::Fr: Copy,
+    B::G1: Copy,
+{
+    let settings = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+    let blobs = [
+        generate_random_blob_bytes(&mut rng),
+        generate_random_blob_bytes(&mut rng),
+    ];
+
+    let commitments = blobs.map(|blob| {
+        blob_to_kzg_commitment_raw::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(blob, &settings)
+        .unwrap()
+        .to_bytes()
+    });
+    let blob_proofs = [0, 1].map(|i| {
+        compute_blob_kzg_proof_raw::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(blobs[i], commitments[i], &settings)
+        .unwrap()
+        .to_bytes()
+    });
+    let (cells, cell_proofs) = compute_cells_and_kzg_proofs_raw::<B>(blobs[0], &settings).unwrap();
+
+    // all valid
+    let verifier = BatchVerifier::<B>::new(&settings);
+    assert!(verifier.is_empty());
+    assert!(verifier.finalize().is_empty());
+
+    let mut verifier = BatchVerifier::<B>::new(&settings);
+    for i in 0..2 {
+        assert_eq!(
+            verifier.add_blob(&blobs[i], &commitments[i], &blob_proofs[i]),
+            Ok(i)
+        );
+    }
+    for i in 0..4 {
+        assert_eq!(
+            verifier.add_cell(&commitments[0], i, &cells[i], &cell_proofs[i]),
+            Ok(2 + i)
+        );
+    }
+    assert_eq!(verifier.len(), 6);
+    assert!(verifier.finalize().is_empty());
+
+    // invalid proofs are reported by item number, malformed items are rejected when added
+    let mut verifier = BatchVerifier::<B>::new(&settings);
+    assert_eq!(
+        verifier.add_blob(&blobs[0], &commitments[0], &blob_proofs[0]),
+        Ok(0)
+    );
+    assert_eq!(
+        verifier.add_blob(&blobs[1], &commitments[1], &blob_proofs[0]),
+        Ok(1)
+    );
+    assert!(verifier
+        .add_blob(
+            &[0xff; eth::BYTES_PER_BLOB],
+            &commitments[1],
+            &blob_proofs[1]
+        )
+        .is_err());
+    for i in 0..4 {
+        assert_eq!(
+            verifier.add_cell(&commitments[0], i, &cells[i], &cell_proofs[i]),
+            Ok(2 + i)
+        );
+    }
+    assert_eq!(
+        verifier.add_cell(&commitments[0], 5, &cells[5], &cell_proofs[6]),
+        Ok(6)
+    );
+    assert_eq!(
+        verifier.add_cell(&commitments[1], 6, &cells[6], &cell_proofs[6]),
+        Ok(7)
+    );
+    assert!(verifier
+        .add_cell(
+            &commitments[0],
+            eth::CELLS_PER_EXT_BLOB,
+            &cells[0],
+            &cell_proofs[0]
+        )
+        .is_err());
+    assert_eq!(verifier.len(), 8);
+    assert_eq!(verifier.finalize(), [1, 6, 7]);
+}
+
+pub fn data_column_sidecar_test<B: EcBackend>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<B::KZGSettings, String>,
+) where
+    B::Fr: Copy,
+    B::G1: Copy,
+{
+    let settings = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+    let blobs = [
+        generate_random_blob_bytes(&mut rng),
+        generate_random_blob_bytes(&mut rng),
+    ];
+    let commitments = blobs.map(|blob| {
+        blob_to_kzg_commitment_raw::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(blob, &settings)
+        .unwrap()
+        .to_bytes()
+    });
+    let cells_and_proofs =
+        blobs.map(|blob| compute_cells_and_kzg_proofs_raw::<B>(blob, &settings).unwrap());
+
+    let column_index = 5;
+    let signed_block_header = SignedBeaconBlockHeader {
+        message: BeaconBlockHeader {
+            slot: 1,
+            proposer_index: 2,
+            parent_root: [3; 32],
+            state_root: [4; 32],
+            body_root: [5; 32],
+        },
+        signature: [6; BYTES_PER_SIGNATURE],
+    };
+    let sidecar = DataColumnSidecar {
+        index: column_index as u64,
+        column: cells_and_proofs
+            .iter()
+            .map(|(cells, _)| cells[column_index])
+            .collect(),
+        kzg_commitments: commitments.to_vec(),
+        kzg_proofs: cells_and_proofs
+            .iter()
+            .map(|(_, proofs)| proofs[column_index])
+            .collect(),
+        signed_block_header: signed_block_header.clone(),
+        kzg_commitments_inclusion_proof: [[7; 32]; KZG_COMMITMENTS_INCLUSION_PROOF_DEPTH],
+    };
+
+    // SSZ round trip
+    let ssz = sidecar.to_ssz_bytes();
+    assert_eq!(
+        ssz.len(),
+        DataColumnSidecar::SSZ_FIXED_LEN
+            + 2 * (eth::BYTES_PER_CELL + eth::BYTES_PER_COMMITMENT + eth::BYTES_PER_PROOF)
+    );
+    assert_eq!(
+        ssz[8..12],
+        (DataColumnSidecar::SSZ_FIXED_LEN as u32).to_le_bytes()
+    );
+    assert_eq!(
+        DataColumnSidecar::from_ssz_bytes(&ssz).as_ref(),
+        Ok(&sidecar)
+    );
+    assert!(
+        DataColumnSidecar::from_ssz_bytes(&ssz[..DataColumnSidecar::SSZ_FIXED_LEN - 1]).is_err()
+    );
+    assert!(DataColumnSidecar::from_ssz_bytes(&ssz[..ssz.len() - 1]).is_err());
+    let mut invalid_offset = ssz.clone();
+    invalid_offset[8] += 1;
+    assert!(DataColumnSidecar::from_ssz_bytes(&invalid_offset).is_err());
+
+    // proofs
+    assert_eq!(
+        verify_data_column_sidecar_kzg_proofs::<B>(&sidecar, &settings),
+        Ok(true)
+    );
+    let mut swapped_proofs = sidecar.clone();
+    swapped_proofs.kzg_proofs.reverse();
+    assert_eq!(
+        verify_data_column_sidecar_kzg_proofs::<B>(&swapped_proofs, &settings),
+        Ok(false)
+    );
+    let mut other_column = sidecar.clone();
+    other_column.index += 1;
+    assert_eq!(
+        verify_data_column_sidecar_kzg_proofs::<B>(&other_column, &settings),
+        Ok(false)
+    );
+
+    // structure
+    let mut invalid_index = sidecar.clone();
+    invalid_index.index = eth::CELLS_PER_EXT_BLOB as u64;
+    assert!(verify_data_column_sidecar_kzg_proofs::<B>(&invalid_index, &settings).is_err());
+    let mut missing_commitment = sidecar.clone();
+    missing_commitment.kzg_commitments.pop();
+    assert!(verify_data_column_sidecar_kzg_proofs::<B>(&missing_commitment, &settings).is_err());
+    let mut missing_proof = sidecar.clone();
+    missing_proof.kzg_proofs.pop();
+    assert!(verify_data_column_sidecar_kzg_proofs::<B>(&missing_proof, &settings).is_err());
+    let empty = DataColumnSidecar {
+        column: Vec::new(),
+        kzg_commitments: Vec::new(),
+        kzg_proofs: Vec::new(),
+        ..sidecar
+    };
+    assert!(verify_data_column_sidecar_kzg_proofs::<B>(&empty, &settings).is_err());
+
+    // blob sidecars have a fixed size
+    let blob_sidecar = BlobSidecar {
+        index: 1,
+        blob: Box::new(blobs[1]),
+        kzg_commitment: commitments[1],
+        kzg_proof: cells_and_proofs[1].1[0],
+        signed_block_header,
+        kzg_commitment_inclusion_proof: [[8; 32]; KZG_COMMITMENT_INCLUSION_PROOF_DEPTH],
+    };
+    let ssz = blob_sidecar.to_ssz_bytes();
+    assert_eq!(ssz.len(), BlobSidecar::SSZ_LEN);
+    assert_eq!(ssz[8..8 + eth::BYTES_PER_BLOB], blobs[1]);
+    assert_eq!(
+        BlobSidecar::from_ssz_bytes(&ssz).as_ref(),
+        Ok(&blob_sidecar)
+    );
+    assert!(BlobSidecar::from_ssz_bytes(&ssz[1..]).is_err());
+}
+
+pub fn trusted_point_validation_test<B: EcBackend>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<B::KZGSettings, String>,
+) where
+    B::Fr: Copy,
+    B::G1: Copy,
+    B::KZGSettings: Sync,
+{
+    let settings = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+    let blobs = [
+        generate_random_blob_bytes(&mut rng),
+        generate_random_blob_bytes(&mut rng),
+    ];
+    let commitments = blobs.map(|blob| {
+        blob_to_kzg_commitment_raw::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(blob, &settings)
+        .unwrap()
+    });
+    let proofs = [0, 1].map(|i| {
+        compute_blob_kzg_proof_raw::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(blobs[i], commitments[i].to_bytes(), &settings)
+        .unwrap()
+    });
+
+    // points round trip through the unchecked decoders
+    for point in commitments
+        .iter()
+        .chain(&proofs)
+        .chain([&B::G1::identity()])
+    {
+        let decoded = B::G1::from_bytes_unchecked(&point.to_bytes()).unwrap();
+        assert!(decoded.equals(point));
+    }
+    let g2 = &settings.get_g2_monomial()[1];
+    assert!(B::G2::from_bytes_unchecked(&g2.to_bytes())
+        .unwrap()
+        .equals(g2));
+
+    let points = [commitments[0], proofs[0], commitments[1], proofs[1]];
+    let affines = B::G1Affine::into_affines(&points);
+    let uncompressed = affines
+        .iter()
+        .map(|affine| affine.to_bytes_uncompressed())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        B::G1Affine::from_bytes_uncompressed_batch_unchecked(&uncompressed).unwrap(),
+        affines
+    );
+    for (affine, bytes) in affines.iter().zip(&uncompressed) {
+        assert_eq!(
+            B::G1Affine::from_bytes_uncompressed(*bytes).unwrap(),
+            *affine
+        );
+    }
+
+    let commitments = commitments.map(|commitment| commitment.to_bytes());
+    let proofs = proofs.map(|proof| proof.to_bytes());
+
+    for validation in [PointValidation::Checked, PointValidation::Trusted] {
+        assert!(verify_blob_kzg_proof_raw_with_validation::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(blobs[0], commitments[0], proofs[0], &settings, validation)
+        .unwrap());
+        assert!(verify_blob_kzg_proof_batch_raw_with_validation::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(&blobs, &commitments, &proofs, &settings, validation)
+        .unwrap());
+        assert!(!verify_blob_kzg_proof_batch_raw_with_validation::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(
+            &blobs,
+            &commitments,
+            &[proofs[1], proofs[0]],
+            &settings,
+            validation,
+        )
+        .unwrap());
+
+        let (cells, cell_proofs) =
+            compute_cells_and_kzg_proofs_raw::<B>(blobs[0], &settings).unwrap();
+        assert!(verify_cell_kzg_proof_batch_raw_with_validation::<B>(
+            &[commitments[0]; 2],
+            &[0, 7],
+            &[cells[0], cells[7]],
+            &[cell_proofs[0], cell_proofs[7]],
+            &settings,
+            validation,
+        )
+        .unwrap());
+    }
+
+    // A point outside of the subgroup is only rejected when checked
+    let not_in_subgroup = hex_to_bytes(G1_NOT_IN_SUBGROUP);
+    let verify_kzg_proof = |validation| {
+        verify_kzg_proof_raw_with_validation::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(
+            not_in_subgroup,
+            [0; BYTES_PER_FIELD_ELEMENT],
+            [0; BYTES_PER_FIELD_ELEMENT],
+            proofs[0],
+            &settings,
+            validation,
+        )
+    };
+    assert!(verify_kzg_proof(PointValidation::Checked).is_err());
+    assert_eq!(verify_kzg_proof(PointValidation::Trusted), Ok(false));
+}
+
+/// SHA-256 transcript with its own domain separator in front of the protocol ones.
+struct CustomDomainTranscript(Sha256Transcript);
+
+impl Transcript for CustomDomainTranscript {
+    fn new(domain: &[u8]) -> Self {
+        let mut transcript = Sha256Transcript::new(b"CUSTOM_DOMAIN_V1");
+        transcript.absorb_bytes(domain);
+        Self(transcript)
+    }
+
+    fn absorb_bytes(&mut self, bytes: &[u8]) {
+        self.0.absorb_bytes(bytes);
+    }
+
+    fn squeeze_fr<TFr: Fr>(self) -> TFr {
+        self.0.squeeze_fr()
+    }
+}
+
+pub fn custom_transcript_test<B: EcBackend>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<B::KZGSettings, String>,
+) where
+    B::Fr: Copy,
+    B::G1: Copy,
+    B::KZGSettings: Sync,
+{
+    let settings = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+    let blob_bytes = [
+        generate_random_blob_bytes(&mut rng),
+        generate_random_blob_bytes(&mut rng),
+    ];
+    let blobs = blob_bytes.map(|blob| bytes_to_blob::<B::Fr>(&blob).unwrap());
+    let commitments = [0, 1].map(|i| {
+        blob_to_kzg_commitment_rust::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(&blobs[i], &settings)
+        .unwrap()
+    });
+
+    // The default transcript reproduces the spec challenge, a custom one derives another
+    let challenge: B::Fr = compute_challenge_rust(&blobs[0], &commitments[0]);
+    assert!(
+        compute_challenge_with_transcript::<Sha256Transcript, B::Fr, B::G1>(
+            &blobs[0],
+            &commitments[0]
+        ) == challenge
+    );
+    assert!(
+        compute_challenge_with_transcript::<CustomDomainTranscript, B::Fr, B::G1>(
+            &blobs[0],
+            &commitments[0]
+        ) != challenge
+    );
+
+    let proofs = [0, 1].map(|i| {
+        compute_blob_kzg_proof_with_transcript::<
+            CustomDomainTranscript,
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(&blobs[i], &commitments[i], &settings)
+        .unwrap()
+    });
+
+    // Proofs only verify with the transcript they were made with
+    assert_eq!(
+        verify_blob_kzg_proof_with_transcript::<
+            CustomDomainTranscript,
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(&blobs[0], &commitments[0], &proofs[0], &settings),
+        Ok(true)
+    );
+    assert_eq!(
+        verify_blob_kzg_proof_rust::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(&blobs[0], &commitments[0], &proofs[0], &settings),
+        Ok(false)
+    );
+    assert_eq!(
+        verify_blob_kzg_proof_batch_with_transcript::<
+            CustomDomainTranscript,
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(&blobs, &commitments, &proofs, &settings),
+        Ok(true)
+    );
+    assert_eq!(
+        verify_blob_kzg_proof_batch_rust::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(&blobs, &commitments, &proofs, &settings),
+        Ok(false)
+    );
+
+    // Cell proofs do not depend on the transcript, only the batch challenge does
+    let (cells, cell_proofs) =
+        compute_cells_and_kzg_proofs_raw::<B>(blob_bytes[0], &settings).unwrap();
+    let cell_indices = [0, 7, 100];
+    let cells = cell_indices
+        .iter()
+        .flat_map(|&i| cells[i].chunks(BYTES_PER_FIELD_ELEMENT))
+        .map(|bytes| B::Fr::from_bytes(bytes).unwrap())
+        .collect::<Vec<_>>();
+    let cell_proofs = cell_indices.map(|i| B::G1::from_bytes(&cell_proofs[i]).unwrap());
+    assert_eq!(
+        <B::KZGSettings as DAS<B>>::verify_cell_kzg_proof_batch_with_transcript::<
+            CustomDomainTranscript,
+        >(
+            &settings,
+            &[commitments[0]; 3],
+            &cell_indices,
+            &cells,
+            &cell_proofs,
+        ),
+        Ok(true)
+    );
+    assert_eq!(
+        <B::KZGSettings as DAS<B>>::verify_cell_kzg_proof_batch_with_transcript::<
+            CustomDomainTranscript,
+        >(
+            &settings,
+            &[commitments[0]; 3],
+            &cell_indices,
+            &cells,
+            &[cell_proofs[0], cell_proofs[2], cell_proofs[1]],
+        ),
+        Ok(false)
+    );
+}
+
+pub fn randomized_batch_verification_test<B: EcBackend>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<B::KZGSettings, String>,
+) where
+    B::Fr: Copy,
+    B::G1: Copy,
+    B::KZGSettings: Sync,
+{
+    let settings = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+    let blob_bytes = [
+        generate_random_blob_bytes(&mut rng),
+        generate_random_blob_bytes(&mut rng),
+        generate_random_blob_bytes(&mut rng),
+    ];
+    let blobs = blob_bytes
+        .iter()
+        .map(|blob| bytes_to_blob::<B::Fr>(blob).unwrap())
+        .collect::<Vec<_>>();
+    let commitments = blobs
+        .iter()
+        .map(|blob| {
+            blob_to_kzg_commitment_rust::<
+                B::Fr,
+                B::G1,
+                B::G2,
+                B::FFTSettings,
+                B::Poly,
+                B::KZGSettings,
+                B::G1Fp,
+                B::G1Affine,
+                B::G1ProjAddAffine,
+            >(blob, &settings)
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+    let proofs = blobs
+        .iter()
+        .zip(&commitments)
+        .map(|(blob, commitment)| {
+            compute_blob_kzg_proof_rust::<
+                B::Fr,
+                B::G1,
+                B::G2,
+                B::FFTSettings,
+                B::Poly,
+                B::KZGSettings,
+                B::G1Fp,
+                B::G1Affine,
+                B::G1ProjAddAffine,
+            >(blob, commitment, &settings)
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+
+    let verify_blobs = |commitments: &[B::G1], proofs: &[B::G1]| {
+        verify_blob_kzg_proof_batch_randomized::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(&blobs, commitments, proofs, &settings, &mut OsRng)
+    };
+    assert_eq!(verify_blobs(&commitments, &proofs), Ok(true));
+    assert_eq!(
+        verify_blobs(&commitments, &[proofs[0], proofs[2], proofs[1]]),
+        Ok(false)
+    );
+    assert!(verify_blobs(&commitments, &proofs[..2]).is_err());
+
+    let (cells, cell_proofs) =
+        compute_cells_and_kzg_proofs_raw::<B>(blob_bytes[0], &settings).unwrap();
+    let cell_indices = [1, 2, 64, 127];
+    let cells = cell_indices
+        .iter()
+        .flat_map(|&i| cells[i].chunks(BYTES_PER_FIELD_ELEMENT))
+        .map(|bytes| B::Fr::from_bytes(bytes).unwrap())
+        .collect::<Vec<_>>();
+    let cell_proofs = cell_indices.map(|i| B::G1::from_bytes(&cell_proofs[i]).unwrap());
+
+    let verify_cells = |cell_indices: &[usize], proofs: &[B::G1]| {
+        <B::KZGSettings as DAS<B>>::verify_cell_kzg_proof_batch_randomized(
+            &settings,
+            &[commitments[0]; 4],
+            cell_indices,
+            &cells,
+            proofs,
+            &mut OsRng,
+        )
+    };
+    assert_eq!(verify_cells(&cell_indices, &cell_proofs), Ok(true));
+    assert_eq!(verify_cells(&[1, 2, 64, 126], &cell_proofs), Ok(false));
+    assert!(verify_cells(&cell_indices, &cell_proofs[..3]).is_err());
+}
+
+pub fn all_blob_point_proofs_test<B: EcBackend>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<B::KZGSettings, String>,
+) where
+    B::Fr: Copy,
+    B::G1: Copy,
+{
+    let settings = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let point_proof_settings = BlobPointProofSettings::<B>::new(&settings).unwrap();
+    let mut rng = rand::thread_rng();
+    let blob_bytes = generate_random_blob_bytes(&mut rng);
+    let blob = bytes_to_blob::<B::Fr>(&blob_bytes).unwrap();
+    let commitment = blob_to_kzg_commitment_raw::<
+        B::Fr,
+        B::G1,
+        B::G2,
+        B::FFTSettings,
+        B::Poly,
+        B::KZGSettings,
+        B::G1Fp,
+        B::G1Affine,
+        B::G1ProjAddAffine,
+    >(blob_bytes, &settings)
+    .unwrap();
+
+    let proofs = point_proof_settings
+        .compute_all_blob_point_proofs(&blob)
+        .unwrap();
+    assert_eq!(proofs.len(), blob.len());
+
+    // Spot check the proofs against the single point prover
+    let roots_of_unity = settings.get_fft_settings().get_brp_roots_of_unity();
+    for i in [0, 1, 17, 2048, 4095] {
+        let (proof, y) = compute_kzg_proof_rust::<
+            B::Fr,
+            B::G1,
+            B::G2,
+            B::FFTSettings,
+            B::Poly,
+            B::KZGSettings,
+            B::G1Fp,
+            B::G1Affine,
+            B::G1ProjAddAffine,
+        >(&blob, &roots_of_unity[i], &settings)
+        .unwrap();
+        assert!(y.equals(&blob[i]));
+        assert!(proofs[i].equals(&proof), "proof {i} differs");
+    }
+
+    assert_eq!(
+        verify_all_blob_point_proofs::<B>(&commitment, &blob, &proofs, &settings),
+        Ok(true)
+    );
+
+    let mut swapped_proofs = proofs.clone();
+    swapped_proofs.swap(3, 4);
+    assert_eq!(
+        verify_all_blob_point_proofs::<B>(&commitment, &blob, &swapped_proofs, &settings),
+        Ok(false)
+    );
+    assert!(
+        verify_all_blob_point_proofs::<B>(&commitment, &blob, &proofs[1..], &settings).is_err()
+    );
+    assert!(point_proof_settings
+        .compute_all_blob_point_proofs(&blob[1..])
+        .is_err());
+
+    let proofs_bytes =
+        compute_all_blob_point_proofs_raw::<B>(blob_bytes, &point_proof_settings).unwrap();
+    assert!(proofs_bytes
+        .iter()
+        .zip(&proofs)
+        .all(|(bytes, proof)| *bytes == proof.to_bytes()));
+    assert_eq!(
+        verify_all_blob_point_proofs_raw::<B>(
+            commitment.to_bytes(),
+            blob_bytes,
+            &proofs_bytes,
+            &settings
+        ),
+        Ok(true)
+    );
+}
+
+pub fn compute_cell_kzg_proofs_test<B: EcBackend>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<B::KZGSettings, String>,
+) where
+    B::Fr: Copy,
+    B::G1: Copy,
+{
+    let settings = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+    let blob_bytes = generate_random_blob_bytes(&mut rng);
+    let blob = bytes_to_blob::<B::Fr>(&blob_bytes).unwrap();
+
+    let mut proofs = vec![B::G1::default(); eth::CELLS_PER_EXT_BLOB];
+    <B::KZGSettings as DAS<B>>::compute_cells_and_kzg_proofs(
+        &settings,
+        None,
+        Some(&mut proofs),
+        &blob,
+    )
+    .unwrap();
+
+    let all_cell_indices = (0..eth::CELLS_PER_EXT_BLOB).collect::<Vec<_>>();
+    // Both sides of the FK20 threshold, duplicates and cells of both halves of the extension
+    for cell_indices in [
+        &[][..],
+        &[0],
+        &[127, 3, 64],
+        &[5, 5, 100, 5],
+        &[1, 2, 3, 4, 5],
+        &all_cell_indices,
+    ] {
+        let cell_proofs =
+            <B::KZGSettings as DAS<B>>::compute_cell_kzg_proofs(&settings, &blob, cell_indices)
+                .unwrap();
+        assert_eq!(cell_proofs.len(), cell_indices.len());
+        for (index, proof) in cell_indices.iter().zip(&cell_proofs) {
+            assert!(
+                proof.equals(&proofs[*index]),
+                "proof of cell {index} differs"
+            );
+        }
+    }
+
+    assert!(<B::KZGSettings as DAS<B>>::compute_cell_kzg_proofs(
+        &settings,
+        &blob,
+        &[0, eth::CELLS_PER_EXT_BLOB]
+    )
+    .is_err());
+    assert!(
+        <B::KZGSettings as DAS<B>>::compute_cell_kzg_proofs(&settings, &blob[1..], &[0]).is_err()
+    );
+
+    let proofs_bytes = compute_cell_kzg_proofs_raw::<B>(blob_bytes, &[9, 90], &settings).unwrap();
+    assert_eq!(
+        proofs_bytes,
+        vec![proofs[9].to_bytes(), proofs[90].to_bytes()]
+    );
+}
+
+pub fn recover_cells_without_proofs_test<B: EcBackend>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<B::KZGSettings, String>,
+) where
+    B::Fr: Copy,
+    B::G1: Copy,
+{
+    let settings = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+    let blob_bytes = generate_random_blob_bytes(&mut rng);
+
+    let (cells, _) = compute_cells_and_kzg_proofs_raw::<B>(blob_bytes, &settings).unwrap();
+
+    // Keep the upper half of the cells, which are not part of the blob
+    let half = eth::CELLS_PER_EXT_BLOB / 2;
+    let cell_indices = (half..eth::CELLS_PER_EXT_BLOB).collect::<Vec<_>>();
+    let recovered_cells = recover_cells_raw::<B>(&cell_indices, &cells[half..], &settings).unwrap();
+    assert_eq!(recovered_cells, cells);
+    assert_eq!(cells_to_blob(&recovered_cells), Ok(blob_bytes));
+
+    assert!(recover_cells_raw::<B>(&cell_indices[1..], &cells[half + 1..], &settings).is_err());
+    assert!(cells_to_blob(&recovered_cells[1..]).is_err());
+}
+
+pub fn recover_cells_correcting_errors_test<B: EcBackend>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<B::KZGSettings, String>,
+) where
+    B::Fr: Copy,
+    B::G1: Copy,
+{
+    let settings = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+    let blob_bytes = generate_random_blob_bytes(&mut rng);
+    let (cells, _) = compute_cells_and_kzg_proofs_raw::<B>(blob_bytes, &settings).unwrap();
+    let (other_cells, _) =
+        compute_cells_and_kzg_proofs_raw::<B>(generate_random_blob_bytes(&mut rng), &settings)
+            .unwrap();
+
+    // 8 cells more than needed: up to 4 of them may be wrong
+    let kept = eth::CELLS_PER_EXT_BLOB / 2 + 8;
+    let cell_indices =
+        (eth::CELLS_PER_EXT_BLOB - kept..eth::CELLS_PER_EXT_BLOB).collect::<Vec<_>>();
+    let mut received = cell_indices.iter().map(|&i| cells[i]).collect::<Vec<_>>();
+    for i in [2, 5, 40, 71] {
+        received[i] = other_cells[cell_indices[i]];
+    }
+
+    let (recovered_cells, wrong_cells) =
+        recover_cells_correcting_errors_raw::<B>(&cell_indices, &received, &settings).unwrap();
+    assert_eq!(recovered_cells, cells);
+    assert_eq!(
+        wrong_cells,
+        [2, 5, 40, 71].map(|i| cell_indices[i]).to_vec()
+    );
+
+    // Erasure recovery takes the wrong cells at face value
+    assert_ne!(
+        recover_cells_raw::<B>(&cell_indices, &received, &settings).unwrap(),
+        cells
+    );
+
+    received[10] = other_cells[cell_indices[10]];
+    assert!(recover_cells_correcting_errors_raw::<B>(&cell_indices, &received, &settings).is_err());
+    assert!(recover_cells_correcting_errors_raw::<B>(
+        &cell_indices[..eth::CELLS_PER_EXT_BLOB / 2 - 1],
+        &received[..eth::CELLS_PER_EXT_BLOB / 2 - 1],
+        &settings
+    )
+    .is_err());
+}
+
+pub fn recover_cells_and_kzg_proofs_batch_test<B: EcBackend>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<B::KZGSettings, String>,
+) where
+    B::KZGSettings: Sync,
+{
+    let settings = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+    let cell_size = eth::FIELD_ELEMENTS_PER_CELL;
+    let half = eth::CELLS_PER_EXT_BLOB / 2;
+
+    let upper_half = (half..eth::CELLS_PER_EXT_BLOB).collect::<Vec<_>>();
+    let even = (0..eth::CELLS_PER_EXT_BLOB).step_by(2).collect::<Vec<_>>();
+    let all = (0..eth::CELLS_PER_EXT_BLOB).collect::<Vec<_>>();
+    // Blobs sharing a loss pattern are interleaved with blobs that lost other cells
+    let patterns = [&upper_half, &even, &upper_half, &all, &upper_half, &even];
+
+    let mut expected_cells = Vec::new();
+    let mut expected_proofs = Vec::new();
+    let mut cell_indices = Vec::new();
+    let mut partial_cells = Vec::new();
+    for pattern in patterns {
+        let blob = bytes_to_blob::<B::Fr>(&generate_random_blob_bytes(&mut rng)).unwrap();
+        let mut cells = vec![B::Fr::default(); eth::CELLS_PER_EXT_BLOB * cell_size];
+        let mut proofs = vec![B::G1::default(); eth::CELLS_PER_EXT_BLOB];
+        <B::KZGSettings as DAS<B>>::compute_cells_and_kzg_proofs(
+            &settings,
+            Some(&mut cells),
+            Some(&mut proofs),
+            &blob,
+        )
+        .unwrap();
+
+        cell_indices.push(pattern.clone());
+        partial_cells.push(
+            pattern
+                .iter()
+                .flat_map(|&i| cells[i * cell_size..(i + 1) * cell_size].to_vec())
+                .collect::<Vec<_>>(),
+        );
+        expected_cells.push(cells);
+        expected_proofs.push(proofs);
+    }
+
+    let (recovered_cells, recovered_proofs) =
+        <B::KZGSettings as DAS<B>>::recover_cells_and_kzg_proofs_batch(
+            &settings,
+            &cell_indices,
+            &partial_cells,
+        )
+        .unwrap();
+    assert_eq!(recovered_cells, expected_cells);
+    assert!(recovered_proofs
+        .iter()
+        .flatten()
+        .zip(expected_proofs.iter().flatten())
+        .all(|(recovered, expected)| recovered.equals(expected)));
+
+    // An invalid entry fails the batch with the same error as recovering it alone
+    cell_indices[2].swap(0, 1);
+    let mut cells = vec![B::Fr::default(); eth::CELLS_PER_EXT_BLOB * cell_size];
+    let err = <B::KZGSettings as DAS<B>>::recover_cells_and_kzg_proofs(
+        &settings,
+        &mut cells,
+        None,
+        &cell_indices[2],
+        &partial_cells[2],
+    )
+    .unwrap_err();
+    assert_eq!(
+        <B::KZGSettings as DAS<B>>::recover_cells_and_kzg_proofs_batch(
+            &settings,
+            &cell_indices,
+            &partial_cells,
+        )
+        .err(),
+        Some(err)
+    );
+}
+
+#[allow(clippy::type_complexity)]
+pub fn das_extension_factor_and_cell_size_test<B: EcBackend>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<B::G1>, Vec<B::G1>, Vec<B::G2>),
+) where
+    B::Fr: Copy,
+{
+    let field_elements_per_blob = 32;
+    let (g1_monomial, mut g1_lagrange_brp, g2_monomial) =
+        generate_trusted_setup(field_elements_per_blob, SECRET);
+    reverse_bit_order(&mut g1_lagrange_brp).unwrap();
+
+    for (extension_factor, cell_size) in [(2, 4), (4, 8), (4, 16), (8, 2)] {
+        let settings = new_das_settings::<B>(
+            &g1_monomial,
+            &g1_lagrange_brp,
+            &g2_monomial,
+            extension_factor,
+            cell_size,
+        )
+        .unwrap();
+        assert_eq!(settings.get_extension_factor(), extension_factor);
+
+        let field_elements_per_ext_blob = field_elements_per_blob * extension_factor;
+        let cells_per_ext_blob = field_elements_per_ext_blob / cell_size;
+
+        let blob = (0..field_elements_per_blob)
+            .map(|_| B::Fr::rand())
+            .collect::<Vec<_>>();
+        let commitment = B::G1::g1_lincomb(&g1_lagrange_brp, &blob, field_elements_per_blob, None);
+
+        let mut cells = vec![B::Fr::default(); field_elements_per_ext_blob];
+        let mut proofs = vec![B::G1::default(); cells_per_ext_blob];
+        <B::KZGSettings as DAS<B>>::compute_cells_and_kzg_proofs(
+            &settings,
+            Some(&mut cells),
+            Some(&mut proofs),
+            &blob,
+        )
+        .unwrap();
+        // The extended blob starts with the blob itself
+        assert_eq!(cells[..field_elements_per_blob], blob);
+
+        let cell_indices = (0..cells_per_ext_blob).collect::<Vec<_>>();
+        let commitments = vec![commitment; cells_per_ext_blob];
+        assert!(
+            <B::KZGSettings as DAS<B>>::verify_cell_kzg_proof_batch(
+                &settings,
+                &commitments,
+                &cell_indices,
+                &cells,
+                &proofs,
+            )
+            .unwrap(),
+            "proofs rejected for extension factor {extension_factor}, cell size {cell_size}"
+        );
+
+        let mut other_cells = cells.clone();
+        other_cells[cell_size] = other_cells[cell_size].add(&B::Fr::one());
+        assert!(!<B::KZGSettings as DAS<B>>::verify_cell_kzg_proof_batch(
+            &settings,
+            &commitments,
+            &cell_indices,
+            &other_cells,
+            &proofs,
+        )
+        .unwrap());
+
+        let direct_proofs = <B::KZGSettings as DAS<B>>::compute_cell_kzg_proofs(
+            &settings,
+            &blob,
+            &[1, cells_per_ext_blob - 1],
+        )
+        .unwrap();
+        assert!(direct_proofs[0].equals(&proofs[1]));
+        assert!(direct_proofs[1].equals(&proofs[cells_per_ext_blob - 1]));
+
+        // Any 1 / extension_factor of the cells is enough to recover the rest
+        let first_kept = cells_per_ext_blob - cells_per_ext_blob / extension_factor;
+        let mut recovered_cells = vec![B::Fr::default(); field_elements_per_ext_blob];
+        let mut recovered_proofs = vec![B::G1::default(); cells_per_ext_blob];
+        <B::KZGSettings as DAS<B>>::recover_cells_and_kzg_proofs(
+            &settings,
+            &mut recovered_cells,
+            Some(&mut recovered_proofs),
+            &cell_indices[first_kept..],
+            &cells[first_kept * cell_size..],
+        )
+        .unwrap();
+        assert_eq!(recovered_cells, cells);
+        assert!(recovered_proofs
+            .iter()
+            .zip(&proofs)
+            .all(|(recovered, proof)| recovered.equals(proof)));
+
+        assert!(<B::KZGSettings as DAS<B>>::recover_cells_and_kzg_proofs(
+            &settings,
+            &mut recovered_cells,
+            None,
+            &cell_indices[first_kept + 1..],
+            &cells[(first_kept + 1) * cell_size..],
+        )
+        .is_err());
+    }
+
+    for (extension_factor, cell_size) in [(1, 4), (3, 4), (2, 0), (2, 3), (2, 32)] {
+        assert!(new_das_settings::<B>(
+            &g1_monomial,
+            &g1_lagrange_brp,
+            &g2_monomial,
+            extension_factor,
+            cell_size,
+        )
+        .is_err());
+    }
+
+    // The batch verifier takes cells of the Ethereum size, the upper half of the indices only
+    // exists with the 4x extension
+    let field_elements_per_blob = 2 * eth::FIELD_ELEMENTS_PER_CELL;
+    let (g1_monomial, mut g1_lagrange_brp, g2_monomial) =
+        generate_trusted_setup(field_elements_per_blob, SECRET);
+    reverse_bit_order(&mut g1_lagrange_brp).unwrap();
+    let settings = new_das_settings::<B>(
+        &g1_monomial,
+        &g1_lagrange_brp,
+        &g2_monomial,
+        4,
+        eth::FIELD_ELEMENTS_PER_CELL,
+    )
+    .unwrap();
+    let cells_per_ext_blob = 4 * field_elements_per_blob / eth::FIELD_ELEMENTS_PER_CELL;
+
+    let blob = (0..field_elements_per_blob)
+        .map(|_| B::Fr::rand())
+        .collect::<Vec<_>>();
+    let commitment = B::G1::g1_lincomb(&g1_lagrange_brp, &blob, field_elements_per_blob, None);
+    let mut cells = vec![B::Fr::default(); 4 * field_elements_per_blob];
+    let mut proofs = vec![B::G1::default(); cells_per_ext_blob];
+    <B::KZGSettings as DAS<B>>::compute_cells_and_kzg_proofs(
+        &settings,
+        Some(&mut cells),
+        Some(&mut proofs),
+        &blob,
+    )
+    .unwrap();
+
+    let mut verifier = BatchVerifier::<B>::new(&settings);
+    for (i, (cell, proof)) in cells
+        .chunks(eth::FIELD_ELEMENTS_PER_CELL)
+        .zip(&proofs)
+        .enumerate()
+    {
+        let cell: [u8; eth::BYTES_PER_CELL] = cell
+            .iter()
+            .flat_map(|fr| fr.to_bytes())
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+        verifier
+            .add_cell(&commitment.to_bytes(), i, &cell, &proof.to_bytes())
+            .unwrap();
+        if i == 0 {
+            assert!(verifier
+                .add_cell(
+                    &commitment.to_bytes(),
+                    cells_per_ext_blob,
+                    &cell,
+                    &proof.to_bytes()
+                )
+                .is_err());
+        }
+    }
+    assert!(verifier.finalize().is_empty());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn das_2d_test<B: EcBackend>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<B::G1>, Vec<B::G1>, Vec<B::G2>),
+) {
+    let field_elements_per_blob = 32;
+    let cell_size = 4;
+    let (g1_monomial, mut g1_lagrange_brp, g2_monomial) =
+        generate_trusted_setup(field_elements_per_blob, SECRET);
+    reverse_bit_order(&mut g1_lagrange_brp).unwrap();
+    let settings =
+        new_das_settings::<B>(&g1_monomial, &g1_lagrange_brp, &g2_monomial, 2, cell_size).unwrap();
+    let cells_per_ext_blob = 2 * field_elements_per_blob / cell_size;
+
+    let blob_count = 4;
+    let blobs = (0..blob_count)
+        .map(|_| {
+            (0..field_elements_per_blob)
+                .map(|_| B::Fr::rand())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let commitments = blobs
+        .iter()
+        .map(|blob| B::G1::g1_lincomb(&g1_lagrange_brp, blob, field_elements_per_blob, None))
+        .collect::<Vec<_>>();
+
+    let matrix = das_2d::compute_cell_matrix::<B>(&blobs, &settings).unwrap();
+    assert_eq!(matrix.commitments.len(), 2 * blob_count);
+    assert_eq!(matrix.cells.len(), 2 * blob_count);
+    assert_eq!(matrix.proofs.len(), 2 * blob_count);
+    assert!(matrix.commitments[..blob_count]
+        .iter()
+        .zip(&commitments)
+        .all(|(extended, commitment)| extended.equals(commitment)));
+
+    let cell_indices = (0..cells_per_ext_blob).collect::<Vec<_>>();
+    for (row, (cells, proofs)) in matrix.cells.iter().zip(&matrix.proofs).enumerate() {
+        // Every row is the extension of the blob committed to by its extended commitment
+        let row_blob = &cells[..field_elements_per_blob];
+        if row < blob_count {
+            assert_eq!(row_blob, blobs[row]);
+        }
+        assert!(
+            B::G1::g1_lincomb(&g1_lagrange_brp, row_blob, field_elements_per_blob, None)
+                .equals(&matrix.commitments[row])
+        );
+
+        assert!(
+            das_2d::verify_cell_matrix_cells::<B>(
+                &commitments,
+                &vec![row; cells_per_ext_blob],
+                &cell_indices,
+                cells,
+                proofs,
+                &settings,
+            )
+            .unwrap(),
+            "cells of row {row} rejected"
+        );
+    }
+
+    let mut other_cells = matrix.cells[blob_count + 1].clone();
+    other_cells[0] = other_cells[0].add(&B::Fr::one());
+    assert!(!das_2d::verify_cell_matrix_cells::<B>(
+        &commitments,
+        &vec![blob_count + 1; cells_per_ext_blob],
+        &cell_indices,
+        &other_cells,
+        &matrix.proofs[blob_count + 1],
+        &settings,
+    )
+    .unwrap());
+
+    // An extension row is recovered from half of its cells like any blob
+    let row = blob_count + 1;
+    let (recovered_cells, recovered_proofs) = das_2d::recover_row::<B>(
+        &cell_indices[cells_per_ext_blob / 2..],
+        &matrix.cells[row][field_elements_per_blob..],
+        &settings,
+    )
+    .unwrap();
+    assert_eq!(recovered_cells, matrix.cells[row]);
+    assert!(recovered_proofs
+        .iter()
+        .zip(&matrix.proofs[row])
+        .all(|(recovered, proof)| recovered.equals(proof)));
+
+    // A column is recovered from half of its rows
+    let column = 3;
+    let row_indices = [6, 1, 7, 4];
+    let column_cells = row_indices
+        .iter()
+        .flat_map(|&row| matrix.cells[row][column * cell_size..(column + 1) * cell_size].to_vec())
+        .collect::<Vec<_>>();
+    let column_proofs = row_indices
+        .iter()
+        .map(|&row| matrix.proofs[row][column].clone())
+        .collect::<Vec<_>>();
+    let (recovered_cells, recovered_proofs) = das_2d::recover_column::<B>(
+        &row_indices,
+        &column_cells,
+        &column_proofs,
+        blob_count,
+        &settings,
+    )
+    .unwrap();
+    for row in 0..2 * blob_count {
+        assert_eq!(
+            recovered_cells[row * cell_size..(row + 1) * cell_size],
+            matrix.cells[row][column * cell_size..(column + 1) * cell_size]
+        );
+        assert!(recovered_proofs[row].equals(&matrix.proofs[row][column]));
+    }
+
+    assert!(das_2d::extend_commitments::<B>(&commitments[..3], &settings).is_err());
+    assert!(das_2d::compute_cell_matrix::<B>(&blobs[..3], &settings).is_err());
+    assert!(das_2d::recover_column::<B>(
+        &row_indices[..3],
+        &column_cells[..3 * cell_size],
+        &column_proofs[..3],
+        blob_count,
+        &settings,
+    )
+    .is_err());
+    assert!(das_2d::recover_column::<B>(
+        &[6, 1, 6, 4],
+        &column_cells,
+        &column_proofs,
+        blob_count,
+        &settings,
+    )
+    .is_err());
+    assert!(das_2d::recover_column::<B>(
+        &[6, 1, 8, 4],
+        &column_cells,
+        &column_proofs,
+        blob_count,
+        &settings,
+    )
+    .is_err());
+    assert!(das_2d::verify_cell_matrix_cells::<B>(
+        &commitments,
+        &[2 * blob_count],
+        &[0],
+        &matrix.cells[0][..cell_size],
+        &matrix.proofs[0][..1],
+        &settings,
+    )
+    .is_err());
+}
+
+pub fn das_sampling_test<B: EcBackend>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<B::KZGSettings, String>,
+) where
+    B::Fr: Copy,
+    B::G1: Copy,
+{
+    let settings = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+    let blobs = [
+        generate_random_blob_bytes(&mut rng),
+        generate_random_blob_bytes(&mut rng),
+    ];
+    let commitments = blobs
+        .map(|blob| {
+            blob_to_kzg_commitment_raw::<
+                B::Fr,
+                B::G1,
+                B::G2,
+                B::FFTSettings,
+                B::Poly,
+                B::KZGSettings,
+                B::G1Fp,
+                B::G1Affine,
+                B::G1ProjAddAffine,
+            >(blob, &settings)
+            .unwrap()
+            .to_bytes()
+        })
+        .to_vec();
+    let cells_and_proofs = blobs
+        .map(|blob| compute_cells_and_kzg_proofs_raw::<B>(blob, &settings).unwrap())
+        .to_vec();
+
+    // The same seed picks the same cells
+    let mut sampler = DasSampler::<StdRng>::from_seed(7, commitments.clone());
+    let samples = sampler.pick_cells(8);
+    assert_eq!(
+        samples,
+        DasSampler::<StdRng>::from_seed(7, commitments.clone()).pick_cells(8)
+    );
+    assert_eq!(samples.len(), 16);
+    for blob_index in 0..2 {
+        let cell_indices = samples
+            .iter()
+            .filter(|(blob, _)| *blob == blob_index)
+            .map(|(_, cell)| *cell)
+            .collect::<HashSet<_>>();
+        assert_eq!(cell_indices.len(), 8);
+        assert!(cell_indices
+            .iter()
+            .all(|&cell| cell < eth::CELLS_PER_EXT_BLOB));
+    }
+
+    let mut network = InMemoryNetwork::new(&cells_and_proofs);
+    assert_eq!(sampler.confidence(0.5), 0.0);
+    let round = sampler
+        .sample::<B>(&samples, &mut network, &settings)
+        .unwrap();
+    assert_eq!(round.verified, samples);
+    assert!(round.missing.is_empty() && round.invalid.is_empty());
+    assert_eq!(sampler.verified_cell_count(0), 8);
+    assert_eq!(
+        sampler.confidence(0.5),
+        availability_confidence(eth::CELLS_PER_EXT_BLOB, 8, 0.5)
+    );
+    assert!(sampler.confidence(0.5) > 0.99);
+
+    // Verified cells are not picked again
+    let next_samples = sampler.pick_cells(8);
+    assert!(next_samples.iter().all(|sample| !samples.contains(sample)));
+
+    let columns = sampler.pick_columns(4);
+    assert_eq!(columns.iter().collect::<HashSet<_>>().len(), 4);
+
+    // Withheld and corrupted cells are reported, and do not count
+    network.withhold(1, columns[0]);
+    let wrong_cell = network.cells[0][columns[1]].as_mut().unwrap();
+    wrong_cell.proof = cells_and_proofs[0].1[(columns[1] + 1) % eth::CELLS_PER_EXT_BLOB];
+    let round = sampler
+        .sample_columns::<B>(&columns, &mut network, &settings)
+        .unwrap();
+    assert_eq!(round.missing, vec![(1, columns[0])]);
+    assert_eq!(round.invalid, vec![(0, columns[1])]);
+    assert_eq!(round.verified.len(), 6);
+
+    assert!(sampler
+        .sample::<B>(&[(2, 0)], &mut network, &settings)
+        .is_err());
+    assert!(sampler
+        .sample::<B>(&[(0, eth::CELLS_PER_EXT_BLOB)], &mut network, &settings)
+        .is_err());
+
+    assert_eq!(availability_confidence(128, 0, 0.5), 0.0);
+    assert_eq!(availability_confidence(128, 1, 0.5), 0.5);
+    assert_eq!(availability_confidence(128, 65, 0.5), 1.0);
+    assert!(
+        (availability_confidence(128, 2, 0.5) - (1.0 - 64.0 / 128.0 * 63.0 / 127.0)).abs() < 1e-12
+    );
+}
+
+/// Runs the locally generated `get_custody_groups` vectors, not ones from a spec tests release.
+pub fn test_vectors_get_custody_groups() {
+    let test_files: Vec<PathBuf> = glob::glob(&format!(
+        "{}/{}",
+        get_manifest_dir(),
+        GET_CUSTODY_GROUPS_TEST_VECTORS
+    ))
+    .unwrap()
+    .collect::<Result<Vec<_>, _>>()
+    .unwrap();
+    assert!(!test_files.is_empty());
+
+    for test_file in test_files {
+        let yaml_data = fs::read_to_string(test_file.clone()).unwrap();
+        let test: get_custody_groups::Test = serde_yaml::from_str(&yaml_data).unwrap();
+
+        let custody_groups = custody::get_custody_groups(
+            &test.get_node_id_bytes().unwrap(),
+            test.custody_group_count,
+        );
+        assert_eq!(
+            custody_groups,
+            Ok(test.result),
+            "Custody groups do not match, for test vector {:?}",
+            test_file
+        );
+    }
+}
+
+/// Runs the locally generated `compute_columns_for_custody_group` vectors, not ones from a spec
+/// tests release.
+pub fn test_vectors_compute_columns_for_custody_group() {
+    let test_files: Vec<PathBuf> = glob::glob(&format!(
+        "{}/{}",
+        get_manifest_dir(),
+        COMPUTE_COLUMNS_FOR_CUSTODY_GROUP_TEST_VECTORS
+    ))
+    .unwrap()
+    .collect::<Result<Vec<_>, _>>()
+    .unwrap();
+    assert!(!test_files.is_empty());
+
+    for test_file in test_files {
+        let yaml_data = fs::read_to_string(test_file.clone()).unwrap();
+        let test: compute_columns_for_custody_group::Test =
+            serde_yaml::from_str(&yaml_data).unwrap();
+
+        assert_eq!(
+            custody::compute_columns_for_custody_group(test.custody_group),
+            Ok(test.result),
+            "Columns do not match, for test vector {:?}",
+            test_file
+        );
+    }
+}
@@ -12,5 +12,6 @@ pub mod kzg_proofs;
 pub mod msm;
 pub mod poly;
 pub mod recover;
+pub mod serialization;
 pub mod utils;
 pub mod zero_poly;
@@ -1,10 +1,14 @@
+use kzg::erasure::ErasureCoder;
+use kzg::reed_solomon::decode_coeffs_with_errors;
 use kzg::FFTFr;
 use kzg::FFTSettings;
 use kzg::Fr;
 use kzg::Poly;
 use kzg::PolyRecover;
+use kzg::ZeroPoly;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
+use rand::Rng;
 use std::convert::TryInto;
 
 pub fn recover_simple<
@@ -143,3 +147,120 @@ fn random_missing<TFr: Fr>(data: Vec<TFr>, len_data: usize, known: u64) -> Vec<O
     }
     with_missing
 }
+
+pub fn decode_with_errors<TFr: Fr, TFTTSettings: FFTSettings<TFr> + FFTFr<TFr>>() {
+    let fs = TFTTSettings::new(6).unwrap();
+    let len_samples = 32;
+    let data_len = 8;
+
+    let mut poly = (0..data_len).map(|_| TFr::rand()).collect::<Vec<_>>();
+    poly.resize(len_samples, TFr::zero());
+    // A subdomain of the FFT settings
+    let data = fs.fft_fr(&poly, false).unwrap();
+
+    let mut positions = (0..len_samples).collect::<Vec<_>>();
+    positions.shuffle(&mut thread_rng());
+
+    // 6 missing samples leave 26, of which (26 - 8) / 2 = 9 may be wrong
+    let mut samples = data.iter().cloned().map(Some).collect::<Vec<_>>();
+    for &i in &positions[..6] {
+        samples[i] = None;
+    }
+    let mut wrong = positions[6..15].to_vec();
+    wrong.sort_unstable();
+    for &i in &wrong {
+        samples[i] = Some(TFr::rand());
+    }
+
+    let (coeffs, error_positions) = decode_coeffs_with_errors(&samples, data_len, &fs).unwrap();
+    assert!(coeffs == poly);
+    assert_eq!(error_positions, wrong);
+
+    // Without errors it is plain erasure decoding
+    let mut clean = data.iter().cloned().map(Some).collect::<Vec<_>>();
+    clean[3] = None;
+    let (coeffs, error_positions) = decode_coeffs_with_errors(&clean, data_len, &fs).unwrap();
+    assert!(coeffs == poly);
+    assert!(error_positions.is_empty());
+
+    samples[positions[15]] = Some(TFr::rand());
+    assert!(decode_coeffs_with_errors(&samples, data_len, &fs).is_err());
+
+    assert!(decode_coeffs_with_errors(&clean[..31], data_len, &fs).is_err());
+    assert!(decode_coeffs_with_errors(&clean, 0, &fs).is_err());
+    assert!(decode_coeffs_with_errors(&clean, len_samples + 1, &fs).is_err());
+    assert!(decode_coeffs_with_errors(&vec![None; len_samples], data_len, &fs).is_err());
+}
+
+pub fn erasure_coder<
+    TFr: Fr,
+    TFTTSettings: FFTSettings<TFr> + FFTFr<TFr> + ZeroPoly<TFr, TPoly>,
+    TPoly: Poly<TFr> + PolyRecover<TFr, TPoly, TFTTSettings>,
+>() {
+    let mut rng = thread_rng();
+
+    // Rates above, at and below one half, which take different recovery paths
+    for (data_len, data_shards, total_shards) in [
+        (1000, 4, 8),
+        (1000, 3, 5),
+        (100, 2, 8),
+        (5000, 5, 6),
+        (31, 1, 3),
+        (0, 1, 1),
+    ] {
+        let coder =
+            ErasureCoder::<TFr, TPoly, TFTTSettings>::new(data_len, data_shards, total_shards)
+                .unwrap();
+        let layout = *coder.layout();
+        assert!(layout.field_elements_per_shard * data_shards * 31 >= data_len);
+
+        let data = (0..data_len).map(|_| rng.gen()).collect::<Vec<u8>>();
+        let shards = coder.encode(&data).unwrap();
+        assert_eq!(shards.len(), total_shards);
+        assert!(shards
+            .iter()
+            .all(|shard| shard.len() == layout.bytes_per_shard()));
+
+        let all_indices = (0..total_shards).collect::<Vec<_>>();
+        assert_eq!(coder.decode(&all_indices, &shards).unwrap(), data);
+
+        for _ in 0..3 {
+            let mut indices = all_indices.clone();
+            indices.shuffle(&mut rng);
+            indices.truncate(data_shards);
+            let picked = indices
+                .iter()
+                .map(|&i| shards[i].clone())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                coder.decode(&indices, &picked).unwrap(),
+                data,
+                "decoding {data_len} bytes from shards {indices:?} out of {total_shards}"
+            );
+        }
+
+        let first = &all_indices[..data_shards - 1];
+        assert!(coder.decode(first, &shards[..data_shards - 1]).is_err());
+    }
+
+    let coder = ErasureCoder::<TFr, TPoly, TFTTSettings>::new(1000, 4, 8).unwrap();
+    let data = (0..1000).map(|_| rng.gen()).collect::<Vec<u8>>();
+    let other_data = (0..1000).map(|_| rng.gen()).collect::<Vec<u8>>();
+    let shards = coder.encode(&data).unwrap();
+    let other_shards = coder.encode(&other_data).unwrap();
+
+    let mut mixed = shards[..5].to_vec();
+    mixed[2] = other_shards[2].clone();
+    assert!(coder.decode(&[0, 1, 2, 3, 4], &mixed).is_err());
+
+    assert!(coder.encode(&data[1..]).is_err());
+    assert!(coder.decode(&[0, 1, 1, 3], &shards[..4]).is_err());
+    assert!(coder.decode(&[0, 1, 2, 8], &shards[..4]).is_err());
+    assert!(coder.decode(&[0, 1, 2], &shards[..4]).is_err());
+    let mut short = shards[..4].to_vec();
+    short[0].pop();
+    assert!(coder.decode(&[0, 1, 2, 3], &short).is_err());
+
+    assert!(ErasureCoder::<TFr, TPoly, TFTTSettings>::new(10, 0, 1).is_err());
+    assert!(ErasureCoder::<TFr, TPoly, TFTTSettings>::new(10, 3, 2).is_err());
+}
@@ -0,0 +1,121 @@
+use kzg::{
+    eth::{
+        c_bindings::{Blob, Bytes48, KZGCommitment, KZGProof},
+        BYTES_PER_BLOB, BYTES_PER_COMMITMENT,
+    },
+    Fr, G1Mul, G2Mul, PointValidation, G1, G2,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::utils::{G1_NOT_IN_SUBGROUP, G1_NOT_ON_CURVE};
+
+/// BLS modulus, not a canonical field element
+const FR_NOT_CANONICAL: &str = "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001";
+
+fn json_round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    let json = serde_json::to_string(value).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+fn cbor_round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    let mut cbor = Vec::new();
+    ciborium::into_writer(value, &mut cbor).unwrap();
+    ciborium::from_reader(cbor.as_slice()).unwrap()
+}
+
+fn json_hex(bytes: &[u8]) -> String {
+    format!("\"0x{}\"", hex::encode(bytes))
+}
+
+pub fn fr_serde_works<TFr: Fr + Serialize + DeserializeOwned>() {
+    let fr = TFr::from_u64_arr(&[
+        0x0123456789abcdef,
+        0xfedcba9876543210,
+        0x0011223344556677,
+        0x0102030405060708,
+    ]);
+
+    assert_eq!(
+        serde_json::to_string(&fr).unwrap(),
+        json_hex(&fr.to_bytes())
+    );
+    assert!(json_round_trip(&fr).equals(&fr));
+    assert!(cbor_round_trip(&fr).equals(&fr));
+
+    // hex without the prefix is accepted too
+    let json = format!("\"{}\"", hex::encode(fr.to_bytes()));
+    assert!(serde_json::from_str::<TFr>(&json).unwrap().equals(&fr));
+
+    let json = format!("\"{FR_NOT_CANONICAL}\"");
+    assert!(serde_json::from_str::<TFr>(&json).is_err());
+    assert!(serde_json::from_str::<TFr>("\"0x0102\"").is_err());
+    assert!(serde_json::from_str::<TFr>("\"0xzz\"").is_err());
+}
+
+pub fn g1_serde_works<TFr: Fr, TG1: G1 + G1Mul<TFr> + Serialize + DeserializeOwned>() {
+    let g1 = TG1::generator().mul(&TFr::from_u64(1234567));
+
+    assert_eq!(
+        serde_json::to_string(&g1).unwrap(),
+        json_hex(&g1.to_bytes())
+    );
+    assert!(json_round_trip(&g1).equals(&g1));
+    assert!(cbor_round_trip(&g1).equals(&g1));
+    assert!(json_round_trip(&TG1::identity()).is_inf());
+
+    for invalid in [G1_NOT_IN_SUBGROUP, G1_NOT_ON_CURVE] {
+        let json = format!("\"{invalid}\"");
+        assert!(serde_json::from_str::<TG1>(&json).is_err());
+    }
+}
+
+pub fn g2_serde_works<TFr: Fr, TG2: G2 + G2Mul<TFr> + Serialize + DeserializeOwned>() {
+    let g2 = TG2::generator().mul(&TFr::from_u64(1234567));
+
+    assert_eq!(
+        serde_json::to_string(&g2).unwrap(),
+        json_hex(&g2.to_bytes())
+    );
+    assert!(json_round_trip(&g2).equals(&g2));
+    assert!(cbor_round_trip(&g2).equals(&g2));
+
+    assert!(serde_json::from_str::<TG2>(&json_hex(&g2.to_bytes()[..48])).is_err());
+}
+
+pub fn byte_containers_serde_works<TFr: Fr, TG1: G1 + G1Mul<TFr>>() {
+    let commitment = KZGCommitment {
+        bytes: TG1::generator().mul(&TFr::from_u64(1234567)).to_bytes(),
+    };
+    let json = serde_json::to_string(&commitment).unwrap();
+    assert_eq!(json, json_hex(&commitment.bytes));
+    assert_eq!(json_round_trip(&commitment).bytes, commitment.bytes);
+    assert_eq!(cbor_round_trip(&commitment).bytes, commitment.bytes);
+    assert!(
+        serde_json::from_str::<KZGCommitment>(&json_hex(&[0; BYTES_PER_COMMITMENT - 1])).is_err()
+    );
+
+    let proof = KZGProof {
+        bytes: TG1::identity().to_bytes(),
+    };
+    assert_eq!(json_round_trip(&proof).bytes, proof.bytes);
+
+    // Invalid points are kept as is, and rejected when the backend decodes them
+    for invalid in [G1_NOT_IN_SUBGROUP, G1_NOT_ON_CURVE] {
+        let json = format!("\"{invalid}\"");
+        let commitment = serde_json::from_str::<KZGCommitment>(&json).unwrap();
+        let proof = serde_json::from_str::<KZGProof>(&json).unwrap();
+        assert!(serde_json::from_str::<Bytes48>(&json).is_ok());
+        for bytes in [commitment.bytes, proof.bytes] {
+            assert!(PointValidation::Checked
+                .g1_from_bytes::<TG1>(&bytes)
+                .map_or(true, |g1| !PointValidation::Checked.is_valid_g1(&g1)));
+        }
+    }
+
+    let mut blob = Box::new(Blob {
+        bytes: [0; BYTES_PER_BLOB],
+    });
+    blob.bytes[BYTES_PER_BLOB - 1] = 0xaa;
+    assert_eq!(json_round_trip(blob.as_ref()).bytes, blob.bytes);
+    assert_eq!(cbor_round_trip(blob.as_ref()).bytes, blob.bytes);
+}
@@ -3,6 +3,17 @@ use std::{env::current_dir, path::PathBuf};
 use kzg::eip_4844::TRUSTED_SETUP_PATH;
 use pathdiff::diff_paths;
 
+/// On the curve, but not in the G1 subgroup (from the consensus specs)
+pub const G1_NOT_IN_SUBGROUP: &str = "0x8123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+/// Not on the curve (from the consensus specs)
+pub const G1_NOT_ON_CURVE: &str = "0x8123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcde0";
+
+pub fn hex_to_bytes<const N: usize>(hex: &str) -> [u8; N] {
+    let mut bytes = [0; N];
+    hex::decode_to_slice(hex.strip_prefix("0x").unwrap_or(hex), &mut bytes).unwrap();
+    bytes
+}
+
 pub fn get_manifest_dir() -> String {
     let current = current_dir().unwrap();
     let manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
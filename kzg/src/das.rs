@@ -1,7 +1,8 @@
 use alloc::format;
-use core::mem::size_of;
 use core::{fmt::Debug, hash::Hash};
 use hashbrown::{HashMap, HashSet};
+#[cfg(feature = "rand")]
+use rand_core::{CryptoRng, RngCore};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -12,20 +13,27 @@ use alloc::{
     vec::Vec,
 };
 
+#[cfg(feature = "rand")]
+use crate::eip_4844::random_batch_weights;
+use crate::eip_4844::RANDOM_WEIGHT_BITS;
 use crate::G1ProjAddAffine;
 use crate::{
     cfg_iter, cfg_iter_mut,
     common_utils::{reverse_bit_order, reverse_bits_limited},
-    eip_4844::{
-        blob_to_polynomial, compute_powers, hash, hash_to_bls_field, BYTES_PER_COMMITMENT,
-        BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
-    },
-    eth, FFTFr, FFTSettings, Fr, G1Affine, G1Fp, G1LinComb, KZGSettings, PairingVerify, Poly,
-    FFTG1, G1, G2,
+    eip_4844::compute_powers,
+    eth,
+    transcript::{Sha256Transcript, Transcript},
+    FFTFr, FFTSettings, Fr, G1Affine, G1Fp, G1LinComb, GTPow, KZGSettings, Pairing, PairingVerify,
+    PointValidation, Poly, FFTG1, G1, G2, GT,
 };
 
 pub const RANDOM_CHALLENGE_KZG_CELL_BATCH_DOMAIN: [u8; 16] = *b"RCKZGCBATCH__V1_";
 
+/// Number of distinct cells up to which [`DAS::compute_cell_kzg_proofs`] computes every proof
+/// with its own MSM instead of running FK20 for all cells. One MSM over the blob-sized setup costs
+/// about a fifth of the FK20 computation of all proofs.
+pub const CELL_PROOFS_FK20_THRESHOLD: usize = 4;
+
 pub trait EcBackend {
     type Fr: Fr + Debug + Send;
     type G1Fp: G1Fp;
@@ -34,9 +42,11 @@ pub trait EcBackend {
     type G1: G1
         + G1LinComb<Self::Fr, Self::G1Fp, Self::G1Affine, Self::G1ProjAddAffine>
         + PairingVerify<Self::G1, Self::G2>
+        + Pairing<Self::G1, Self::G2, Self::GT>
         + Eq
         + Hash;
     type G2: G2;
+    type GT: GT + GTPow<Self::Fr>;
     type Poly: Poly<Self::Fr>;
     type FFTSettings: FFTSettings<Self::Fr> + FFTFr<Self::Fr> + FFTG1<Self::G1>;
     type KZGSettings: KZGSettings<
@@ -95,6 +105,59 @@ const CELL_INDICES_RBL: [usize; 128] = [
     0x07, 0x47, 0x27, 0x67, 0x17, 0x57, 0x37, 0x77, 0x0f, 0x4f, 0x2f, 0x6f, 0x1f, 0x5f, 0x3f, 0x7f,
 ];
 
+/// The cell index in reverse-bit order, which points to the cell's coset factor h_k in the
+/// roots of unity of the extended blob. Uses [`CELL_INDICES_RBL`] for the Ethereum cell count.
+fn cell_index_rbl(cells_per_ext_blob: usize, cell_index: usize) -> usize {
+    if cells_per_ext_blob == eth::CELLS_PER_EXT_BLOB {
+        CELL_INDICES_RBL[cell_index]
+    } else {
+        reverse_bits_limited(cells_per_ext_blob, cell_index)
+    }
+}
+
+/// Creates settings for a blob of `g1_monomial.len()` field elements, extended
+/// `extension_factor` times and split into cells of `cell_size` field elements.
+///
+/// The setup is expected in the form loaded from a trusted setup file, with the Lagrange points
+/// in bit-reversed order. All sizes must be powers of two; the extension factor must be at least
+/// 2 and a blob must hold at least two cells.
+pub fn new_das_settings<B: EcBackend>(
+    g1_monomial: &[B::G1],
+    g1_lagrange_brp: &[B::G1],
+    g2_monomial: &[B::G2],
+    extension_factor: usize,
+    cell_size: usize,
+) -> Result<B::KZGSettings, String> {
+    let field_elements_per_blob = g1_monomial.len();
+
+    if !field_elements_per_blob.is_power_of_two() {
+        return Err("Trusted setup size must be a power of two".to_string());
+    }
+    if extension_factor < 2 || !extension_factor.is_power_of_two() {
+        return Err(format!(
+            "Invalid extension factor {extension_factor}: must be a power of two, at least 2"
+        ));
+    }
+    if !cell_size.is_power_of_two() || 2 * cell_size > field_elements_per_blob {
+        return Err(format!(
+            "Invalid cell size {cell_size}: must be a power of two, at most half of the blob"
+        ));
+    }
+    if g2_monomial.len() <= cell_size {
+        return Err("Not enough G2 points for the cell size".to_string());
+    }
+
+    let field_elements_per_ext_blob = field_elements_per_blob * extension_factor;
+    let fs = B::FFTSettings::new(field_elements_per_ext_blob.trailing_zeros() as usize)?;
+
+    B::KZGSettings::new(g1_monomial, g1_lagrange_brp, g2_monomial, &fs, cell_size)
+}
+
+/// Number of field elements in the extended blob, which spans the FFT domain of the settings.
+fn field_elements_per_ext_blob<B: EcBackend>(settings: &B::KZGSettings) -> usize {
+    settings.get_g1_monomial().len() * settings.get_extension_factor()
+}
+
 pub trait DAS<B: EcBackend> {
     fn kzg_settings(&self) -> &B::KZGSettings;
 
@@ -105,107 +168,18 @@ pub trait DAS<B: EcBackend> {
         cell_indices: &[usize],
         cells: &[B::Fr],
     ) -> Result<(), String> {
-        let kzg_settings = self.kzg_settings();
-        let ts_len = kzg_settings.get_g1_monomial().len();
-        let cell_size = kzg_settings.get_cell_size();
-
-        if recovered_cells.len() != 2 * ts_len
-            || recovered_proofs
-                .as_ref()
-                .is_some_and(|it| it.len() != (2 * ts_len) / cell_size)
-        {
-            return Err("Invalid output array length".to_string());
-        }
-
-        if cells.len() / cell_size != cell_indices.len() {
-            return Err(
-                "Cell indicies mismatch - cells length must be equal to cell indicies length"
-                    .to_string(),
-            );
-        }
-
-        if cells.len() > 2 * ts_len {
-            return Err("Cell length cannot be larger than CELLS_PER_EXT_BLOB".to_string());
-        }
-
-        if cells.len() < ts_len {
-            return Err(
-                "Impossible to recover - cells length cannot be less than CELLS_PER_EXT_BLOB / 2"
-                    .to_string(),
-            );
-        }
-
-        for fr in recovered_cells.iter_mut() {
-            *fr = B::Fr::null();
-        }
-
-        // Trick to use HashSet, to check for duplicate commitments, is taken from rust-eth-kzg:
-        // https://github.com/crate-crypto/rust-eth-kzg/blob/63d469ce1c98a9898a0d8cd717aa3ebe46ace227/eip7594/src/recovery.rs#L64-L76
-        let mut provided_indices = HashSet::new();
-        for ((i, &cell_index), next_cell_index) in cell_indices
-            .iter()
-            .enumerate()
-            .zip(cell_indices.iter().map(Some).skip(1).chain(Some(None)))
-        {
-            if cell_index >= (2 * ts_len) / cell_size {
-                return Err(format!("Invalid cell index {cell_index}, position {i}: Cell index cannot be larger than CELLS_PER_EXT_BLOB"));
-            }
-
-            if let Some(&idx) = next_cell_index {
-                if idx <= cell_index {
-                    return Err(
-                        format!("Invalid cell indices: Indices must be in strictly ascending order, but indices at positions {i} and {next} are not ({cell_index} >= {idx})", next = i + 1)
-                    );
-                }
-            }
-
-            if !provided_indices.insert(cell_index) {
-                return Err(format!(
-                    "Invalid cell indices: cell index {cell_index} appears twice."
-                ));
-            }
-
-            recovered_cells[cell_index * cell_size..(cell_index + 1) * cell_size]
-                .clone_from_slice(&cells[i * cell_size..(i + 1) * cell_size]);
-        }
-
-        let fft_settings = kzg_settings.get_fft_settings();
-
-        if cells.len() != 2 * ts_len {
-            recover_cells::<B>(
-                cell_size,
-                recovered_cells,
-                &provided_indices,
-                fft_settings,
-                2 * ts_len,
-            )
-            .map_err(|err| format!("Cell recovery failed with error: {err}"))?;
-        }
-
-        #[allow(clippy::redundant_slicing)]
-        let recovered_cells = &recovered_cells[..];
-
-        if let Some(recovered_proofs) = recovered_proofs {
-            let mut poly = vec![B::Fr::default(); ts_len * 2];
-            poly.clone_from_slice(recovered_cells);
-            poly_lagrange_to_monomial::<B>(&mut poly, fft_settings)?;
-
-            let res = compute_fk20_proofs::<B>(
-                cell_size,
-                &poly,
-                ts_len,
-                fft_settings,
-                self.kzg_settings(),
-            )
-            .map_err(|err| format!("Proof computation failed with error: {err}"))?;
-            recovered_proofs.clone_from_slice(&res);
-
-            reverse_bit_order(recovered_proofs)?;
-        }
-
-        Ok(())
+        recover_cells_and_kzg_proofs_with::<B>(
+            self.kzg_settings(),
+            recovered_cells,
+            recovered_proofs,
+            cell_indices,
+            cells,
+            None,
+        )
     }
 
+    /// Recovers every blob in the batch. The vanishing polynomial of the missing cells is computed
+    /// once for each distinct set of cell indices and shared by all blobs that lost the same cells.
     #[allow(clippy::type_complexity)]
     fn recover_cells_and_kzg_proofs_batch(
         &self,
@@ -222,18 +196,59 @@ pub trait DAS<B: EcBackend> {
         let kzg_settings = self.kzg_settings();
         let ts_len = kzg_settings.get_g1_monomial().len();
         let cell_size = kzg_settings.get_cell_size();
+        let ext_len = field_elements_per_ext_blob::<B>(kzg_settings);
+        let cells_per_ext_blob = ext_len / cell_size;
+
+        let mut groups = HashMap::new();
+        let mut distinct_indices = Vec::new();
+        let blob_groups = cell_indices
+            .iter()
+            .map(|indices| {
+                *groups.entry(indices.as_slice()).or_insert_with(|| {
+                    distinct_indices.push(indices.as_slice());
+                    distinct_indices.len() - 1
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Invalid index sets are left to the per-blob validation, which reports the error
+        let vanishing_polys = cfg_iter!(distinct_indices)
+            .map(|indices| {
+                if indices.len() >= cells_per_ext_blob {
+                    return None;
+                }
+
+                let indices = indices
+                    .iter()
+                    .copied()
+                    .filter(|&i| i < cells_per_ext_blob)
+                    .collect::<HashSet<_>>();
+
+                RecoveryVanishingPoly::<B>::new(
+                    cell_size,
+                    &indices,
+                    self.kzg_settings().get_fft_settings(),
+                    ts_len,
+                    ext_len,
+                )
+                .ok()
+            })
+            .collect::<Vec<_>>();
 
         cfg_iter!(cells)
             .zip(cfg_iter!(cell_indices))
-            .map(|(cells, cell_indices)| {
-                let mut recovered_cells = vec![B::Fr::null(); 2 * ts_len];
-                let mut recovered_proofs = vec![B::G1::default(); (2 * ts_len) / cell_size];
+            .zip(cfg_iter!(blob_groups))
+            .map(|((cells, cell_indices), &group)| {
+                let mut recovered_cells = vec![B::Fr::null(); ext_len];
+                let mut recovered_proofs = vec![B::G1::default(); cells_per_ext_blob];
 
-                self.recover_cells_and_kzg_proofs(
+                recover_cells_and_kzg_proofs_with::<B>(
+                    self.kzg_settings(),
                     &mut recovered_cells,
                     Some(&mut recovered_proofs),
                     cell_indices,
                     cells,
+                    vanishing_polys[group].as_ref(),
                 )?;
 
                 Ok((recovered_cells, recovered_proofs))
@@ -255,10 +270,12 @@ pub trait DAS<B: EcBackend> {
         let ts_size = settings.get_g1_monomial().len();
         let cell_size = settings.get_cell_size();
 
-        let poly = blob_to_polynomial::<B::Fr, B::Poly>(blob)?;
+        if blob.len() != ts_size {
+            return Err("Invalid blob length".to_string());
+        }
 
-        let mut poly_monomial = vec![B::Fr::zero(); 2 * ts_size];
-        poly_monomial[0..ts_size].clone_from_slice(poly.get_coeffs());
+        let mut poly_monomial = vec![B::Fr::zero(); field_elements_per_ext_blob::<B>(settings)];
+        poly_monomial[0..ts_size].clone_from_slice(blob);
 
         let fft_settings = self.kzg_settings().get_fft_settings();
         poly_lagrange_to_monomial::<B>(&mut poly_monomial[..ts_size], fft_settings)?;
@@ -291,103 +308,170 @@ pub trait DAS<B: EcBackend> {
         Ok(())
     }
 
-    fn verify_cell_kzg_proof_batch(
+    /// Computes the proofs of the cells at `cell_indices`, equal to the matching proofs of
+    /// [`Self::compute_cells_and_kzg_proofs`].
+    ///
+    /// Up to [`CELL_PROOFS_FK20_THRESHOLD`] distinct cells, each proof is a separate MSM of the
+    /// quotient by the vanishing polynomial of the cell's coset. For more cells, the proofs of all
+    /// cells are computed with FK20 and the requested ones are picked.
+    fn compute_cell_kzg_proofs(
         &self,
-        commitments: &[B::G1],
+        blob: &[B::Fr],
         cell_indices: &[usize],
-        cells: &[B::Fr],
-        proofs: &[B::G1],
-    ) -> Result<bool, String> {
+    ) -> Result<Vec<B::G1>, String> {
         let settings = self.kzg_settings();
-        let cell_size = settings.get_cell_size();
-        let cell_count = cells.len() / cell_size;
         let ts_size = settings.get_g1_monomial().len();
+        let cell_size = settings.get_cell_size();
+        let cells_per_ext_blob = field_elements_per_ext_blob::<B>(settings) / cell_size;
 
-        if cells.len() != cell_indices.len() * cell_size {
-            return Err("Cell count mismatch".to_string());
-        }
-
-        if commitments.len() != cell_count {
-            return Err("Commitment count mismatch".to_string());
-        }
-
-        if proofs.len() != cell_count {
-            return Err("Proof count mismatch".to_string());
+        if blob.len() != ts_size {
+            return Err("Invalid blob length".to_string());
         }
-
-        if cells.is_empty() {
-            return Ok(true);
+        if let Some(index) = cell_indices.iter().find(|&&i| i >= cells_per_ext_blob) {
+            return Err(format!("Invalid cell index {index}"));
         }
 
-        if cfg_iter!(cell_indices).any(|&cell_index| cell_index >= (2 * ts_size) / cell_size) {
-            return Err("Invalid cell index".to_string());
-        }
+        let (unique_indices, indices) = deduplicate_with_indices(cell_indices);
 
-        if cfg_iter!(proofs).any(|proof| !proof.is_valid()) {
-            return Err("Proof is not valid".to_string());
-        }
+        let fft_settings = settings.get_fft_settings();
+        let mut poly_monomial = blob.to_vec();
+        poly_lagrange_to_monomial::<B>(&mut poly_monomial, fft_settings)?;
 
-        let (unique_commitments, commitment_indices) = deduplicate_with_indices(commitments);
+        let unique_proofs = if unique_indices.len() > CELL_PROOFS_FK20_THRESHOLD {
+            let mut proofs = compute_fk20_proofs::<B>(
+                cell_size,
+                &poly_monomial,
+                ts_size,
+                fft_settings,
+                settings,
+            )
+            .map_err(|err| format!("Proof computation failed with error: {err:?}"))?;
+            reverse_bit_order(&mut proofs)?;
 
-        if cfg_iter!(unique_commitments).any(|commitment| !commitment.is_valid()) {
-            return Err("Commitment is not valid".to_string());
-        }
+            unique_indices
+                .iter()
+                .map(|&index| proofs[index].clone())
+                .collect::<Vec<_>>()
+        } else {
+            unique_indices
+                .iter()
+                .map(|&index| compute_cell_proof::<B>(cell_size, index, &poly_monomial, settings))
+                .collect::<Result<Vec<_>, _>>()?
+        };
 
-        let fft_settings = settings.get_fft_settings();
+        Ok(indices
+            .iter()
+            .map(|&index| unique_proofs[index].clone())
+            .collect())
+    }
 
-        let r = Self::compute_verify_cell_kzg_proof_batch_challenge(
-            cell_size,
-            &unique_commitments,
-            &commitment_indices,
+    fn verify_cell_kzg_proof_batch(
+        &self,
+        commitments: &[B::G1],
+        cell_indices: &[usize],
+        cells: &[B::Fr],
+        proofs: &[B::G1],
+    ) -> Result<bool, String> {
+        self.verify_cell_kzg_proof_batch_with_validation(
+            commitments,
             cell_indices,
             cells,
             proofs,
-            ts_size,
-        )?;
+            PointValidation::Checked,
+        )
+    }
 
-        let r_powers = compute_powers(&r, cell_count);
+    /// Like [`DAS::verify_cell_kzg_proof_batch`], with the checks of the points picked by
+    /// `validation`.
+    fn verify_cell_kzg_proof_batch_with_validation(
+        &self,
+        commitments: &[B::G1],
+        cell_indices: &[usize],
+        cells: &[B::Fr],
+        proofs: &[B::G1],
+        validation: PointValidation,
+    ) -> Result<bool, String> {
+        verify_cell_kzg_proof_batch_impl::<B, Sha256Transcript, Self>(
+            self,
+            commitments,
+            cell_indices,
+            cells,
+            proofs,
+            validation,
+            None,
+        )
+    }
 
-        let proof_lincomb = B::G1::g1_lincomb(proofs, &r_powers, cell_count, None);
+    /// Like [`DAS::verify_cell_kzg_proof_batch`], with the challenge derived by the transcript `T`.
+    fn verify_cell_kzg_proof_batch_with_transcript<T: Transcript>(
+        &self,
+        commitments: &[B::G1],
+        cell_indices: &[usize],
+        cells: &[B::Fr],
+        proofs: &[B::G1],
+    ) -> Result<bool, String> {
+        verify_cell_kzg_proof_batch_impl::<B, T, Self>(
+            self,
+            commitments,
+            cell_indices,
+            cells,
+            proofs,
+            PointValidation::Checked,
+            None,
+        )
+    }
 
-        let final_g1_sum = compute_weighted_sum_of_commitments::<B>(
-            &unique_commitments,
-            &commitment_indices,
-            &r_powers,
-        );
+    /// Like [`DAS::verify_cell_kzg_proof_batch`], but the cells are combined with random 128-bit
+    /// weights drawn from `rng` instead of the powers of a Fiat-Shamir challenge, which skips
+    /// hashing every cell of the batch.
+    ///
+    /// The result depends on `rng`, so only use it for local verification, with a
+    /// cryptographically secure generator.
+    #[cfg(feature = "rand")]
+    fn verify_cell_kzg_proof_batch_randomized(
+        &self,
+        commitments: &[B::G1],
+        cell_indices: &[usize],
+        cells: &[B::Fr],
+        proofs: &[B::G1],
+        rng: &mut (impl RngCore + CryptoRng),
+    ) -> Result<bool, String> {
+        let weights = random_batch_weights(rng, cell_indices.len());
 
-        let interpolation_poly_commit = compute_commitment_to_aggregated_interpolation_poly::<B>(
-            cell_size,
-            &r_powers,
+        verify_cell_kzg_proof_batch_impl::<B, Sha256Transcript, Self>(
+            self,
+            commitments,
             cell_indices,
             cells,
-            fft_settings,
-            settings.get_g1_monomial(),
-        )?;
-
-        let final_g1_sum = final_g1_sum.sub(&interpolation_poly_commit);
+            proofs,
+            PointValidation::Checked,
+            Some(&weights),
+        )
+    }
 
-        let weighted_sum_of_proofs = computed_weighted_sum_of_proofs::<B>(
+    fn compute_verify_cell_kzg_proof_batch_challenge(
+        cell_size: usize,
+        commitments: &[B::G1],
+        commitment_indices: &[usize],
+        cell_indices: &[usize],
+        cells: &[B::Fr],
+        proofs: &[B::G1],
+        blob_size: usize,
+    ) -> Result<B::Fr, String> {
+        Self::compute_verify_cell_kzg_proof_batch_challenge_with_transcript::<Sha256Transcript>(
             cell_size,
-            proofs,
-            &r_powers,
+            commitments,
+            commitment_indices,
             cell_indices,
-            fft_settings,
-            ts_size * 2,
-        )?;
-
-        let final_g1_sum = final_g1_sum.add(&weighted_sum_of_proofs);
-
-        let power_of_s = &settings.get_g2_monomial()[cell_size];
-
-        Ok(B::G1::verify(
-            &final_g1_sum,
-            &B::G2::generator(),
-            &proof_lincomb,
-            power_of_s,
-        ))
+            cells,
+            proofs,
+            blob_size,
+        )
     }
 
-    fn compute_verify_cell_kzg_proof_batch_challenge(
+    /// Like [`DAS::compute_verify_cell_kzg_proof_batch_challenge`], with the challenge derived by
+    /// the transcript `T`.
+    fn compute_verify_cell_kzg_proof_batch_challenge_with_transcript<T: Transcript>(
         cell_size: usize,
         commitments: &[B::G1],
         commitment_indices: &[usize],
@@ -405,51 +489,144 @@ pub trait DAS<B: EcBackend> {
             return Err("Cell count mismatch".to_string());
         }
 
-        let input_size = RANDOM_CHALLENGE_KZG_CELL_BATCH_DOMAIN.len() /* the domain separator */
-            + size_of::<u64>()                                               /* field elements per blob */
-            + size_of::<u64>()                                               /* cell_size */
-            + size_of::<u64>()                                               /* commitment count */
-            + size_of::<u64>()                                               /* cell count */
-            + (commitments.len() * BYTES_PER_COMMITMENT)                     /* commitment bytes */
-            + (cell_count * size_of::<u64>())                                /* commitment indices */
-            + (cell_count * size_of::<u64>())                                /* cell indices */
-            + (cell_count * cell_size * BYTES_PER_FIELD_ELEMENT)             /* cells */
-            + (cell_count * BYTES_PER_PROOF)                                 /* proofs bytes */
-            ;
-
-        let mut bytes = Vec::with_capacity(input_size);
-        bytes.extend_from_slice(&RANDOM_CHALLENGE_KZG_CELL_BATCH_DOMAIN);
-        bytes.extend_from_slice(&(blob_size as u64).to_be_bytes());
-        bytes.extend_from_slice(&(cell_size as u64).to_be_bytes());
-        bytes.extend_from_slice(&(commitments.len() as u64).to_be_bytes());
-        bytes.extend_from_slice(&(cell_count as u64).to_be_bytes());
+        let mut transcript = T::new(&RANDOM_CHALLENGE_KZG_CELL_BATCH_DOMAIN);
+        transcript.absorb_u64(blob_size as u64);
+        transcript.absorb_u64(cell_size as u64);
+        transcript.absorb_u64(commitments.len() as u64);
+        transcript.absorb_u64(cell_count as u64);
 
         for commitment in commitments {
-            bytes.extend_from_slice(&commitment.to_bytes());
+            transcript.absorb_g1(commitment);
         }
 
         for i in 0..cell_count {
-            bytes.extend_from_slice(&(commitment_indices[i] as u64).to_be_bytes());
-            bytes.extend_from_slice(&(cell_indices[i] as u64).to_be_bytes());
+            transcript.absorb_u64(commitment_indices[i] as u64);
+            transcript.absorb_u64(cell_indices[i] as u64);
 
             for fr in &cells[(i * cell_size)..((i + 1) * cell_size)] {
-                bytes.extend_from_slice(&fr.to_bytes());
+                transcript.absorb_fr(fr);
             }
 
-            bytes.extend_from_slice(&(proofs[i].to_bytes()));
+            transcript.absorb_g1(&proofs[i]);
         }
 
-        let bytes = &bytes[..];
+        let r = transcript.squeeze_fr();
 
-        if bytes.len() != input_size {
-            return Err("Failed to create challenge - invalid length".to_string());
-        }
+        Ok(r)
+    }
+}
 
-        let eval_challenge = hash(bytes);
-        let r = hash_to_bls_field(&eval_challenge);
+fn verify_cell_kzg_proof_batch_impl<B: EcBackend, T: Transcript, D: DAS<B> + ?Sized>(
+    das: &D,
+    commitments: &[B::G1],
+    cell_indices: &[usize],
+    cells: &[B::Fr],
+    proofs: &[B::G1],
+    validation: PointValidation,
+    weights: Option<&[B::Fr]>,
+) -> Result<bool, String> {
+    let settings = das.kzg_settings();
+    let cell_size = settings.get_cell_size();
+    let cell_count = cells.len() / cell_size;
+    let ts_size = settings.get_g1_monomial().len();
+    let ext_len = field_elements_per_ext_blob::<B>(settings);
+
+    if cells.len() != cell_indices.len() * cell_size {
+        return Err("Cell count mismatch".to_string());
+    }
 
-        Ok(r)
+    if commitments.len() != cell_count {
+        return Err("Commitment count mismatch".to_string());
+    }
+
+    if proofs.len() != cell_count {
+        return Err("Proof count mismatch".to_string());
+    }
+
+    if cells.is_empty() {
+        return Ok(true);
+    }
+
+    if cfg_iter!(cell_indices).any(|&cell_index| cell_index >= ext_len / cell_size) {
+        return Err("Invalid cell index".to_string());
+    }
+
+    if validation == PointValidation::Checked && cfg_iter!(proofs).any(|proof| !proof.is_valid()) {
+        return Err("Proof is not valid".to_string());
+    }
+
+    let (unique_commitments, commitment_indices) = deduplicate_with_indices(commitments);
+
+    if validation == PointValidation::Checked
+        && cfg_iter!(unique_commitments).any(|commitment| !commitment.is_valid())
+    {
+        return Err("Commitment is not valid".to_string());
     }
+
+    let fft_settings = settings.get_fft_settings();
+
+    // The weights of the random linear combination, unless the caller drew them already as
+    // `RANDOM_WEIGHT_BITS`-bit weights
+    let r_powers = match weights {
+        Some(weights) => weights.to_vec(),
+        None => {
+            let r = D::compute_verify_cell_kzg_proof_batch_challenge_with_transcript::<T>(
+                cell_size,
+                &unique_commitments,
+                &commitment_indices,
+                cell_indices,
+                cells,
+                proofs,
+                ts_size,
+            )?;
+
+            compute_powers(&r, cell_count)
+        }
+    };
+
+    // Random weights are short, so the MSM over them can skip the windows of the high bits
+    let proof_lincomb = match weights {
+        Some(_) => B::G1::g1_lincomb_short(proofs, &r_powers, cell_count, RANDOM_WEIGHT_BITS),
+        None => B::G1::g1_lincomb(proofs, &r_powers, cell_count, None),
+    };
+
+    let final_g1_sum = compute_weighted_sum_of_commitments::<B>(
+        &unique_commitments,
+        &commitment_indices,
+        &r_powers,
+    );
+
+    let interpolation_poly_commit = compute_commitment_to_aggregated_interpolation_poly::<B>(
+        cell_size,
+        &r_powers,
+        cell_indices,
+        cells,
+        fft_settings,
+        settings.get_g1_monomial(),
+        ext_len,
+    )?;
+
+    let final_g1_sum = final_g1_sum.sub(&interpolation_poly_commit);
+
+    let weighted_sum_of_proofs = computed_weighted_sum_of_proofs::<B>(
+        cell_size,
+        proofs,
+        &r_powers,
+        cell_indices,
+        fft_settings,
+        ext_len,
+    )?;
+
+    let final_g1_sum = final_g1_sum.add(&weighted_sum_of_proofs);
+
+    let power_of_s = &settings.get_g2_monomial()[cell_size];
+
+    Ok(B::G1::verify(
+        &final_g1_sum,
+        &B::G2::generator(),
+        &proof_lincomb,
+        power_of_s,
+    ))
 }
 
 fn shift_poly<B: EcBackend>(poly: &mut [B::Fr], shift_factor: &B::Fr) {
@@ -546,63 +723,197 @@ fn vanishing_polynomial_for_missing_cells<B: EcBackend>(
     Ok(vanishing_poly)
 }
 
-fn recover_cells<B: EcBackend>(
-    cell_size: usize,
-    output: &mut [B::Fr],
-    cell_indicies: &HashSet<usize>,
-    fft_settings: &B::FFTSettings,
-    field_elements_per_ext_blob: usize,
+fn recover_cells_and_kzg_proofs_with<B: EcBackend>(
+    kzg_settings: &B::KZGSettings,
+    recovered_cells: &mut [B::Fr],
+    recovered_proofs: Option<&mut [B::G1]>,
+    cell_indices: &[usize],
+    cells: &[B::Fr],
+    vanishing_poly: Option<&RecoveryVanishingPoly<B>>,
 ) -> Result<(), String> {
-    let cells_per_ext_blob = field_elements_per_ext_blob / cell_size;
-    let mut missing_cell_indicies = Vec::new();
+    let ts_len = kzg_settings.get_g1_monomial().len();
+    let cell_size = kzg_settings.get_cell_size();
+    let ext_len = field_elements_per_ext_blob::<B>(kzg_settings);
+
+    if recovered_cells.len() != ext_len
+        || recovered_proofs
+            .as_ref()
+            .is_some_and(|it| it.len() != ext_len / cell_size)
+    {
+        return Err("Invalid output array length".to_string());
+    }
 
-    let mut cells_brp = output.to_vec();
-    reverse_bit_order(&mut cells_brp)?;
+    if cells.len() / cell_size != cell_indices.len() {
+        return Err(
+            "Cell indicies mismatch - cells length must be equal to cell indicies length"
+                .to_string(),
+        );
+    }
+
+    if cells.len() > ext_len {
+        return Err("Cell length cannot be larger than CELLS_PER_EXT_BLOB".to_string());
+    }
+
+    if cells.len() < ts_len {
+        return Err(
+            "Impossible to recover - cells length cannot be less than the blob length".to_string(),
+        );
+    }
+
+    for fr in recovered_cells.iter_mut() {
+        *fr = B::Fr::null();
+    }
+
+    // Trick to use HashSet, to check for duplicate commitments, is taken from rust-eth-kzg:
+    // https://github.com/crate-crypto/rust-eth-kzg/blob/63d469ce1c98a9898a0d8cd717aa3ebe46ace227/eip7594/src/recovery.rs#L64-L76
+    let mut provided_indices = HashSet::new();
+    for ((i, &cell_index), next_cell_index) in cell_indices
+        .iter()
+        .enumerate()
+        .zip(cell_indices.iter().map(Some).skip(1).chain(Some(None)))
+    {
+        if cell_index >= ext_len / cell_size {
+            return Err(format!("Invalid cell index {cell_index}, position {i}: Cell index cannot be larger than CELLS_PER_EXT_BLOB"));
+        }
+
+        if let Some(&idx) = next_cell_index {
+            if idx <= cell_index {
+                return Err(
+                    format!("Invalid cell indices: Indices must be in strictly ascending order, but indices at positions {i} and {next} are not ({cell_index} >= {idx})", next = i + 1)
+                );
+            }
+        }
 
-    for i in 0..cells_per_ext_blob {
-        if !cell_indicies.contains(&i) {
-            missing_cell_indicies.push(reverse_bits_limited(cells_per_ext_blob, i));
+        if !provided_indices.insert(cell_index) {
+            return Err(format!(
+                "Invalid cell indices: cell index {cell_index} appears twice."
+            ));
         }
+
+        recovered_cells[cell_index * cell_size..(cell_index + 1) * cell_size]
+            .clone_from_slice(&cells[i * cell_size..(i + 1) * cell_size]);
     }
 
-    let missing_cell_indicies = &missing_cell_indicies[..];
+    let fft_settings = kzg_settings.get_fft_settings();
+
+    if cells.len() != ext_len {
+        let computed;
+        let vanishing_poly = match vanishing_poly {
+            Some(vanishing_poly) => vanishing_poly,
+            None => {
+                computed = RecoveryVanishingPoly::<B>::new(
+                    cell_size,
+                    &provided_indices,
+                    fft_settings,
+                    ts_len,
+                    ext_len,
+                )
+                .map_err(|err| format!("Cell recovery failed with error: {err}"))?;
+                &computed
+            }
+        };
 
-    if missing_cell_indicies.len() > cells_per_ext_blob / 2 {
-        return Err("Not enough cells".to_string());
+        recover_cells::<B>(recovered_cells, vanishing_poly, fft_settings)
+            .map_err(|err| format!("Cell recovery failed with error: {err}"))?;
     }
 
-    let vanishing_poly_coeff = vanishing_polynomial_for_missing_cells::<B>(
-        cell_size,
-        missing_cell_indicies,
-        fft_settings,
-        field_elements_per_ext_blob,
-    )?;
+    #[allow(clippy::redundant_slicing)]
+    let recovered_cells = &recovered_cells[..];
 
-    let vanishing_poly_eval = fft_settings
-        .fft_fr(&vanishing_poly_coeff, false)
-        .map_err(|err| format!("Vanishing polynomial evaluation failed: {err}"))?;
+    if let Some(recovered_proofs) = recovered_proofs {
+        let mut poly = vec![B::Fr::default(); ext_len];
+        poly.clone_from_slice(recovered_cells);
+        poly_lagrange_to_monomial::<B>(&mut poly, fft_settings)?;
 
-    let mut extended_evaluation_times_zero = Vec::with_capacity(field_elements_per_ext_blob);
+        let res = compute_fk20_proofs::<B>(cell_size, &poly, ts_len, fft_settings, kzg_settings)
+            .map_err(|err| format!("Proof computation failed with error: {err}"))?;
+        recovered_proofs.clone_from_slice(&res);
 
-    for i in 0..field_elements_per_ext_blob {
-        if cells_brp[i].is_null() {
-            extended_evaluation_times_zero.push(B::Fr::zero());
-        } else {
-            extended_evaluation_times_zero.push(cells_brp[i].mul(&vanishing_poly_eval[i]));
+        reverse_bit_order(recovered_proofs)?;
+    }
+
+    Ok(())
+}
+
+/// Vanishing polynomial of the missing cells, evaluated over the extended domain and, inverted,
+/// over its coset. It only depends on which cells are missing, so blobs that lost the same cells
+/// share it.
+struct RecoveryVanishingPoly<B: EcBackend> {
+    eval: Vec<B::Fr>,
+    coset_eval_inv: Vec<B::Fr>,
+}
+
+impl<B: EcBackend> RecoveryVanishingPoly<B> {
+    fn new(
+        cell_size: usize,
+        cell_indicies: &HashSet<usize>,
+        fft_settings: &B::FFTSettings,
+        field_elements_per_blob: usize,
+        field_elements_per_ext_blob: usize,
+    ) -> Result<Self, String> {
+        let cells_per_ext_blob = field_elements_per_ext_blob / cell_size;
+        let cells_per_blob = field_elements_per_blob / cell_size;
+
+        let missing_cell_indicies = (0..cells_per_ext_blob)
+            .filter(|i| !cell_indicies.contains(i))
+            .map(|i| reverse_bits_limited(cells_per_ext_blob, i))
+            .collect::<Vec<_>>();
+
+        if missing_cell_indicies.len() > cells_per_ext_blob - cells_per_blob {
+            return Err("Not enough cells".to_string());
         }
+
+        let vanishing_poly_coeff = vanishing_polynomial_for_missing_cells::<B>(
+            cell_size,
+            &missing_cell_indicies,
+            fft_settings,
+            field_elements_per_ext_blob,
+        )?;
+
+        let eval = fft_settings
+            .fft_fr(&vanishing_poly_coeff, false)
+            .map_err(|err| format!("Vanishing polynomial evaluation failed: {err}"))?;
+
+        let mut coset_eval_inv = coset_fft::<B>(vanishing_poly_coeff, fft_settings)?;
+        batch_inverse::<B>(&mut coset_eval_inv);
+
+        Ok(Self {
+            eval,
+            coset_eval_inv,
+        })
     }
+}
+
+fn recover_cells<B: EcBackend>(
+    output: &mut [B::Fr],
+    vanishing_poly: &RecoveryVanishingPoly<B>,
+    fft_settings: &B::FFTSettings,
+) -> Result<(), String> {
+    let mut cells_brp = output.to_vec();
+    reverse_bit_order(&mut cells_brp)?;
+
+    let extended_evaluation_times_zero = cells_brp
+        .iter()
+        .zip(&vanishing_poly.eval)
+        .map(|(cell, eval)| {
+            if cell.is_null() {
+                B::Fr::zero()
+            } else {
+                cell.mul(eval)
+            }
+        })
+        .collect::<Vec<_>>();
 
     let extended_evaluation_times_zero_coeffs =
         fft_settings.fft_fr(&extended_evaluation_times_zero, true)?;
     let mut extended_evaluations_over_coset =
         coset_fft::<B>(extended_evaluation_times_zero_coeffs, fft_settings)?;
 
-    let mut vanishing_poly_over_coset = coset_fft::<B>(vanishing_poly_coeff, fft_settings)?;
-    batch_inverse::<B>(&mut vanishing_poly_over_coset);
-
-    for i in 0..field_elements_per_ext_blob {
-        extended_evaluations_over_coset[i] =
-            extended_evaluations_over_coset[i].mul(&vanishing_poly_over_coset[i]);
+    for (eval, inv) in extended_evaluations_over_coset
+        .iter_mut()
+        .zip(&vanishing_poly.coset_eval_inv)
+    {
+        *eval = eval.mul(inv);
     }
 
     let reconstructed_poly_coeff = coset_ifft::<B>(&extended_evaluations_over_coset, fft_settings)?;
@@ -615,7 +926,7 @@ fn recover_cells<B: EcBackend>(
     Ok(())
 }
 
-fn poly_lagrange_to_monomial<B: EcBackend>(
+pub(crate) fn poly_lagrange_to_monomial<B: EcBackend>(
     lagrange_poly: &mut [B::Fr],
     fft_settings: &B::FFTSettings,
 ) -> Result<(), String> {
@@ -628,7 +939,7 @@ fn poly_lagrange_to_monomial<B: EcBackend>(
     Ok(())
 }
 
-fn toeplitz_coeffs_stride<B: EcBackend>(
+pub(crate) fn toeplitz_coeffs_stride<B: EcBackend>(
     out: &mut [B::Fr],
     input: &[B::Fr],
     n: usize,
@@ -667,7 +978,7 @@ fn compute_fk20_proofs<B: EcBackend>(
     let k = n / cell_size;
     let k2 = k * 2;
 
-    let mut coeffs = vec![vec![B::Fr::default(); k]; k2];
+    let mut coeffs = vec![vec![B::Fr::default(); cell_size]; k2];
     let mut toeplitz_coeffs = vec![B::Fr::default(); k2];
     let mut toeplitz_coeffs_fft = vec![B::Fr::default(); k2];
 
@@ -692,9 +1003,49 @@ fn compute_fk20_proofs<B: EcBackend>(
         .skip(k)
         .for_each(|h| *h = B::G1::identity());
 
+    // The proofs are the evaluations of h at the cell_size-th powers of the coset factors
+    h.resize(k * kzg_settings.get_extension_factor(), B::G1::identity());
+
     fft_settings.fft_g1(&h, false)
 }
 
+/// Computes the proof of the cell `cell_index` directly: the commitment to the quotient of the
+/// polynomial by `X^cell_size - h_k^cell_size`, the vanishing polynomial of the cell's coset.
+fn compute_cell_proof<B: EcBackend>(
+    cell_size: usize,
+    cell_index: usize,
+    poly_monomial: &[B::Fr],
+    kzg_settings: &B::KZGSettings,
+) -> Result<B::G1, String> {
+    let n = poly_monomial.len();
+    let fft_settings = kzg_settings.get_fft_settings();
+    let coset_shift_pow = get_coset_shift_pow_for_cell::<B>(
+        cell_size,
+        cell_index,
+        fft_settings,
+        field_elements_per_ext_blob::<B>(kzg_settings),
+    )?;
+
+    // q_j = p_(j + cell_size) + h_k^cell_size * q_(j + cell_size), the top cell_size
+    // coefficients of q are zero
+    let mut quotient = vec![B::Fr::zero(); n];
+    for j in (0..n - cell_size).rev() {
+        quotient[j] =
+            poly_monomial[j + cell_size].add(&coset_shift_pow.mul(&quotient[j + cell_size]));
+    }
+
+    // Commit in Lagrange form to use the precomputation of the setup
+    let mut quotient_lagrange = fft_settings.fft_fr(&quotient, false)?;
+    reverse_bit_order(&mut quotient_lagrange)?;
+
+    Ok(B::G1::g1_lincomb(
+        kzg_settings.get_g1_lagrange_brp(),
+        &quotient_lagrange,
+        n,
+        kzg_settings.get_precomputation(),
+    ))
+}
+
 fn compute_weighted_sum_of_commitments<B: EcBackend>(
     commitments: &[B::G1],
     commitment_indices: &[usize],
@@ -748,11 +1099,7 @@ fn get_inv_coset_shift_for_cell<B: EcBackend>(
      * Get the cell index in reverse-bit order.
      * This index points to this cell's coset factor h_k in the roots_of_unity array.
      */
-    let cell_index_rbl = if cells_per_ext_blob == eth::CELLS_PER_EXT_BLOB {
-        CELL_INDICES_RBL[cell_index]
-    } else {
-        reverse_bits_limited(cells_per_ext_blob, cell_index)
-    };
+    let cell_index_rbl = cell_index_rbl(cells_per_ext_blob, cell_index);
 
     /*
      * Observe that for every element in roots_of_unity, we can find its inverse by
@@ -782,8 +1129,9 @@ fn compute_commitment_to_aggregated_interpolation_poly<B: EcBackend>(
     cells: &[B::Fr],
     fft_settings: &B::FFTSettings,
     g1_monomial: &[B::G1],
+    field_elements_per_ext_blob: usize,
 ) -> Result<B::G1, String> {
-    let cells_per_ext_blob = g1_monomial.len() * 2 / cell_size;
+    let cells_per_ext_blob = field_elements_per_ext_blob / cell_size;
 
     let mut aggregated_column_cells = vec![B::Fr::zero(); cells_per_ext_blob * cell_size];
 
@@ -818,8 +1166,12 @@ fn compute_commitment_to_aggregated_interpolation_poly<B: EcBackend>(
         let mut column_interpolation_poly =
             fft_settings.fft_fr(&aggregated_column_cells[index..(index + cell_size)], true)?;
 
-        let inv_coset_factor =
-            get_inv_coset_shift_for_cell::<B>(cell_size, i, fft_settings, g1_monomial.len() * 2)?;
+        let inv_coset_factor = get_inv_coset_shift_for_cell::<B>(
+            cell_size,
+            i,
+            fft_settings,
+            field_elements_per_ext_blob,
+        )?;
 
         shift_poly::<B>(&mut column_interpolation_poly, &inv_coset_factor);
 
@@ -849,11 +1201,7 @@ fn get_coset_shift_pow_for_cell<B: EcBackend>(
      * Get the cell index in reverse-bit order.
      * This index points to this cell's coset factor h_k in the roots_of_unity array.
      */
-    let cell_idx_rbl = if cells_per_ext_blob == eth::CELLS_PER_EXT_BLOB {
-        CELL_INDICES_RBL[cell_index]
-    } else {
-        reverse_bits_limited(cells_per_ext_blob, cell_index)
-    };
+    let cell_idx_rbl = cell_index_rbl(cells_per_ext_blob, cell_index);
 
     /*
      * Get the index to h_k^n in the roots_of_unity array.
@@ -6,12 +6,15 @@ use crate::eth;
 use crate::eth::c_bindings::CKZGSettings;
 use crate::eth::FIELD_ELEMENTS_PER_EXT_BLOB;
 use crate::msm::precompute::PrecomputationTable;
+use crate::transcript::{Sha256Transcript, Transcript};
 use crate::G1Affine;
 use crate::G1Fp;
 use crate::G1GetFp;
 use crate::G1LinComb;
 use crate::G1ProjAddAffine;
-use crate::{FFTSettings, Fr, G1Mul, KZGSettings, PairingVerify, Poly, G1, G2};
+use crate::{
+    FFTSettings, Fr, G1Mul, G2Mul, KZGSettings, PairingVerify, PointValidation, Poly, G1, G2,
+};
 use alloc::collections::BTreeMap;
 use alloc::format;
 use alloc::string::String;
@@ -21,6 +24,8 @@ use alloc::vec;
 use alloc::vec::Vec;
 use core::hash::Hash;
 use core::hash::Hasher;
+#[cfg(feature = "rand")]
+use rand_core::{CryptoRng, RngCore};
 use sha2::{Digest, Sha256};
 use siphasher::sip::SipHasher;
 
@@ -33,6 +38,7 @@ pub const FIELD_ELEMENTS_PER_BLOB: usize = 4096;
 
 pub const BYTES_PER_G1: usize = 48;
 pub const BYTES_PER_G2: usize = 96;
+pub const BYTES_PER_GT: usize = 576;
 pub const BYTES_PER_BLOB: usize = BYTES_PER_FIELD_ELEMENT * FIELD_ELEMENTS_PER_BLOB;
 pub const BYTES_PER_FIELD_ELEMENT: usize = 32;
 pub const BYTES_PER_PROOF: usize = 48;
@@ -209,7 +215,11 @@ pub fn load_trusted_setup_string(contents: &str) -> Result<(Vec<u8>, Vec<u8>, Ve
             *offset
         };
 
-        u8::from_str_radix(&contents[start..end], 16).map_err(|_| String::from(TRUSTED_SETUP_ERROR))
+        // `end` is not a char boundary if the first character is not ASCII
+        let digits = contents
+            .get(start..end)
+            .ok_or_else(|| String::from(TRUSTED_SETUP_ERROR))?;
+        u8::from_str_radix(digits, 16).map_err(|_| String::from(TRUSTED_SETUP_ERROR))
     }
 
     for byte in &mut g1_lagrange_bytes {
@@ -325,59 +335,33 @@ pub fn compute_powers<TFr: Fr>(base: &TFr, num_powers: usize) -> Vec<TFr> {
     powers
 }
 
-fn compute_r_powers<TG1: G1, TFr: Fr>(
+fn compute_r_powers<T: Transcript, TG1: G1, TFr: Fr>(
     commitments_g1: &[TG1],
     zs_fr: &[TFr],
     ys_fr: &[TFr],
     proofs_g1: &[TG1],
-) -> Result<Vec<TFr>, String> {
+) -> Vec<TFr> {
     let n = commitments_g1.len();
-    let input_size =
-        32 + n * (BYTES_PER_COMMITMENT + 2 * BYTES_PER_FIELD_ELEMENT + BYTES_PER_PROOF);
-
-    let mut bytes: Vec<u8> = vec![0; input_size];
 
-    // Copy domain separator
-    bytes[..16].copy_from_slice(&RANDOM_CHALLENGE_KZG_BATCH_DOMAIN);
-    bytes_of_uint64(&mut bytes[16..24], FIELD_ELEMENTS_PER_BLOB as u64);
-    bytes_of_uint64(&mut bytes[24..32], n as u64);
-    let mut offset = 32;
+    let mut transcript = T::new(&RANDOM_CHALLENGE_KZG_BATCH_DOMAIN);
+    transcript.absorb_u64(FIELD_ELEMENTS_PER_BLOB as u64);
+    transcript.absorb_u64(n as u64);
 
     for i in 0..n {
-        // Copy commitment
-        let v = commitments_g1[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_COMMITMENT;
-
-        // Copy evaluation challenge
-        let v = zs_fr[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_FIELD_ELEMENT;
-
-        // Copy polynomial's evaluation value
-        let v = ys_fr[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_FIELD_ELEMENT;
-
-        // Copy proof
-        let v = proofs_g1[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_PROOF;
-    }
-
-    // Make sure we wrote the entire buffer
-    if offset != input_size {
-        return Err(String::from("Error while copying commitments"));
+        transcript.absorb_g1(&commitments_g1[i]);
+        transcript.absorb_fr(&zs_fr[i]);
+        transcript.absorb_fr(&ys_fr[i]);
+        transcript.absorb_g1(&proofs_g1[i]);
     }
 
     // Now let's create the challenge!
-    let eval_challenge = hash(&bytes);
-    let r = hash_to_bls_field(&eval_challenge);
+    let r = transcript.squeeze_fr();
 
-    Ok(compute_powers(&r, n))
+    compute_powers(&r, n)
 }
 
-fn verify_kzg_proof_batch<
+pub(crate) fn verify_kzg_proof_batch<
+    T: Transcript,
     TFr: Fr,
     TG1: G1
         + G1Mul<TFr>
@@ -397,16 +381,27 @@ fn verify_kzg_proof_batch<
     ys_fr: &[TFr],
     proofs_g1: &[TG1],
     ts: &TKZGSettings,
+    weights: Option<&[TFr]>,
 ) -> Result<bool, String> {
     let n = commitments_g1.len();
     let mut c_minus_y: Vec<TG1> = Vec::with_capacity(n);
     let mut r_times_z: Vec<TFr> = Vec::with_capacity(n);
 
-    // Compute the random lincomb challenges
-    let r_powers = compute_r_powers(commitments_g1, zs_fr, ys_fr, proofs_g1)?;
+    // Compute the random lincomb challenges, unless the caller drew them already as
+    // `RANDOM_WEIGHT_BITS`-bit weights
+    let r_powers = match weights {
+        Some(weights) => weights.to_vec(),
+        None => compute_r_powers::<T, _, _>(commitments_g1, zs_fr, ys_fr, proofs_g1),
+    };
+
+    // Random weights are short, so the MSMs over them can skip the windows of the high bits
+    let weighted_sum = |points: &[TG1]| match weights {
+        Some(_) => TG1::g1_lincomb_short(points, &r_powers, n, RANDOM_WEIGHT_BITS),
+        None => TG1::g1_lincomb(points, &r_powers, n, None),
+    };
 
     // Compute \sum r^i * Proof_i
-    let proof_lincomb = TG1::g1_lincomb(proofs_g1, &r_powers, n, None);
+    let proof_lincomb = weighted_sum(proofs_g1);
 
     for i in 0..n {
         // Get [y_i]
@@ -420,7 +415,7 @@ fn verify_kzg_proof_batch<
     // Get \sum r^i z_i Proof_i
     let proof_z_lincomb = TG1::g1_lincomb(proofs_g1, &r_times_z, n, None);
     // Get \sum r^i (C_i - [y_i])
-    let c_minus_y_lincomb = TG1::g1_lincomb(&c_minus_y, &r_powers, n, None);
+    let c_minus_y_lincomb = weighted_sum(&c_minus_y);
 
     // Get C_minus_y_lincomb + proof_z_lincomb
     let rhs_g1 = c_minus_y_lincomb.add_or_dbl(&proof_z_lincomb);
@@ -451,7 +446,34 @@ pub fn compute_kzg_proof_rust<
 ) -> Result<(TG1, TFr), String> {
     let polynomial = blob_to_polynomial(blob)?;
     let y = evaluate_polynomial_in_evaluation_form(&polynomial, z, s)?;
+    let q = compute_quotient_in_evaluation_form(&polynomial, z, &y, s)?;
 
+    let proof = TG1::g1_lincomb(
+        s.get_g1_lagrange_brp(),
+        q.get_coeffs(),
+        FIELD_ELEMENTS_PER_BLOB,
+        s.get_precomputation(),
+    );
+    Ok((proof, y))
+}
+
+/// Computes `(p(X) - y) / (X - z)` in evaluation form, where `y = p(z)`.
+fn compute_quotient_in_evaluation_form<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    polynomial: &TPoly,
+    z: &TFr,
+    y: &TFr,
+    s: &TKZGSettings,
+) -> Result<TPoly, String> {
     let mut tmp: TFr;
 
     let mut m: usize = 0;
@@ -471,7 +493,7 @@ pub fn compute_kzg_proof_rust<
             continue;
         }
         // (p_i - y) / (ω_i - z)
-        q.set_coeff_at(i, &poly_coeffs[i].sub(&y));
+        q.set_coeff_at(i, &poly_coeffs[i].sub(y));
         inverses_in[i] = roots_of_unity[i].sub(z);
     }
 
@@ -501,7 +523,7 @@ pub fn compute_kzg_proof_rust<
                 continue;
             }
             // Build numerator: ω_i * (p_i - y)
-            tmp = poly_coeffs[i].sub(&y);
+            tmp = poly_coeffs[i].sub(y);
             tmp = tmp.mul(&roots_of_unity[i]);
             // Do the division: (p_i - y) * ω_i / (z * (z - ω_i))
             tmp = tmp.mul(&inverses[i]);
@@ -509,16 +531,452 @@ pub fn compute_kzg_proof_rust<
         }
     }
 
+    Ok(q)
+}
+
+pub fn compute_kzg_proof_raw<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    blob: [u8; BYTES_PER_BLOB],
+    z: [u8; BYTES_PER_FIELD_ELEMENT],
+    s: &TKZGSettings,
+) -> Result<(TG1, TFr), String> {
+    let blob = bytes_to_blob(&blob)?;
+    let z = TFr::from_bytes(&z)?;
+    compute_kzg_proof_rust(&blob, &z, s)
+}
+
+pub fn compute_blob_kzg_proof_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    blob: &[TFr],
+    commitment: &TG1,
+    ts: &TKZGSettings,
+) -> Result<TG1, String> {
+    compute_blob_kzg_proof::<Sha256Transcript, _, _, _, _, _, _, _, _, _>(
+        blob,
+        commitment,
+        ts,
+        PointValidation::Checked,
+    )
+}
+
+/// Like [`compute_blob_kzg_proof_rust`], with the evaluation challenge derived by the transcript
+/// `T`.
+pub fn compute_blob_kzg_proof_with_transcript<
+    T: Transcript,
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    blob: &[TFr],
+    commitment: &TG1,
+    ts: &TKZGSettings,
+) -> Result<TG1, String> {
+    compute_blob_kzg_proof::<T, _, _, _, _, _, _, _, _, _>(
+        blob,
+        commitment,
+        ts,
+        PointValidation::Checked,
+    )
+}
+
+fn compute_blob_kzg_proof<
+    T: Transcript,
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    blob: &[TFr],
+    commitment: &TG1,
+    ts: &TKZGSettings,
+    validation: PointValidation,
+) -> Result<TG1, String> {
+    if !validation.is_valid_g1(commitment) {
+        return Err("Invalid commitment".to_string());
+    }
+
+    let evaluation_challenge_fr = compute_challenge_with_transcript::<T, _, _>(blob, commitment);
+    let (proof, _) = compute_kzg_proof_rust(blob, &evaluation_challenge_fr, ts)?;
+    Ok(proof)
+}
+
+pub fn compute_blob_kzg_proof_raw<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    blob: [u8; BYTES_PER_BLOB],
+    commitment: [u8; BYTES_PER_G1],
+    ts: &TKZGSettings,
+) -> Result<TG1, String> {
+    compute_blob_kzg_proof_raw_with_validation(blob, commitment, ts, PointValidation::Checked)
+}
+
+/// Like [`compute_blob_kzg_proof_raw`], with the checks of the commitment picked by `validation`.
+pub fn compute_blob_kzg_proof_raw_with_validation<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    blob: [u8; BYTES_PER_BLOB],
+    commitment: [u8; BYTES_PER_G1],
+    ts: &TKZGSettings,
+    validation: PointValidation,
+) -> Result<TG1, String> {
+    let blob = bytes_to_blob(&blob)?;
+    let commitment = validation.g1_from_bytes(&commitment)?;
+
+    compute_blob_kzg_proof::<Sha256Transcript, _, _, _, _, _, _, _, _, _>(
+        &blob,
+        &commitment,
+        ts,
+        validation,
+    )
+}
+
+pub fn verify_kzg_proof_rust<
+    TFr: Fr,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    commitment: &TG1,
+    z: &TFr,
+    y: &TFr,
+    proof: &TG1,
+    s: &TKZGSettings,
+) -> Result<bool, String> {
+    verify_kzg_proof(commitment, z, y, proof, s, PointValidation::Checked)
+}
+
+fn verify_kzg_proof<
+    TFr: Fr,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    commitment: &TG1,
+    z: &TFr,
+    y: &TFr,
+    proof: &TG1,
+    s: &TKZGSettings,
+    validation: PointValidation,
+) -> Result<bool, String> {
+    if !validation.is_valid_g1(commitment) {
+        return Err("Invalid commitment".to_string());
+    }
+    if !validation.is_valid_g1(proof) {
+        return Err("Invalid proof".to_string());
+    }
+
+    s.check_proof_single(commitment, proof, z, y)
+}
+
+pub fn verify_kzg_proof_raw<
+    TFr: Fr,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    commitment: [u8; BYTES_PER_G1],
+    z: [u8; BYTES_PER_FIELD_ELEMENT],
+    y: [u8; BYTES_PER_FIELD_ELEMENT],
+    proof: [u8; BYTES_PER_G1],
+    s: &TKZGSettings,
+) -> Result<bool, String> {
+    verify_kzg_proof_raw_with_validation(commitment, z, y, proof, s, PointValidation::Checked)
+}
+
+/// Like [`verify_kzg_proof_raw`], with the checks of the points picked by `validation`.
+pub fn verify_kzg_proof_raw_with_validation<
+    TFr: Fr,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    commitment: [u8; BYTES_PER_G1],
+    z: [u8; BYTES_PER_FIELD_ELEMENT],
+    y: [u8; BYTES_PER_FIELD_ELEMENT],
+    proof: [u8; BYTES_PER_G1],
+    s: &TKZGSettings,
+    validation: PointValidation,
+) -> Result<bool, String> {
+    let commitment = validation.g1_from_bytes(&commitment)?;
+    let z = TFr::from_bytes(&z)?;
+    let y = TFr::from_bytes(&y)?;
+    let proof = validation.g1_from_bytes(&proof)?;
+
+    verify_kzg_proof(&commitment, &z, &y, &proof, s, validation)
+}
+
+/// Verifies independent openings `(commitments_g1[i], zs_fr[i], ys_fr[i], proofs_g1[i])`, e.g. of
+/// different polynomials at arbitrary points, with a single pairing check: the openings are
+/// combined with the powers of a Fiat-Shamir challenge, like the blob proofs in
+/// [`verify_blob_kzg_proof_batch_rust`].
+pub fn verify_kzg_proof_batch_rust<
+    TFr: Fr,
+    TG1: G1
+        + G1Mul<TFr>
+        + G1GetFp<TG1Fp>
+        + PairingVerify<TG1, TG2>
+        + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    commitments_g1: &[TG1],
+    zs_fr: &[TFr],
+    ys_fr: &[TFr],
+    proofs_g1: &[TG1],
+    s: &TKZGSettings,
+) -> Result<bool, String> {
+    verify_openings_batch(
+        commitments_g1,
+        zs_fr,
+        ys_fr,
+        proofs_g1,
+        s,
+        PointValidation::Checked,
+    )
+}
+
+fn verify_openings_batch<
+    TFr: Fr,
+    TG1: G1
+        + G1Mul<TFr>
+        + G1GetFp<TG1Fp>
+        + PairingVerify<TG1, TG2>
+        + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    commitments_g1: &[TG1],
+    zs_fr: &[TFr],
+    ys_fr: &[TFr],
+    proofs_g1: &[TG1],
+    s: &TKZGSettings,
+    validation: PointValidation,
+) -> Result<bool, String> {
+    let n = commitments_g1.len();
+    if zs_fr.len() != n || ys_fr.len() != n || proofs_g1.len() != n {
+        return Err("Invalid amount of arguments".to_string());
+    }
+
+    if n == 0 {
+        return Ok(true);
+    }
+
+    validate_batched_input(commitments_g1, proofs_g1, validation)?;
+
+    verify_kzg_proof_batch::<Sha256Transcript, _, _, _, _, _, _, _, _, _>(
+        commitments_g1,
+        zs_fr,
+        ys_fr,
+        proofs_g1,
+        s,
+        None,
+    )
+}
+
+pub fn verify_kzg_proof_batch_raw<
+    TFr: Fr,
+    TG1: G1
+        + G1Mul<TFr>
+        + G1GetFp<TG1Fp>
+        + PairingVerify<TG1, TG2>
+        + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    commitments_g1: &[[u8; BYTES_PER_G1]],
+    zs: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+    ys: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+    proofs_g1: &[[u8; BYTES_PER_G1]],
+    s: &TKZGSettings,
+) -> Result<bool, String> {
+    verify_kzg_proof_batch_raw_with_validation(
+        commitments_g1,
+        zs,
+        ys,
+        proofs_g1,
+        s,
+        PointValidation::Checked,
+    )
+}
+
+/// Like [`verify_kzg_proof_batch_raw`], with the checks of the points picked by `validation`.
+pub fn verify_kzg_proof_batch_raw_with_validation<
+    TFr: Fr,
+    TG1: G1
+        + G1Mul<TFr>
+        + G1GetFp<TG1Fp>
+        + PairingVerify<TG1, TG2>
+        + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    commitments_g1: &[[u8; BYTES_PER_G1]],
+    zs: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+    ys: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+    proofs_g1: &[[u8; BYTES_PER_G1]],
+    s: &TKZGSettings,
+    validation: PointValidation,
+) -> Result<bool, String> {
+    let commitments_g1 = validation
+        .g1_from_bytes_batch(commitments_g1)
+        .map_err(|err| format!("Invalid commitment: {err}"))?;
+    let zs_fr = zs
+        .iter()
+        .map(|z| TFr::from_bytes(z))
+        .collect::<Result<Vec<_>, _>>()?;
+    let ys_fr = ys
+        .iter()
+        .map(|y| TFr::from_bytes(y))
+        .collect::<Result<Vec<_>, _>>()?;
+    let proofs_g1 = validation
+        .g1_from_bytes_batch(proofs_g1)
+        .map_err(|err| format!("Invalid proof: {err}"))?;
+
+    // the points were checked while decoding
+    verify_openings_batch(
+        &commitments_g1,
+        &zs_fr,
+        &ys_fr,
+        &proofs_g1,
+        s,
+        PointValidation::Trusted,
+    )
+}
+
+/// Opens the polynomial of `blob` at every point of `zs` with a single proof, and returns the
+/// proof together with the evaluations at `zs`.
+///
+/// The proof is the commitment to `q(X) = (p(X) - I(X)) / Z(X)`, where `Z` is the vanishing
+/// polynomial of `zs` and `I` interpolates the evaluations over `zs`. The points must be distinct,
+/// and there can be at most `s.get_g2_monomial().len() - 1` of them, as the verifier needs
+/// `[Z(s)]_2`. Field element `i` of the blob is the evaluation at
+/// `s.get_fft_settings().get_brp_roots_of_unity_at(i)`.
+pub fn compute_kzg_multi_proof_rust<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    blob: &[TFr],
+    zs: &[TFr],
+    s: &TKZGSettings,
+) -> Result<(TG1, Vec<TFr>), String> {
+    let weights = multi_proof_weights(zs, s.get_g2_monomial().len())?;
+    let polynomial = blob_to_polynomial(blob)?;
+
+    // 1 / Z(X) = sum(w_j / (X - z_j)) with w_j = 1 / Z'(z_j), so the quotient is the combination
+    // of the single point quotients (p(X) - y_j) / (X - z_j) with the weights w_j.
+    let mut ys = Vec::with_capacity(zs.len());
+    let mut q = vec![TFr::zero(); FIELD_ELEMENTS_PER_BLOB];
+    for (z, weight) in zs.iter().zip(weights.iter()) {
+        let y = evaluate_polynomial_in_evaluation_form(&polynomial, z, s)?;
+        let q_z = compute_quotient_in_evaluation_form(&polynomial, z, &y, s)?;
+        for (acc, coeff) in q.iter_mut().zip(q_z.get_coeffs()) {
+            *acc = acc.add(&coeff.mul(weight));
+        }
+        ys.push(y);
+    }
+
     let proof = TG1::g1_lincomb(
         s.get_g1_lagrange_brp(),
-        q.get_coeffs(),
+        &q,
         FIELD_ELEMENTS_PER_BLOB,
         s.get_precomputation(),
     );
-    Ok((proof, y))
+    Ok((proof, ys))
 }
 
-pub fn compute_kzg_proof_raw<
+pub fn compute_kzg_multi_proof_raw<
     TFr: Fr + Copy,
     TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
     TG2: G2,
@@ -529,19 +987,53 @@ pub fn compute_kzg_proof_raw<
     TG1Affine: G1Affine<TG1, TG1Fp>,
     TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
 >(
-    blob: [u8; BYTES_PER_BLOB],
-    z: [u8; BYTES_PER_FIELD_ELEMENT],
+    blob: [u8; BYTES_PER_BLOB],
+    zs: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+    s: &TKZGSettings,
+) -> Result<(TG1, Vec<TFr>), String> {
+    let blob = bytes_to_blob(&blob)?;
+    let zs = zs
+        .iter()
+        .map(|z| TFr::from_bytes(z))
+        .collect::<Result<Vec<_>, _>>()?;
+    compute_kzg_multi_proof_rust(&blob, &zs, s)
+}
+
+/// Verifies a proof made by [`compute_kzg_multi_proof_rust`] that the polynomial committed to by
+/// `commitment` evaluates to `ys[i]` at `zs[i]`, by checking
+/// `e(commitment - [I(s)]_1, [1]_2) == e(proof, [Z(s)]_2)`.
+pub fn verify_kzg_multi_proof_rust<
+    TFr: Fr,
+    TG1: G1
+        + G1Mul<TFr>
+        + G1GetFp<TG1Fp>
+        + PairingVerify<TG1, TG2>
+        + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2 + G2Mul<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    commitment: &TG1,
+    zs: &[TFr],
+    ys: &[TFr],
+    proof: &TG1,
     s: &TKZGSettings,
-) -> Result<(TG1, TFr), String> {
-    let blob = bytes_to_blob(&blob)?;
-    let z = TFr::from_bytes(&z)?;
-    compute_kzg_proof_rust(&blob, &z, s)
+) -> Result<bool, String> {
+    verify_kzg_multi_proof(commitment, zs, ys, proof, s, PointValidation::Checked)
 }
 
-pub fn compute_blob_kzg_proof_rust<
-    TFr: Fr + Copy,
-    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
-    TG2: G2,
+fn verify_kzg_multi_proof<
+    TFr: Fr,
+    TG1: G1
+        + G1Mul<TFr>
+        + G1GetFp<TG1Fp>
+        + PairingVerify<TG1, TG2>
+        + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2 + G2Mul<TFr>,
     TFFTSettings: FFTSettings<TFr>,
     TPoly: Poly<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
@@ -549,23 +1041,64 @@ pub fn compute_blob_kzg_proof_rust<
     TG1Affine: G1Affine<TG1, TG1Fp>,
     TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
 >(
-    blob: &[TFr],
     commitment: &TG1,
-    ts: &TKZGSettings,
-) -> Result<TG1, String> {
-    if !commitment.is_inf() && !commitment.is_valid() {
+    zs: &[TFr],
+    ys: &[TFr],
+    proof: &TG1,
+    s: &TKZGSettings,
+    validation: PointValidation,
+) -> Result<bool, String> {
+    if !validation.is_valid_g1(commitment) {
         return Err("Invalid commitment".to_string());
     }
+    if !validation.is_valid_g1(proof) {
+        return Err("Invalid proof".to_string());
+    }
+    if ys.len() != zs.len() {
+        return Err("Invalid amount of arguments".to_string());
+    }
 
-    let evaluation_challenge_fr = compute_challenge_rust(blob, commitment);
-    let (proof, _) = compute_kzg_proof_rust(blob, &evaluation_challenge_fr, ts)?;
-    Ok(proof)
+    let g2_monomial = s.get_g2_monomial();
+    let weights = multi_proof_weights(zs, g2_monomial.len())?;
+    let vanishing_poly = vanishing_poly_coeffs(zs);
+
+    // I(X) = sum(y_j * w_j * Z(X) / (X - z_j))
+    let mut interp = vec![TFr::zero(); zs.len()];
+    for ((z, y), weight) in zs.iter().zip(ys.iter()).zip(weights.iter()) {
+        let scale = y.mul(weight);
+        let mut carry = TFr::zero();
+        for i in (0..zs.len()).rev() {
+            carry = vanishing_poly[i + 1].add(&carry.mul(z));
+            interp[i] = interp[i].add(&carry.mul(&scale));
+        }
+    }
+
+    let interp_g1 = TG1::g1_lincomb(s.get_g1_monomial(), &interp, interp.len(), None);
+
+    // Z is monic, so its leading term is just the power of s
+    let vanishing_g2 = vanishing_poly[..zs.len()]
+        .iter()
+        .zip(g2_monomial.iter())
+        .fold(g2_monomial[zs.len()].clone(), |mut acc, (coeff, power)| {
+            acc.add_or_dbl(&power.mul(coeff))
+        });
+
+    Ok(TG1::verify(
+        &commitment.sub(&interp_g1),
+        &TG2::generator(),
+        proof,
+        &vanishing_g2,
+    ))
 }
 
-pub fn compute_blob_kzg_proof_raw<
-    TFr: Fr + Copy,
-    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
-    TG2: G2,
+pub fn verify_kzg_multi_proof_raw<
+    TFr: Fr,
+    TG1: G1
+        + G1Mul<TFr>
+        + G1GetFp<TG1Fp>
+        + PairingVerify<TG1, TG2>
+        + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2 + G2Mul<TFr>,
     TFFTSettings: FFTSettings<TFr>,
     TPoly: Poly<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
@@ -573,20 +1106,24 @@ pub fn compute_blob_kzg_proof_raw<
     TG1Affine: G1Affine<TG1, TG1Fp>,
     TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
 >(
-    blob: [u8; BYTES_PER_BLOB],
     commitment: [u8; BYTES_PER_G1],
-    ts: &TKZGSettings,
-) -> Result<TG1, String> {
-    let blob = bytes_to_blob(&blob)?;
-    let commitment = TG1::from_bytes(&commitment)?;
-
-    compute_blob_kzg_proof_rust(&blob, &commitment, ts)
+    zs: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+    ys: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+    proof: [u8; BYTES_PER_G1],
+    s: &TKZGSettings,
+) -> Result<bool, String> {
+    verify_kzg_multi_proof_raw_with_validation(commitment, zs, ys, proof, s, PointValidation::Checked)
 }
 
-pub fn verify_kzg_proof_rust<
+/// Like [`verify_kzg_multi_proof_raw`], with the checks of the points picked by `validation`.
+pub fn verify_kzg_multi_proof_raw_with_validation<
     TFr: Fr,
-    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
-    TG2: G2,
+    TG1: G1
+        + G1Mul<TFr>
+        + G1GetFp<TG1Fp>
+        + PairingVerify<TG1, TG2>
+        + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2 + G2Mul<TFr>,
     TFFTSettings: FFTSettings<TFr>,
     TPoly: Poly<TFr>,
     TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
@@ -594,24 +1131,72 @@ pub fn verify_kzg_proof_rust<
     TG1Affine: G1Affine<TG1, TG1Fp>,
     TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
 >(
-    commitment: &TG1,
-    z: &TFr,
-    y: &TFr,
-    proof: &TG1,
+    commitment: [u8; BYTES_PER_G1],
+    zs: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+    ys: &[[u8; BYTES_PER_FIELD_ELEMENT]],
+    proof: [u8; BYTES_PER_G1],
     s: &TKZGSettings,
+    validation: PointValidation,
 ) -> Result<bool, String> {
-    if !commitment.is_inf() && !commitment.is_valid() {
-        return Err("Invalid commitment".to_string());
+    let commitment = validation.g1_from_bytes(&commitment)?;
+    let zs = zs
+        .iter()
+        .map(|z| TFr::from_bytes(z))
+        .collect::<Result<Vec<_>, _>>()?;
+    let ys = ys
+        .iter()
+        .map(|y| TFr::from_bytes(y))
+        .collect::<Result<Vec<_>, _>>()?;
+    let proof = validation.g1_from_bytes(&proof)?;
+
+    verify_kzg_multi_proof(&commitment, &zs, &ys, &proof, s, validation)
+}
+
+/// Computes the barycentric weights `1 / Z'(z_j) = 1 / prod(z_j - z_k, k != j)` of the opening
+/// points, checking that they are distinct and that `[Z(s)]_2` fits in a setup of
+/// `g2_monomial_len` powers.
+fn multi_proof_weights<TFr: Fr>(zs: &[TFr], g2_monomial_len: usize) -> Result<Vec<TFr>, String> {
+    if zs.is_empty() {
+        return Err("At least one point is required".to_string());
     }
-    if !proof.is_inf() && !proof.is_valid() {
-        return Err("Invalid proof".to_string());
+    if zs.len() >= g2_monomial_len {
+        return Err(format!(
+            "At most {} points can be opened at once",
+            g2_monomial_len.saturating_sub(1)
+        ));
     }
 
-    s.check_proof_single(commitment, proof, z, y)
+    zs.iter()
+        .enumerate()
+        .map(|(j, z_j)| {
+            let denominator = zs
+                .iter()
+                .enumerate()
+                .filter(|(k, _)| *k != j)
+                .fold(TFr::one(), |acc, (_, z_k)| acc.mul(&z_j.sub(z_k)));
+            if denominator.is_zero() {
+                return Err("Points must be distinct".to_string());
+            }
+            Ok(denominator.inverse())
+        })
+        .collect()
 }
 
-pub fn verify_kzg_proof_raw<
-    TFr: Fr,
+/// Coefficients of `Z(X) = prod(X - z_j)`, lowest degree first.
+fn vanishing_poly_coeffs<TFr: Fr>(zs: &[TFr]) -> Vec<TFr> {
+    let mut coeffs = vec![TFr::one()];
+    for z in zs {
+        coeffs.push(TFr::zero());
+        for i in (1..coeffs.len()).rev() {
+            coeffs[i] = coeffs[i - 1].sub(&coeffs[i].mul(z));
+        }
+        coeffs[0] = coeffs[0].mul(z).negate();
+    }
+    coeffs
+}
+
+pub fn verify_blob_kzg_proof_rust<
+    TFr: Fr + Copy,
     TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
     TG2: G2,
     TFFTSettings: FFTSettings<TFr>,
@@ -621,21 +1206,50 @@ pub fn verify_kzg_proof_raw<
     TG1Affine: G1Affine<TG1, TG1Fp>,
     TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
 >(
-    commitment: [u8; BYTES_PER_G1],
-    z: [u8; BYTES_PER_FIELD_ELEMENT],
-    y: [u8; BYTES_PER_FIELD_ELEMENT],
-    proof: [u8; BYTES_PER_G1],
-    s: &TKZGSettings,
+    blob: &[TFr],
+    commitment_g1: &TG1,
+    proof_g1: &TG1,
+    ts: &TKZGSettings,
 ) -> Result<bool, String> {
-    let commitment = TG1::from_bytes(&commitment)?;
-    let z = TFr::from_bytes(&z)?;
-    let y = TFr::from_bytes(&y)?;
-    let proof = TG1::from_bytes(&proof)?;
+    verify_blob_kzg_proof::<Sha256Transcript, _, _, _, _, _, _, _, _, _>(
+        blob,
+        commitment_g1,
+        proof_g1,
+        ts,
+        PointValidation::Checked,
+    )
+}
 
-    verify_kzg_proof_rust(&commitment, &z, &y, &proof, s)
+/// Like [`verify_blob_kzg_proof_rust`], with the evaluation challenge derived by the transcript
+/// `T`.
+pub fn verify_blob_kzg_proof_with_transcript<
+    T: Transcript,
+    TFr: Fr + Copy,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    blob: &[TFr],
+    commitment_g1: &TG1,
+    proof_g1: &TG1,
+    ts: &TKZGSettings,
+) -> Result<bool, String> {
+    verify_blob_kzg_proof::<T, _, _, _, _, _, _, _, _, _>(
+        blob,
+        commitment_g1,
+        proof_g1,
+        ts,
+        PointValidation::Checked,
+    )
 }
 
-pub fn verify_blob_kzg_proof_rust<
+fn verify_blob_kzg_proof<
+    T: Transcript,
     TFr: Fr + Copy,
     TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
     TG2: G2,
@@ -650,18 +1264,19 @@ pub fn verify_blob_kzg_proof_rust<
     commitment_g1: &TG1,
     proof_g1: &TG1,
     ts: &TKZGSettings,
+    validation: PointValidation,
 ) -> Result<bool, String> {
-    if !commitment_g1.is_inf() && !commitment_g1.is_valid() {
+    if !validation.is_valid_g1(commitment_g1) {
         return Err("Invalid commitment".to_string());
     }
-    if !proof_g1.is_inf() && !proof_g1.is_valid() {
+    if !validation.is_valid_g1(proof_g1) {
         return Err("Invalid proof".to_string());
     }
 
     let polynomial = blob_to_polynomial(blob)?;
-    let evaluation_challenge_fr = compute_challenge_rust(blob, commitment_g1);
+    let evaluation_challenge_fr = compute_challenge_with_transcript::<T, _, _>(blob, commitment_g1);
     let y_fr = evaluate_polynomial_in_evaluation_form(&polynomial, &evaluation_challenge_fr, ts)?;
-    verify_kzg_proof_rust(commitment_g1, &evaluation_challenge_fr, &y_fr, proof_g1, ts)
+    ts.check_proof_single(commitment_g1, proof_g1, &evaluation_challenge_fr, &y_fr)
 }
 
 pub fn verify_blob_kzg_proof_raw<
@@ -680,14 +1295,48 @@ pub fn verify_blob_kzg_proof_raw<
     proof_g1: [u8; BYTES_PER_G1],
     ts: &TKZGSettings,
 ) -> Result<bool, String> {
-    let blob = bytes_to_blob(&blob)?;
-    let commitment_g1 = TG1::from_bytes(&commitment_g1)?;
-    let proof_g1 = TG1::from_bytes(&proof_g1)?;
+    verify_blob_kzg_proof_raw_with_validation(
+        blob,
+        commitment_g1,
+        proof_g1,
+        ts,
+        PointValidation::Checked,
+    )
+}
 
-    verify_blob_kzg_proof_rust(&blob, &commitment_g1, &proof_g1, ts)
+/// Like [`verify_blob_kzg_proof_raw`], with the checks of the points picked by `validation`.
+pub fn verify_blob_kzg_proof_raw_with_validation<
+    TFr: Fr + Copy,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    blob: [u8; BYTES_PER_BLOB],
+    commitment_g1: [u8; BYTES_PER_G1],
+    proof_g1: [u8; BYTES_PER_G1],
+    ts: &TKZGSettings,
+    validation: PointValidation,
+) -> Result<bool, String> {
+    let blob = bytes_to_blob(&blob)?;
+    let commitment_g1 = validation.g1_from_bytes(&commitment_g1)?;
+    let proof_g1 = validation.g1_from_bytes(&proof_g1)?;
+
+    verify_blob_kzg_proof::<Sha256Transcript, _, _, _, _, _, _, _, _, _>(
+        &blob,
+        &commitment_g1,
+        &proof_g1,
+        ts,
+        validation,
+    )
 }
 
 fn compute_challenges_and_evaluate_polynomial<
+    T: Transcript,
     TFr: Fr + Copy,
     TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
     TG2: G2,
@@ -707,7 +1356,8 @@ fn compute_challenges_and_evaluate_polynomial<
 
     for i in 0..blobs.len() {
         let polynomial = blob_to_polynomial(&blobs[i])?;
-        let evaluation_challenge_fr = compute_challenge_rust(&blobs[i], &commitments_g1[i]);
+        let evaluation_challenge_fr =
+            compute_challenge_with_transcript::<T, _, _>(&blobs[i], &commitments_g1[i]);
         let y_fr =
             evaluate_polynomial_in_evaluation_form(&polynomial, &evaluation_challenge_fr, ts)?;
 
@@ -718,7 +1368,15 @@ fn compute_challenges_and_evaluate_polynomial<
     Ok((evaluation_challenges_fr, ys_fr))
 }
 
-fn validate_batched_input<TG1: G1>(commitments: &[TG1], proofs: &[TG1]) -> Result<(), String> {
+fn validate_batched_input<TG1: G1>(
+    commitments: &[TG1],
+    proofs: &[TG1],
+    validation: PointValidation,
+) -> Result<(), String> {
+    if validation == PointValidation::Trusted {
+        return Ok(());
+    }
+
     let invalid_commitment = cfg_into_iter!(commitments)
         .any(|commitment| !commitment.is_inf() && !commitment.is_valid());
     let invalid_proof = cfg_into_iter!(proofs).any(|proof| !proof.is_inf() && !proof.is_valid());
@@ -752,6 +1410,126 @@ pub fn verify_blob_kzg_proof_batch_rust<
     commitments_g1: &[TG1],
     proofs_g1: &[TG1],
     ts: &TKZGSettings,
+) -> Result<bool, String> {
+    verify_blob_kzg_proof_batch::<Sha256Transcript, _, _, _, _, _, _, _, _, _>(
+        blobs,
+        commitments_g1,
+        proofs_g1,
+        ts,
+        PointValidation::Checked,
+        None,
+    )
+}
+
+/// Like [`verify_blob_kzg_proof_batch_rust`], with all challenges derived by the transcript `T`.
+pub fn verify_blob_kzg_proof_batch_with_transcript<
+    T: Transcript,
+    TFr: Fr + Copy,
+    TG1: G1
+        + G1Mul<TFr>
+        + PairingVerify<TG1, TG2>
+        + G1GetFp<TG1Fp>
+        + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    blobs: &[Vec<TFr>],
+    commitments_g1: &[TG1],
+    proofs_g1: &[TG1],
+    ts: &TKZGSettings,
+) -> Result<bool, String> {
+    verify_blob_kzg_proof_batch::<T, _, _, _, _, _, _, _, _, _>(
+        blobs,
+        commitments_g1,
+        proofs_g1,
+        ts,
+        PointValidation::Checked,
+        None,
+    )
+}
+
+/// Like [`verify_blob_kzg_proof_batch_rust`], but the proofs are combined with random 128-bit
+/// weights drawn from `rng` instead of the powers of a Fiat-Shamir challenge, which skips hashing
+/// every commitment, evaluation and proof of the batch.
+///
+/// The result depends on `rng`, so only use it for local verification, with a cryptographically
+/// secure generator.
+#[cfg(feature = "rand")]
+pub fn verify_blob_kzg_proof_batch_randomized<
+    TFr: Fr + Copy,
+    TG1: G1
+        + G1Mul<TFr>
+        + PairingVerify<TG1, TG2>
+        + G1GetFp<TG1Fp>
+        + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    blobs: &[Vec<TFr>],
+    commitments_g1: &[TG1],
+    proofs_g1: &[TG1],
+    ts: &TKZGSettings,
+    rng: &mut (impl RngCore + CryptoRng),
+) -> Result<bool, String> {
+    let weights = random_batch_weights(rng, blobs.len());
+
+    verify_blob_kzg_proof_batch::<Sha256Transcript, _, _, _, _, _, _, _, _, _>(
+        blobs,
+        commitments_g1,
+        proofs_g1,
+        ts,
+        PointValidation::Checked,
+        Some(&weights),
+    )
+}
+
+/// Bit length of the weights drawn by `random_batch_weights`.
+pub(crate) const RANDOM_WEIGHT_BITS: usize = 128;
+
+/// Draws `n` uniformly random 128-bit weights for a random linear combination. A batch with an
+/// invalid proof passes with probability at most 2^-128.
+#[cfg(feature = "rand")]
+pub(crate) fn random_batch_weights<TFr: Fr>(
+    rng: &mut (impl RngCore + CryptoRng),
+    n: usize,
+) -> Vec<TFr> {
+    (0..n)
+        .map(|_| TFr::from_u64_arr(&[rng.next_u64(), rng.next_u64(), 0, 0]))
+        .collect()
+}
+
+fn verify_blob_kzg_proof_batch<
+    T: Transcript,
+    TFr: Fr + Copy,
+    TG1: G1
+        + G1Mul<TFr>
+        + PairingVerify<TG1, TG2>
+        + G1GetFp<TG1Fp>
+        + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    blobs: &[Vec<TFr>],
+    commitments_g1: &[TG1],
+    proofs_g1: &[TG1],
+    ts: &TKZGSettings,
+    validation: PointValidation,
+    weights: Option<&[TFr]>,
 ) -> Result<bool, String> {
     // Exit early if we are given zero blobs
     if blobs.is_empty() {
@@ -760,7 +1538,13 @@ pub fn verify_blob_kzg_proof_batch_rust<
 
     // For a single blob, just do a regular single verification
     if blobs.len() == 1 {
-        return verify_blob_kzg_proof_rust(&blobs[0], &commitments_g1[0], &proofs_g1[0], ts);
+        return verify_blob_kzg_proof::<T, _, _, _, _, _, _, _, _, _>(
+            &blobs[0],
+            &commitments_g1[0],
+            &proofs_g1[0],
+            ts,
+            validation,
+        );
     }
 
     if blobs.len() != commitments_g1.len() || blobs.len() != proofs_g1.len() {
@@ -773,7 +1557,7 @@ pub fn verify_blob_kzg_proof_batch_rust<
         let num_cores = num_cpus::get_physical();
 
         if num_blobs > num_cores {
-            validate_batched_input(commitments_g1, proofs_g1)?;
+            validate_batched_input(commitments_g1, proofs_g1, validation)?;
 
             // Process blobs in parallel subgroups
             let blobs_per_group = num_blobs / num_cores;
@@ -787,19 +1571,23 @@ pub fn verify_blob_kzg_proof_batch_rust<
                         [blobs_per_group * i..blobs_per_group * i + num_blobs_in_group];
                     let proof_group =
                         &proofs_g1[blobs_per_group * i..blobs_per_group * i + num_blobs_in_group];
+                    let weight_group = weights.map(|weights| {
+                        &weights[blobs_per_group * i..blobs_per_group * i + num_blobs_in_group]
+                    });
                     let (evaluation_challenges_fr, ys_fr) =
-                        compute_challenges_and_evaluate_polynomial(
+                        compute_challenges_and_evaluate_polynomial::<T, _, _, _, _, _, _, _, _, _>(
                             blob_group,
                             commitment_group,
                             ts,
                         )?;
 
-                    verify_kzg_proof_batch(
+                    verify_kzg_proof_batch::<T, _, _, _, _, _, _, _, _, _>(
                         commitment_group,
                         &evaluation_challenges_fr,
                         &ys_fr,
                         proof_group,
                         ts,
+                        weight_group,
                     )
                 })
                 .try_reduce(|| true, |a, b| Ok(a && b))
@@ -809,7 +1597,9 @@ pub fn verify_blob_kzg_proof_batch_rust<
             (blobs, commitments_g1, proofs_g1)
                 .into_par_iter()
                 .map(|(blob, commitment, proof)| {
-                    verify_blob_kzg_proof_rust(blob, commitment, proof, ts)
+                    verify_blob_kzg_proof::<T, _, _, _, _, _, _, _, _, _>(
+                        blob, commitment, proof, ts, validation,
+                    )
                 })
                 .try_reduce(|| true, |a, b| Ok(a && b))
         }
@@ -817,16 +1607,21 @@ pub fn verify_blob_kzg_proof_batch_rust<
 
     #[cfg(not(feature = "parallel"))]
     {
-        validate_batched_input(commitments_g1, proofs_g1)?;
+        validate_batched_input(commitments_g1, proofs_g1, validation)?;
         let (evaluation_challenges_fr, ys_fr) =
-            compute_challenges_and_evaluate_polynomial(blobs, commitments_g1, ts)?;
+            compute_challenges_and_evaluate_polynomial::<T, _, _, _, _, _, _, _, _, _>(
+                blobs,
+                commitments_g1,
+                ts,
+            )?;
 
-        verify_kzg_proof_batch(
+        verify_kzg_proof_batch::<T, _, _, _, _, _, _, _, _, _>(
             commitments_g1,
             &evaluation_challenges_fr,
             &ys_fr,
             proofs_g1,
             ts,
+            weights,
         )
     }
 }
@@ -850,18 +1645,57 @@ pub fn verify_blob_kzg_proof_batch_raw<
     commitments_g1: &[[u8; BYTES_PER_G1]],
     proofs_g1: &[[u8; BYTES_PER_G1]],
     ts: &TKZGSettings,
+) -> Result<bool, String> {
+    verify_blob_kzg_proof_batch_raw_with_validation(
+        blobs,
+        commitments_g1,
+        proofs_g1,
+        ts,
+        PointValidation::Checked,
+    )
+}
+
+/// Like [`verify_blob_kzg_proof_batch_raw`], with the checks of the points picked by `validation`.
+pub fn verify_blob_kzg_proof_batch_raw_with_validation<
+    TFr: Fr + Copy + Send,
+    TG1: G1
+        + G1Mul<TFr>
+        + PairingVerify<TG1, TG2>
+        + G1GetFp<TG1Fp>
+        + G1LinComb<TFr, TG1Fp, TG1Affine, TG1ProjAddAffine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine, TG1ProjAddAffine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TG1ProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+>(
+    blobs: &[[u8; BYTES_PER_BLOB]],
+    commitments_g1: &[[u8; BYTES_PER_G1]],
+    proofs_g1: &[[u8; BYTES_PER_G1]],
+    ts: &TKZGSettings,
+    validation: PointValidation,
 ) -> Result<bool, String> {
     let blobs = cfg_into_iter!(blobs)
         .map(|bytes| bytes_to_blob(bytes))
         .collect::<Result<Vec<_>, _>>()?;
-    let commitments_g1 = cfg_into_iter!(commitments_g1)
-        .map(|bytes| TG1::from_bytes(bytes))
-        .collect::<Result<Vec<_>, _>>()?;
-    let proofs_g1 = cfg_into_iter!(proofs_g1)
-        .map(|bytes| TG1::from_bytes(bytes))
-        .collect::<Result<Vec<_>, _>>()?;
-
-    verify_blob_kzg_proof_batch_rust(&blobs, &commitments_g1, &proofs_g1, ts)
+    let commitments_g1 = validation
+        .g1_from_bytes_batch(commitments_g1)
+        .map_err(|err| format!("Invalid commitment: {err}"))?;
+    let proofs_g1 = validation
+        .g1_from_bytes_batch(proofs_g1)
+        .map_err(|err| format!("Invalid proof: {err}"))?;
+
+    // the points were checked while decoding
+    verify_blob_kzg_proof_batch::<Sha256Transcript, _, _, _, _, _, _, _, _, _>(
+        &blobs,
+        &commitments_g1,
+        &proofs_g1,
+        ts,
+        PointValidation::Trusted,
+        None,
+    )
 }
 
 pub fn bytes_to_blob<TFr: Fr>(bytes: &[u8]) -> Result<Vec<TFr>, String> {
@@ -918,30 +1752,27 @@ pub fn hash_to_bls_field<TFr: Fr>(x: &[u8; BYTES_PER_FIELD_ELEMENT]) -> TFr {
 }
 
 pub fn compute_challenge_rust<TFr: Fr, TG1: G1>(blob: &[TFr], commitment: &TG1) -> TFr {
-    let mut bytes: Vec<u8> = vec![0; CHALLENGE_INPUT_SIZE];
-
-    // Copy domain separator
-    bytes[..16].copy_from_slice(&FIAT_SHAMIR_PROTOCOL_DOMAIN);
-    // Set all other bytes of this 16-byte (big-endian) field to zero
-    bytes_of_uint64(&mut bytes[16..24], 0);
-    bytes_of_uint64(&mut bytes[24..32], FIELD_ELEMENTS_PER_BLOB as u64);
-
-    for (i, field) in blob.iter().enumerate() {
-        let v = field.to_bytes();
-        let size = (32 + i * BYTES_PER_FIELD_ELEMENT)..(32 + (i + 1) * BYTES_PER_FIELD_ELEMENT);
+    compute_challenge_with_transcript::<Sha256Transcript, _, _>(blob, commitment)
+}
 
-        bytes[size].copy_from_slice(&v);
+/// Like [`compute_challenge_rust`], with the challenge derived by the transcript `T`.
+pub fn compute_challenge_with_transcript<T: Transcript, TFr: Fr, TG1: G1>(
+    blob: &[TFr],
+    commitment: &TG1,
+) -> TFr {
+    let mut transcript = T::new(&FIAT_SHAMIR_PROTOCOL_DOMAIN);
+    // The polynomial degree as a 16-byte (big-endian) integer
+    transcript.absorb_u64(0);
+    transcript.absorb_u64(FIELD_ELEMENTS_PER_BLOB as u64);
+
+    for field in blob {
+        transcript.absorb_fr(field);
     }
 
-    // Copy commitment
-    let v = commitment.to_bytes();
-    for i in 0..v.len() {
-        bytes[32 + BYTES_PER_BLOB + i] = v[i];
-    }
+    transcript.absorb_g1(commitment);
 
     // Now let's create the challenge!
-    let eval_challenge = hash(&bytes);
-    hash_to_bls_field(&eval_challenge)
+    transcript.squeeze_fr()
 }
 
 pub fn blob_to_polynomial<TFr: Fr, TPoly: Poly<TFr>>(blob: &[TFr]) -> Result<TPoly, String> {
@@ -1084,3 +1915,14 @@ pub fn load_trusted_setup_rust<
         eth::FIELD_ELEMENTS_PER_CELL,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::eip_4844::load_trusted_setup_string;
+
+    #[test]
+    fn load_trusted_setup_string_rejects_non_ascii_hex_digits() {
+        assert!(load_trusted_setup_string("4096\n65\né0").is_err());
+        assert!(load_trusted_setup_string("4096\n65\n0é").is_err());
+    }
+}
@@ -0,0 +1,266 @@
+//! Reed-Solomon erasure coding of byte payloads of any length, independent of blobs.
+//!
+//! The payload is packed into field elements, 31 bytes each so that any bytes are canonical, and
+//! taken as the coefficients of a polynomial. Shards hold its evaluations over cosets of the FFT
+//! domain, laid out like the cells of an extended blob, so the polynomial and the payload are
+//! recovered from the evaluations of any `data_shards` shards.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::marker::PhantomData;
+
+use crate::{common_utils::reverse_bit_order, FFTFr, FFTSettings, Fr, Poly, PolyRecover, ZeroPoly};
+
+/// Payload bytes packed into each field element. The first byte of the big-endian encoding stays
+/// zero, which keeps every element below the modulus.
+pub const BYTES_PER_PACKED_ELEMENT: usize = 31;
+
+/// Size of a field element in a shard.
+pub const BYTES_PER_SHARD_ELEMENT: usize = 32;
+
+/// Shift of the coset the zero polynomial is divided out on, it is not a root of unity.
+const COSET_SHIFT: u64 = 5;
+
+/// How a payload is split into shards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShardLayout {
+    /// Length of the payload in bytes.
+    pub data_len: usize,
+    /// Number of shards any of which recover the payload.
+    pub data_shards: usize,
+    /// Number of shards the payload is encoded into.
+    pub total_shards: usize,
+    /// Field elements in each shard, a power of two.
+    pub field_elements_per_shard: usize,
+    /// Size of the evaluation domain, a power of two. Shards beyond `total_shards` fill it up but
+    /// are never produced.
+    pub domain_size: usize,
+}
+
+impl ShardLayout {
+    /// Picks the smallest shards that fit `data_len` bytes into `data_shards` shards.
+    pub fn new(data_len: usize, data_shards: usize, total_shards: usize) -> Result<Self, String> {
+        if data_shards == 0 || data_shards > total_shards {
+            return Err(format!(
+                "Invalid shard counts: {data_shards} data shards out of {total_shards}"
+            ));
+        }
+
+        let field_elements_per_shard = data_len
+            .div_ceil(BYTES_PER_PACKED_ELEMENT)
+            .div_ceil(data_shards)
+            .max(1)
+            .next_power_of_two();
+        let domain_size = total_shards
+            .checked_next_power_of_two()
+            .and_then(|shard_slots| shard_slots.checked_mul(field_elements_per_shard))
+            .ok_or_else(|| "Payload is too large".to_string())?;
+
+        Ok(Self {
+            data_len,
+            data_shards,
+            total_shards,
+            field_elements_per_shard,
+            domain_size,
+        })
+    }
+
+    /// Size of a shard in bytes.
+    pub fn bytes_per_shard(&self) -> usize {
+        self.field_elements_per_shard * BYTES_PER_SHARD_ELEMENT
+    }
+
+    /// Number of field elements the payload is packed into.
+    fn packed_len(&self) -> usize {
+        self.data_len.div_ceil(BYTES_PER_PACKED_ELEMENT)
+    }
+}
+
+/// Erasure coder for payloads with a given [`ShardLayout`].
+pub struct ErasureCoder<TFr, TPoly, TFFTSettings> {
+    layout: ShardLayout,
+    fs: TFFTSettings,
+    _marker: PhantomData<(TFr, TPoly)>,
+}
+
+impl<
+        TFr: Fr,
+        TPoly: Poly<TFr> + PolyRecover<TFr, TPoly, TFFTSettings>,
+        TFFTSettings: FFTSettings<TFr> + FFTFr<TFr> + ZeroPoly<TFr, TPoly>,
+    > ErasureCoder<TFr, TPoly, TFFTSettings>
+{
+    /// Creates a coder for payloads of `data_len` bytes, encoded into `total_shards` shards any
+    /// `data_shards` of which recover the payload. Any shard counts are allowed, the rate does not
+    /// need to be a power of two.
+    pub fn new(data_len: usize, data_shards: usize, total_shards: usize) -> Result<Self, String> {
+        let layout = ShardLayout::new(data_len, data_shards, total_shards)?;
+        let fs = TFFTSettings::new(layout.domain_size.trailing_zeros() as usize)?;
+
+        Ok(Self {
+            layout,
+            fs,
+            _marker: PhantomData,
+        })
+    }
+
+    /// The layout of the shards.
+    pub fn layout(&self) -> &ShardLayout {
+        &self.layout
+    }
+
+    /// Encodes the payload into `total_shards` shards of `bytes_per_shard` bytes.
+    pub fn encode(&self, data: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+        if data.len() != self.layout.data_len {
+            return Err(format!(
+                "Invalid data length, expected {} got {}",
+                self.layout.data_len,
+                data.len()
+            ));
+        }
+
+        let mut coeffs = data
+            .chunks(BYTES_PER_PACKED_ELEMENT)
+            .map(|chunk| {
+                let mut bytes = [0u8; BYTES_PER_SHARD_ELEMENT];
+                bytes[1..=chunk.len()].copy_from_slice(chunk);
+                TFr::from_bytes(&bytes)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        coeffs.resize(self.layout.domain_size, TFr::zero());
+
+        let mut evaluations = self.fs.fft_fr(&coeffs, false)?;
+        reverse_bit_order(&mut evaluations)?;
+
+        Ok(evaluations
+            .chunks(self.layout.field_elements_per_shard)
+            .take(self.layout.total_shards)
+            .map(|shard| shard.iter().flat_map(|value| value.to_bytes()).collect())
+            .collect())
+    }
+
+    /// Decodes the payload from at least `data_shards` distinct shards, `shards[i]` being shard
+    /// `shard_indices[i]`. Fails when the shards are not evaluations of the same payload.
+    pub fn decode(&self, shard_indices: &[usize], shards: &[Vec<u8>]) -> Result<Vec<u8>, String> {
+        let layout = &self.layout;
+        if shard_indices.len() != shards.len() {
+            return Err("Shard index and shard counts differ".to_string());
+        }
+        if shards.len() < layout.data_shards {
+            return Err(format!(
+                "Not enough shards, expected at least {} got {}",
+                layout.data_shards,
+                shards.len()
+            ));
+        }
+
+        let mut samples = vec![None; layout.domain_size];
+        for (&index, shard) in shard_indices.iter().zip(shards) {
+            if index >= layout.total_shards {
+                return Err(format!("Invalid shard index {index}"));
+            }
+            if shard.len() != layout.bytes_per_shard() {
+                return Err(format!("Invalid length of shard {index}"));
+            }
+
+            let start = index * layout.field_elements_per_shard;
+            if samples[start].is_some() {
+                return Err(format!("Shard index {index} appears twice"));
+            }
+            for (sample, bytes) in samples[start..]
+                .iter_mut()
+                .zip(shard.chunks(BYTES_PER_SHARD_ELEMENT))
+            {
+                *sample = Some(TFr::from_bytes(bytes)?);
+            }
+        }
+        reverse_bit_order(&mut samples)?;
+
+        // Beyond the payload all coefficients are zero, unless shards of different payloads
+        // were mixed up
+        let coeffs = self.recover_coeffs(&samples)?;
+        let packed_len = layout.packed_len();
+        if coeffs[packed_len..].iter().any(|coeff| !coeff.is_zero()) {
+            return Err("Shards are inconsistent".to_string());
+        }
+
+        let mut data = Vec::with_capacity(packed_len * BYTES_PER_PACKED_ELEMENT);
+        for coeff in &coeffs[..packed_len] {
+            let bytes = coeff.to_bytes();
+            if bytes[0] != 0 {
+                return Err("Shards are inconsistent".to_string());
+            }
+            data.extend_from_slice(&bytes[1..]);
+        }
+        data.truncate(layout.data_len);
+
+        Ok(data)
+    }
+
+    /// Interpolates the samples, in natural order, by dividing out the zero polynomial of the
+    /// missing ones.
+    fn recover_coeffs(&self, samples: &[Option<TFr>]) -> Result<Vec<TFr>, String> {
+        let missing = samples
+            .iter()
+            .enumerate()
+            .filter_map(|(i, sample)| sample.is_none().then_some(i))
+            .collect::<Vec<_>>();
+
+        if missing.is_empty() {
+            let evaluations = samples.iter().flatten().cloned().collect::<Vec<_>>();
+            return self.fs.fft_fr(&evaluations, true);
+        }
+
+        // PolyRecover handles up to half of the samples missing
+        if 2 * missing.len() <= samples.len() {
+            let poly = TPoly::recover_poly_coeffs_from_samples(samples, &self.fs)?;
+            return Ok(poly.get_coeffs().to_vec());
+        }
+
+        let (zero_eval, zero_poly) = self
+            .fs
+            .zero_poly_via_multiplication(samples.len(), &missing)?;
+
+        // (E * Z)(x) = (D * Z)(x), as D * Z has a degree below the domain size
+        let poly_evaluations_with_zero = samples
+            .iter()
+            .zip(&zero_eval)
+            .map(|(sample, zero_eval)| match sample {
+                Some(sample) => sample.mul(zero_eval),
+                None => TFr::zero(),
+            })
+            .collect::<Vec<_>>();
+        let mut poly_with_zero = self.fs.fft_fr(&poly_evaluations_with_zero, true)?;
+        let mut zero_poly = zero_poly.get_coeffs().to_vec();
+        zero_poly.resize(samples.len(), TFr::zero());
+
+        // Z has no roots on the coset, so the division is pointwise there
+        let shift = TFr::from_u64(COSET_SHIFT);
+        scale_by_powers(&mut poly_with_zero, &shift);
+        scale_by_powers(&mut zero_poly, &shift);
+        let quotient = self
+            .fs
+            .fft_fr(&poly_with_zero, false)?
+            .iter()
+            .zip(self.fs.fft_fr(&zero_poly, false)?)
+            .map(|(numerator, denominator)| numerator.div(&denominator))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut coeffs = self.fs.fft_fr(&quotient, true)?;
+        scale_by_powers(&mut coeffs, &shift.inverse());
+
+        Ok(coeffs)
+    }
+}
+
+/// Multiplies the `i`-th coefficient by `factor^i`, turning `p(x)` into `p(factor * x)`.
+fn scale_by_powers<TFr: Fr>(coeffs: &mut [TFr], factor: &TFr) {
+    let mut power = TFr::one();
+    for coeff in coeffs {
+        *coeff = coeff.mul(&power);
+        power = power.mul(factor);
+    }
+}
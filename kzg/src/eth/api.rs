@@ -0,0 +1,73 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::eth::{
+    eip_7594::CellsKzgProofs, BYTES_PER_BLOB, BYTES_PER_CELL, BYTES_PER_COMMITMENT,
+    BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
+};
+
+/// Byte-level EIP-4844 and EIP-7594 API, independent of the backend. Implemented by `DynKzg` of
+/// `rust-kzg-dyn` for every backend, and by the client of the local verification service.
+pub trait KzgApi: Send + Sync {
+    fn blob_to_kzg_commitment(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<[u8; BYTES_PER_COMMITMENT], String>;
+
+    fn compute_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        z: &[u8; BYTES_PER_FIELD_ELEMENT],
+    ) -> Result<([u8; BYTES_PER_PROOF], [u8; BYTES_PER_FIELD_ELEMENT]), String>;
+
+    fn compute_blob_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+    ) -> Result<[u8; BYTES_PER_PROOF], String>;
+
+    fn verify_kzg_proof(
+        &self,
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+        z: &[u8; BYTES_PER_FIELD_ELEMENT],
+        y: &[u8; BYTES_PER_FIELD_ELEMENT],
+        proof: &[u8; BYTES_PER_PROOF],
+    ) -> Result<bool, String>;
+
+    fn verify_blob_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+        proof: &[u8; BYTES_PER_PROOF],
+    ) -> Result<bool, String>;
+
+    fn verify_blob_kzg_proof_batch(
+        &self,
+        blobs: &[[u8; BYTES_PER_BLOB]],
+        commitments: &[[u8; BYTES_PER_COMMITMENT]],
+        proofs: &[[u8; BYTES_PER_PROOF]],
+    ) -> Result<bool, String>;
+
+    fn compute_cells(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<Vec<[u8; BYTES_PER_CELL]>, String>;
+
+    fn compute_cells_and_kzg_proofs(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<CellsKzgProofs, String>;
+
+    fn recover_cells_and_kzg_proofs(
+        &self,
+        cell_indices: &[usize],
+        cells: &[[u8; BYTES_PER_CELL]],
+    ) -> Result<CellsKzgProofs, String>;
+
+    fn verify_cell_kzg_proof_batch(
+        &self,
+        commitments: &[[u8; BYTES_PER_COMMITMENT]],
+        cell_indices: &[usize],
+        cells: &[[u8; BYTES_PER_CELL]],
+        proofs: &[[u8; BYTES_PER_PROOF]],
+    ) -> Result<bool, String>;
+}
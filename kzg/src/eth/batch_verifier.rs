@@ -0,0 +1,229 @@
+use alloc::{format, string::String, vec::Vec};
+use core::ops::Range;
+
+use crate::{
+    das::{EcBackend, DAS},
+    eip_4844::{
+        blob_to_polynomial, bytes_to_blob, compute_challenge_rust,
+        evaluate_polynomial_in_evaluation_form, verify_kzg_proof_batch,
+    },
+    eth::{
+        BYTES_PER_BLOB, BYTES_PER_CELL, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT,
+        BYTES_PER_PROOF,
+    },
+    transcript::Sha256Transcript,
+    Fr, KZGSettings, PointValidation, G1,
+};
+
+/// Accumulator for blob and cell proofs that arrive one by one, e.g. from gossip.
+///
+/// Every item is deserialized and checked (subgroup membership, cell index, blob evaluation) when
+/// it is added, so malformed items are rejected right away. [`BatchVerifier::finalize`] then runs
+/// one random linear combination pairing check for all blob proofs and one for all cell proofs,
+/// and bisects a failed batch to find the items with invalid proofs.
+///
+/// Items are numbered in the order they were accepted, starting from zero, across both kinds.
+pub struct BatchVerifier<'a, B: EcBackend> {
+    settings: &'a B::KZGSettings,
+    num_items: usize,
+    blobs: BlobOpenings<B>,
+    cells: CellProofs<B>,
+}
+
+/// Blob proofs, reduced to openings `(commitment, z, y, proof)` at the blob challenge.
+struct BlobOpenings<B: EcBackend> {
+    ids: Vec<usize>,
+    commitments: Vec<B::G1>,
+    zs: Vec<B::Fr>,
+    ys: Vec<B::Fr>,
+    proofs: Vec<B::G1>,
+}
+
+struct CellProofs<B: EcBackend> {
+    ids: Vec<usize>,
+    commitments: Vec<B::G1>,
+    cell_indices: Vec<usize>,
+    cells: Vec<B::Fr>,
+    proofs: Vec<B::G1>,
+}
+
+impl<'a, B: EcBackend> BatchVerifier<'a, B>
+where
+    B::Fr: Copy,
+{
+    pub fn new(settings: &'a B::KZGSettings) -> Self {
+        Self {
+            settings,
+            num_items: 0,
+            blobs: BlobOpenings {
+                ids: Vec::new(),
+                commitments: Vec::new(),
+                zs: Vec::new(),
+                ys: Vec::new(),
+                proofs: Vec::new(),
+            },
+            cells: CellProofs {
+                ids: Vec::new(),
+                commitments: Vec::new(),
+                cell_indices: Vec::new(),
+                cells: Vec::new(),
+                proofs: Vec::new(),
+            },
+        }
+    }
+
+    /// Number of accepted items.
+    pub fn len(&self) -> usize {
+        self.num_items
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num_items == 0
+    }
+
+    fn next_id(&mut self) -> usize {
+        self.num_items += 1;
+        self.num_items - 1
+    }
+
+    /// Adds the proof of a blob, returning the item number. Fails without adding the item if any
+    /// of the inputs is malformed.
+    pub fn add_blob(
+        &mut self,
+        blob: &[u8; BYTES_PER_BLOB],
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+        proof: &[u8; BYTES_PER_PROOF],
+    ) -> Result<usize, String> {
+        let blob = bytes_to_blob::<B::Fr>(blob)?;
+        let commitment = B::G1::from_bytes(commitment)?;
+        let proof = B::G1::from_bytes(proof)?;
+
+        if !PointValidation::Checked.is_valid_g1(&commitment) {
+            return Err("Invalid commitment".into());
+        }
+        if !PointValidation::Checked.is_valid_g1(&proof) {
+            return Err("Invalid proof".into());
+        }
+
+        let polynomial: B::Poly = blob_to_polynomial(&blob)?;
+        let z = compute_challenge_rust(&blob, &commitment);
+        let y = evaluate_polynomial_in_evaluation_form(&polynomial, &z, self.settings)?;
+
+        let id = self.next_id();
+        self.blobs.ids.push(id);
+        self.blobs.commitments.push(commitment);
+        self.blobs.zs.push(z);
+        self.blobs.ys.push(y);
+        self.blobs.proofs.push(proof);
+
+        Ok(id)
+    }
+
+    /// Adds the proof of a cell, returning the item number. Fails without adding the item if any
+    /// of the inputs is malformed.
+    pub fn add_cell(
+        &mut self,
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+        cell_index: usize,
+        cell: &[u8; BYTES_PER_CELL],
+        proof: &[u8; BYTES_PER_PROOF],
+    ) -> Result<usize, String> {
+        let cell_size = self.settings.get_cell_size();
+        let cell_count = self.settings.get_extension_factor()
+            * self.settings.get_g1_monomial().len()
+            / cell_size;
+        if cell_index >= cell_count {
+            return Err(format!("Invalid cell index {cell_index}"));
+        }
+
+        let commitment = B::G1::from_bytes(commitment)?;
+        let proof = B::G1::from_bytes(proof)?;
+        if !PointValidation::Checked.is_valid_g1(&commitment) {
+            return Err("Invalid commitment".into());
+        }
+        if !PointValidation::Checked.is_valid_g1(&proof) {
+            return Err("Invalid proof".into());
+        }
+
+        let cell = cell
+            .chunks(BYTES_PER_FIELD_ELEMENT)
+            .map(B::Fr::from_bytes)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let id = self.next_id();
+        self.cells.ids.push(id);
+        self.cells.commitments.push(commitment);
+        self.cells.cell_indices.push(cell_index);
+        self.cells.cells.extend(cell);
+        self.cells.proofs.push(proof);
+
+        Ok(id)
+    }
+
+    /// Verifies all accepted items, returning the numbers of the items with invalid proofs in
+    /// ascending order. An empty list means that every item is valid.
+    pub fn finalize(self) -> Vec<usize> {
+        let settings = self.settings;
+        let blobs = &self.blobs;
+        let cells = &self.cells;
+        let cell_size = settings.get_cell_size();
+
+        let mut failed = Vec::new();
+
+        failed_items(
+            0..blobs.ids.len(),
+            &|range: Range<usize>| {
+                verify_kzg_proof_batch::<Sha256Transcript, _, _, _, _, _, _, _, _, _>(
+                    &blobs.commitments[range.clone()],
+                    &blobs.zs[range.clone()],
+                    &blobs.ys[range.clone()],
+                    &blobs.proofs[range],
+                    settings,
+                    None,
+                ) == Ok(true)
+            },
+            &mut failed,
+        );
+        let mut failed = failed.into_iter().map(|i| blobs.ids[i]).collect::<Vec<_>>();
+
+        let mut failed_cells = Vec::new();
+        failed_items(
+            0..cells.ids.len(),
+            &|range: Range<usize>| {
+                <B::KZGSettings as DAS<B>>::verify_cell_kzg_proof_batch(
+                    settings,
+                    &cells.commitments[range.clone()],
+                    &cells.cell_indices[range.clone()],
+                    &cells.cells[range.start * cell_size..range.end * cell_size],
+                    &cells.proofs[range],
+                ) == Ok(true)
+            },
+            &mut failed_cells,
+        );
+        failed.extend(failed_cells.into_iter().map(|i| cells.ids[i]));
+
+        failed.sort_unstable();
+        failed
+    }
+}
+
+/// Collects the indices in `range` for which `verify` fails, by verifying the whole range and
+/// bisecting it on failure.
+fn failed_items(
+    range: Range<usize>,
+    verify: &dyn Fn(Range<usize>) -> bool,
+    failed: &mut Vec<usize>,
+) {
+    if range.is_empty() || verify(range.clone()) {
+        return;
+    }
+
+    if range.len() == 1 {
+        failed.push(range.start);
+        return;
+    }
+
+    let middle = range.start + range.len() / 2;
+    failed_items(range.start..middle, verify, failed);
+    failed_items(middle..range.end, verify, failed);
+}
@@ -112,6 +112,39 @@ pub struct Cell {
     pub bytes: [u8; BYTES_PER_CELL],
 }
 
+/// Byte containers are (de)serialized as is, like in c-kzg-4844 their contents are only validated
+/// when they are passed to a function. That includes commitments and proofs, which are checked
+/// when the backend decodes them.
+#[cfg(feature = "serde")]
+macro_rules! serde_bytes {
+    ($($name:ident),*) => {
+        $(
+            impl serde::Serialize for $name {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    crate::serialization::serialize_bytes(&self.bytes, serializer)
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for $name {
+                fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    crate::serialization::deserialize_bytes(deserializer).map(|bytes| Self { bytes })
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "serde")]
+serde_bytes!(
+    Bytes32,
+    Bytes48,
+    BLSFieldElement,
+    Blob,
+    KZGCommitment,
+    KZGProof,
+    Cell
+);
+
 unsafe fn deserialize_blob<B: EcBackend>(
     blob: *const Blob,
 ) -> core::result::Result<Vec<B::Fr>, CKzgRet> {
@@ -286,6 +319,46 @@ pub unsafe fn recover_cells_and_kzg_proofs<
     }
 }
 
+/// Like [`recover_cells_and_kzg_proofs`] with `recovered_proofs` set to null: only the cells are
+/// recovered.
+///
+/// # Safety
+pub unsafe fn recover_cells<
+    B: EcBackend,
+    D: DAS<B> + for<'a> TryFrom<&'a CKZGSettings, Error = String>,
+>(
+    recovered_cells: *mut Cell,
+    cell_indices: *const u64,
+    cells: *const Cell,
+    num_cells: u64,
+    s: *const CKZGSettings,
+) -> CKzgRet {
+    recover_cells_and_kzg_proofs::<B, D>(
+        recovered_cells,
+        core::ptr::null_mut(),
+        cell_indices,
+        cells,
+        num_cells,
+        s,
+    )
+}
+
+/// Writes the blob held by the first half of the `CELLS_PER_EXT_BLOB` cells of its extension.
+///
+/// # Safety
+pub unsafe fn cells_to_blob(blob: *mut Blob, cells: *const Cell) -> CKzgRet {
+    if blob.is_null() || cells.is_null() {
+        return CKzgRet::BadArgs;
+    }
+
+    let cells = core::slice::from_raw_parts(cells, CELLS_PER_EXT_BLOB);
+    for (chunk, cell) in (*blob).bytes.chunks_mut(BYTES_PER_CELL).zip(cells) {
+        chunk.copy_from_slice(&cell.bytes);
+    }
+
+    CKzgRet::Ok
+}
+
 /// # Safety
 pub unsafe fn verify_cell_kzg_proof_batch<
     B: EcBackend,
@@ -393,6 +466,30 @@ macro_rules! c_bindings_eip7594 {
             )
         }
 
+        /// # Safety
+        #[no_mangle]
+        pub unsafe extern "C" fn recover_cells(
+            recovered_cells: *mut kzg::eth::c_bindings::Cell,
+            cell_indices: *const u64,
+            cells: *const kzg::eth::c_bindings::Cell,
+            num_cells: u64,
+            s: *const kzg::eth::c_bindings::CKZGSettings,
+        ) -> kzg::eth::c_bindings::CKzgRet {
+            kzg::eth::c_bindings::recover_cells::<
+                $backend,
+                <$backend as kzg::EcBackend>::KZGSettings,
+            >(recovered_cells, cell_indices, cells, num_cells, s)
+        }
+
+        /// # Safety
+        #[no_mangle]
+        pub unsafe extern "C" fn cells_to_blob(
+            blob: *mut kzg::eth::c_bindings::Blob,
+            cells: *const kzg::eth::c_bindings::Cell,
+        ) -> kzg::eth::c_bindings::CKzgRet {
+            kzg::eth::c_bindings::cells_to_blob(blob, cells)
+        }
+
         /// # Safety
         #[no_mangle]
         pub unsafe extern "C" fn verify_cell_kzg_proof_batch(
@@ -0,0 +1,105 @@
+//! Custody group and column assignment of PeerDAS, as defined in the Fulu consensus specs
+//! (`das-core.md`). Every node custodies the columns of the groups derived from its node ID.
+
+use alloc::{format, string::String, vec::Vec};
+use sha2::{Digest, Sha256};
+
+use crate::eth::CELLS_PER_EXT_BLOB;
+
+/// Number of columns of the extended blob matrix, one per cell of an extended blob.
+pub const NUMBER_OF_COLUMNS: usize = CELLS_PER_EXT_BLOB;
+/// Number of custody groups the columns are split into, each custodied as a whole.
+pub const NUMBER_OF_CUSTODY_GROUPS: usize = 128;
+
+const _: () = assert!(NUMBER_OF_COLUMNS.is_multiple_of(NUMBER_OF_CUSTODY_GROUPS));
+
+/// Returns the sorted custody groups of a node, `get_custody_groups` of the specs. `node_id` is
+/// the 256-bit node ID in big-endian bytes, like the raw bytes of a discv5 node ID.
+pub fn get_custody_groups(
+    node_id: &[u8; 32],
+    custody_group_count: u64,
+) -> Result<Vec<u64>, String> {
+    if custody_group_count > NUMBER_OF_CUSTODY_GROUPS as u64 {
+        return Err(format!(
+            "Custody group count {custody_group_count} exceeds {NUMBER_OF_CUSTODY_GROUPS}"
+        ));
+    }
+
+    // Skip computation if all groups are custodied
+    if custody_group_count == NUMBER_OF_CUSTODY_GROUPS as u64 {
+        return Ok((0..NUMBER_OF_CUSTODY_GROUPS as u64).collect());
+    }
+
+    let mut current_id = *node_id;
+    let mut custody_groups = Vec::with_capacity(custody_group_count as usize);
+    while (custody_groups.len() as u64) < custody_group_count {
+        // The specs hash the little-endian encoding of the uint256
+        let mut id_bytes = current_id;
+        id_bytes.reverse();
+        let hash = Sha256::digest(id_bytes);
+        let custody_group =
+            u64::from_le_bytes(hash[..8].try_into().unwrap()) % NUMBER_OF_CUSTODY_GROUPS as u64;
+
+        if !custody_groups.contains(&custody_group) {
+            custody_groups.push(custody_group);
+        }
+
+        // Wraps around to zero after the largest node ID
+        for byte in current_id.iter_mut().rev() {
+            let (incremented, overflow) = byte.overflowing_add(1);
+            *byte = incremented;
+            if !overflow {
+                break;
+            }
+        }
+    }
+
+    custody_groups.sort_unstable();
+    Ok(custody_groups)
+}
+
+/// Returns the columns of a custody group, `compute_columns_for_custody_group` of the specs.
+pub fn compute_columns_for_custody_group(custody_group: u64) -> Result<Vec<u64>, String> {
+    if custody_group >= NUMBER_OF_CUSTODY_GROUPS as u64 {
+        return Err(format!("Invalid custody group {custody_group}"));
+    }
+
+    let columns_per_group = NUMBER_OF_COLUMNS / NUMBER_OF_CUSTODY_GROUPS;
+    Ok((0..columns_per_group as u64)
+        .map(|i| NUMBER_OF_CUSTODY_GROUPS as u64 * i + custody_group)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use super::{
+        compute_columns_for_custody_group, get_custody_groups, NUMBER_OF_COLUMNS,
+        NUMBER_OF_CUSTODY_GROUPS,
+    };
+
+    // The test vectors are run by kzg-bench, these only cover the argument checks
+    #[test]
+    fn get_custody_groups_checks_count() {
+        let zero = [0; 32];
+        assert!(get_custody_groups(&zero, 0).unwrap().is_empty());
+        assert_eq!(
+            get_custody_groups(&zero, NUMBER_OF_CUSTODY_GROUPS as u64).unwrap(),
+            (0..NUMBER_OF_CUSTODY_GROUPS as u64).collect::<Vec<_>>()
+        );
+        assert!(get_custody_groups(&zero, NUMBER_OF_CUSTODY_GROUPS as u64 + 1).is_err());
+    }
+
+    #[test]
+    fn custody_groups_cover_all_columns() {
+        let mut columns = (0..NUMBER_OF_CUSTODY_GROUPS as u64)
+            .flat_map(|group| compute_columns_for_custody_group(group).unwrap())
+            .collect::<Vec<_>>();
+        columns.sort_unstable();
+        assert_eq!(columns, (0..NUMBER_OF_COLUMNS as u64).collect::<Vec<_>>());
+
+        assert_eq!(compute_columns_for_custody_group(5).unwrap(), vec![5]);
+        assert!(compute_columns_for_custody_group(NUMBER_OF_CUSTODY_GROUPS as u64).is_err());
+    }
+}
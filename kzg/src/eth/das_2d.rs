@@ -0,0 +1,301 @@
+//! Two-dimensional erasure coding of the blobs of a block. Besides every blob being extended into
+//! a row of cells, the rows themselves are Reed-Solomon extended from `N` blobs to `2N` rows.
+//!
+//! Commitments, cells and proofs are all linear in the blob, so the extension over the rows is the
+//! same for each of them: extended row `j` is the blob committed to by extended commitment `j`, and
+//! its cells verify against it like the cells of any blob. Rows are ordered like the cells of a
+//! blob, in bit-reversed order of their evaluation domain, so the first `N` rows are the blobs.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    common_utils::reverse_bit_order,
+    das::{EcBackend, DAS},
+    FFTFr, FFTSettings, Fr, G1LinComb, KZGSettings, FFTG1, G1,
+};
+
+/// Recovered cells and their proofs.
+pub type CellsAndProofs<B> = (Vec<<B as EcBackend>::Fr>, Vec<<B as EcBackend>::G1>);
+
+/// The cells of `2N` rows: `N` blobs and their extension, each split into cells.
+pub struct CellMatrix<B: EcBackend> {
+    /// Commitment of every row, the first `N` are the commitments of the blobs.
+    pub commitments: Vec<B::G1>,
+    /// Cells of every row, concatenated like the cells of [`DAS::compute_cells_and_kzg_proofs`].
+    pub cells: Vec<Vec<B::Fr>>,
+    /// Cell proofs of every row.
+    pub proofs: Vec<Vec<B::G1>>,
+}
+
+impl<B: EcBackend> Clone for CellMatrix<B> {
+    fn clone(&self) -> Self {
+        Self {
+            commitments: self.commitments.clone(),
+            cells: self.cells.clone(),
+            proofs: self.proofs.clone(),
+        }
+    }
+}
+
+/// Reed-Solomon extends the commitments of `N` blobs to the commitments of `2N` rows. The first
+/// `N` extended commitments are the given ones.
+pub fn extend_commitments<B: EcBackend>(
+    commitments: &[B::G1],
+    das: &impl DAS<B>,
+) -> Result<Vec<B::G1>, String> {
+    let fft_settings = das.kzg_settings().get_fft_settings();
+    check_blob_count::<B>(commitments.len(), fft_settings)?;
+
+    let mut coeffs = commitments.to_vec();
+    reverse_bit_order(&mut coeffs)?;
+    let mut coeffs = fft_settings.fft_g1(&coeffs, true)?;
+    coeffs.resize(2 * commitments.len(), B::G1::identity());
+
+    let mut extended = fft_settings.fft_g1(&coeffs, false)?;
+    reverse_bit_order(&mut extended)?;
+
+    Ok(extended)
+}
+
+/// Computes the commitments, cells and proofs of all `2N` rows of the given `N` blobs.
+///
+/// FK20 only runs for the blobs; the cells and proofs of the extension rows are interpolated from
+/// them column by column.
+pub fn compute_cell_matrix<B: EcBackend>(
+    blobs: &[Vec<B::Fr>],
+    das: &impl DAS<B>,
+) -> Result<CellMatrix<B>, String> {
+    let settings = das.kzg_settings();
+    let fft_settings = settings.get_fft_settings();
+    check_blob_count::<B>(blobs.len(), fft_settings)?;
+
+    let field_elements_per_blob = settings.get_g1_monomial().len();
+    let field_elements_per_ext_blob = field_elements_per_blob * settings.get_extension_factor();
+    let cells_per_ext_blob = field_elements_per_ext_blob / settings.get_cell_size();
+
+    let mut commitments = Vec::with_capacity(blobs.len());
+    let mut cells = Vec::with_capacity(2 * blobs.len());
+    let mut proofs = Vec::with_capacity(2 * blobs.len());
+    for blob in blobs {
+        if blob.len() != field_elements_per_blob {
+            return Err("Invalid blob length".to_string());
+        }
+
+        commitments.push(B::G1::g1_lincomb(
+            settings.get_g1_lagrange_brp(),
+            blob,
+            field_elements_per_blob,
+            settings.get_precomputation(),
+        ));
+
+        let mut row_cells = vec![B::Fr::zero(); field_elements_per_ext_blob];
+        let mut row_proofs = vec![B::G1::identity(); cells_per_ext_blob];
+        das.compute_cells_and_kzg_proofs(Some(&mut row_cells), Some(&mut row_proofs), blob)?;
+        cells.push(row_cells);
+        proofs.push(row_proofs);
+    }
+
+    cells.resize(
+        2 * blobs.len(),
+        vec![B::Fr::zero(); field_elements_per_ext_blob],
+    );
+    for i in 0..field_elements_per_ext_blob {
+        let column = cells[..blobs.len()]
+            .iter()
+            .map(|row| row[i].clone())
+            .collect::<Vec<_>>();
+        let extended = extend_column_fr::<B>(&column, fft_settings)?;
+        for (row, value) in cells.iter_mut().zip(extended).skip(blobs.len()) {
+            row[i] = value;
+        }
+    }
+
+    proofs.resize(2 * blobs.len(), vec![B::G1::identity(); cells_per_ext_blob]);
+    for i in 0..cells_per_ext_blob {
+        let column = proofs[..blobs.len()]
+            .iter()
+            .map(|row| row[i].clone())
+            .collect::<Vec<_>>();
+        let extended = extend_commitments::<B>(&column, das)?;
+        for (row, proof) in proofs.iter_mut().zip(extended).skip(blobs.len()) {
+            row[i] = proof;
+        }
+    }
+
+    Ok(CellMatrix {
+        commitments: extend_commitments::<B>(&commitments, das)?,
+        cells,
+        proofs,
+    })
+}
+
+/// Recovers all cells and proofs of a row from at least half of its cells. Any row of the matrix
+/// is recovered like a blob.
+pub fn recover_row<B: EcBackend>(
+    cell_indices: &[usize],
+    cells: &[B::Fr],
+    das: &impl DAS<B>,
+) -> Result<CellsAndProofs<B>, String> {
+    let settings = das.kzg_settings();
+    let field_elements_per_ext_blob =
+        settings.get_g1_monomial().len() * settings.get_extension_factor();
+
+    let mut recovered_cells = vec![B::Fr::zero(); field_elements_per_ext_blob];
+    let mut recovered_proofs =
+        vec![B::G1::identity(); field_elements_per_ext_blob / settings.get_cell_size()];
+    das.recover_cells_and_kzg_proofs(
+        &mut recovered_cells,
+        Some(&mut recovered_proofs),
+        cell_indices,
+        cells,
+    )?;
+
+    Ok((recovered_cells, recovered_proofs))
+}
+
+/// Recovers one column of the matrix, the cells with the same index in all `2N` rows, from the
+/// cells of at least `blob_count` rows. The cells and proofs are ordered like `row_indices` and
+/// the recovered ones by row.
+pub fn recover_column<B: EcBackend>(
+    row_indices: &[usize],
+    cells: &[B::Fr],
+    proofs: &[B::G1],
+    blob_count: usize,
+    das: &impl DAS<B>,
+) -> Result<CellsAndProofs<B>, String> {
+    let settings = das.kzg_settings();
+    let fft_settings = settings.get_fft_settings();
+    let cell_size = settings.get_cell_size();
+    check_blob_count::<B>(blob_count, fft_settings)?;
+    let row_count = 2 * blob_count;
+
+    if cells.len() != row_indices.len() * cell_size || proofs.len() != row_indices.len() {
+        return Err("Row, cell and proof counts differ".to_string());
+    }
+    if row_indices.len() < blob_count {
+        return Err(format!(
+            "Not enough cells to recover the column, expected at least {blob_count}, got {}",
+            row_indices.len()
+        ));
+    }
+
+    let mut provided = vec![false; row_count];
+    for &row in row_indices {
+        if row >= row_count {
+            return Err(format!("Invalid row index {row}"));
+        }
+        if provided[row] {
+            return Err(format!("Row index {row} appears twice"));
+        }
+        provided[row] = true;
+    }
+
+    let mut recovered_cells = vec![B::Fr::zero(); row_count * cell_size];
+    let mut recovered_proofs = vec![B::G1::identity(); row_count];
+    for (i, &row) in row_indices.iter().enumerate() {
+        recovered_cells[row * cell_size..(row + 1) * cell_size]
+            .clone_from_slice(&cells[i * cell_size..(i + 1) * cell_size]);
+        recovered_proofs[row] = proofs[i].clone();
+    }
+
+    // Any blob_count rows determine the column, the missing ones are interpolated from the first
+    let xs = row_indices[..blob_count]
+        .iter()
+        .map(|&row| fft_settings.get_brp_roots_of_unity_at(row))
+        .collect::<Vec<_>>();
+    for row in (0..row_count).filter(|&row| !provided[row]) {
+        let weights = lagrange_weights::<B>(&xs, &fft_settings.get_brp_roots_of_unity_at(row));
+
+        for j in 0..cell_size {
+            recovered_cells[row * cell_size + j] = weights
+                .iter()
+                .enumerate()
+                .fold(B::Fr::zero(), |acc, (i, weight)| {
+                    acc.add(&weight.mul(&cells[i * cell_size + j]))
+                });
+        }
+        recovered_proofs[row] =
+            B::G1::g1_lincomb(&proofs[..blob_count], &weights, blob_count, None);
+    }
+
+    Ok((recovered_cells, recovered_proofs))
+}
+
+/// Verifies cells of the matrix against the commitments of the `N` blobs: the cell at position
+/// `i` is cell `cell_indices[i]` of row `row_indices[i]`, and is checked against the extended
+/// commitment of that row.
+pub fn verify_cell_matrix_cells<B: EcBackend>(
+    commitments: &[B::G1],
+    row_indices: &[usize],
+    cell_indices: &[usize],
+    cells: &[B::Fr],
+    proofs: &[B::G1],
+    das: &impl DAS<B>,
+) -> Result<bool, String> {
+    if row_indices.len() != cell_indices.len() {
+        return Err("Row and cell index counts differ".to_string());
+    }
+
+    let extended_commitments = extend_commitments::<B>(commitments, das)?;
+    let row_commitments = row_indices
+        .iter()
+        .map(|&row| {
+            extended_commitments
+                .get(row)
+                .cloned()
+                .ok_or_else(|| format!("Invalid row index {row}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    das.verify_cell_kzg_proof_batch(&row_commitments, cell_indices, cells, proofs)
+}
+
+fn check_blob_count<B: EcBackend>(
+    blob_count: usize,
+    fft_settings: &B::FFTSettings,
+) -> Result<(), String> {
+    if !blob_count.is_power_of_two() || 2 * blob_count > fft_settings.get_max_width() {
+        return Err(format!(
+            "Invalid blob count {blob_count}: must be a power of two, at most half of the FFT width"
+        ));
+    }
+
+    Ok(())
+}
+
+fn extend_column_fr<B: EcBackend>(
+    column: &[B::Fr],
+    fft_settings: &B::FFTSettings,
+) -> Result<Vec<B::Fr>, String> {
+    let mut coeffs = column.to_vec();
+    reverse_bit_order(&mut coeffs)?;
+    let mut coeffs = fft_settings.fft_fr(&coeffs, true)?;
+    coeffs.resize(2 * column.len(), B::Fr::zero());
+
+    let mut extended = fft_settings.fft_fr(&coeffs, false)?;
+    reverse_bit_order(&mut extended)?;
+
+    Ok(extended)
+}
+
+/// The Lagrange basis polynomials of the points `xs`, evaluated at `x`.
+fn lagrange_weights<B: EcBackend>(xs: &[B::Fr], x: &B::Fr) -> Vec<B::Fr> {
+    xs.iter()
+        .enumerate()
+        .map(|(i, x_i)| {
+            let (numerator, denominator) = xs.iter().enumerate().filter(|&(m, _)| m != i).fold(
+                (B::Fr::one(), B::Fr::one()),
+                |(numerator, denominator), (_, x_m)| {
+                    (numerator.mul(&x.sub(x_m)), denominator.mul(&x_i.sub(x_m)))
+                },
+            );
+
+            numerator.mul(&denominator.inverse())
+        })
+        .collect()
+}
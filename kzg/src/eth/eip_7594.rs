@@ -4,14 +4,15 @@ use rayon::prelude::*;
 use alloc::{format, string::String, vec::Vec};
 
 use crate::{
-    cfg_chunks, cfg_iter,
+    cfg_chunks,
     das::{EcBackend, DAS},
     eip_4844::bytes_to_blob,
     eth::{
         BYTES_PER_BLOB, BYTES_PER_CELL, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT,
         BYTES_PER_PROOF, CELLS_PER_EXT_BLOB, FIELD_ELEMENTS_PER_CELL, FIELD_ELEMENTS_PER_EXT_BLOB,
     },
-    Fr, G1,
+    reed_solomon::recover_cells_correcting_errors,
+    Fr, PointValidation, G1,
 };
 
 pub type CellsKzgProofs = (Vec<[u8; BYTES_PER_CELL]>, Vec<[u8; BYTES_PER_PROOF]>);
@@ -25,17 +26,7 @@ where
     B::G1: Copy,
     B::Fr: Copy,
 {
-    let cells = cfg_chunks!(cells.as_flattened(), BYTES_PER_FIELD_ELEMENT)
-        .enumerate()
-        .map(|(index, bytes)| {
-            B::Fr::from_bytes(bytes)
-                .map_err(|e| {
-                    let start = index * BYTES_PER_FIELD_ELEMENT;
-                    let end = start + bytes.len();
-                    format!("Failed to deserialize field element with index {index} (bytes [{start}; {end})) with error: {e}")
-                })
-        })
-        .collect::<Result<Vec<_>, _>>()?;
+    let cells = cells_bytes_to_cells_elements::<B>(cells)?;
 
     let mut recovered_cells = [B::Fr::default(); FIELD_ELEMENTS_PER_EXT_BLOB];
     let mut recovered_proofs = [B::G1::default(); CELLS_PER_EXT_BLOB];
@@ -57,6 +48,66 @@ where
     Ok((converted_cells, converted_proofs))
 }
 
+/// Like [`recover_cells_and_kzg_proofs_raw`], but skips computing the proofs, which is most of the
+/// work.
+pub fn recover_cells_raw<B: EcBackend>(
+    cell_indices: &[usize],
+    cells: &[[u8; BYTES_PER_CELL]],
+    das: &impl DAS<B>,
+) -> Result<Vec<[u8; BYTES_PER_CELL]>, String>
+where
+    B::Fr: Copy,
+{
+    let cells = cells_bytes_to_cells_elements::<B>(cells)?;
+
+    let mut recovered_cells = [B::Fr::default(); FIELD_ELEMENTS_PER_EXT_BLOB];
+
+    das.recover_cells_and_kzg_proofs(&mut recovered_cells, None, cell_indices, &cells)
+        .map_err(|err| format!("Cell recovery failed with error: {err}"))?;
+
+    cells_elements_to_cells_bytes::<B>(&recovered_cells)
+}
+
+/// Like [`recover_cells_raw`], but tolerates wrong cells, see
+/// [`crate::reed_solomon::recover_cells_correcting_errors`]. Also returns the indices of the
+/// provided cells that were wrong.
+pub fn recover_cells_correcting_errors_raw<B: EcBackend>(
+    cell_indices: &[usize],
+    cells: &[[u8; BYTES_PER_CELL]],
+    das: &impl DAS<B>,
+) -> Result<(Vec<[u8; BYTES_PER_CELL]>, Vec<usize>), String>
+where
+    B::Fr: Copy,
+{
+    let cells = cells_bytes_to_cells_elements::<B>(cells)?;
+
+    let (recovered_cells, wrong_cells) = recover_cells_correcting_errors(cell_indices, &cells, das)
+        .map_err(|err| format!("Cell recovery failed with error: {err}"))?;
+
+    Ok((
+        cells_elements_to_cells_bytes::<B>(&recovered_cells)?,
+        wrong_cells,
+    ))
+}
+
+/// Rebuilds the blob from all cells of its extension, e.g. the output of [`recover_cells_raw`].
+///
+/// The first half of the cells holds the blob itself, the second half is not checked.
+pub fn cells_to_blob(cells: &[[u8; BYTES_PER_CELL]]) -> Result<[u8; BYTES_PER_BLOB], String> {
+    if cells.len() != CELLS_PER_EXT_BLOB {
+        return Err(format!(
+            "Invalid cells length. Expected {} got {}",
+            CELLS_PER_EXT_BLOB,
+            cells.len(),
+        ));
+    }
+
+    let mut blob = [0u8; BYTES_PER_BLOB];
+    blob.copy_from_slice(cells[..CELLS_PER_EXT_BLOB / 2].as_flattened());
+
+    Ok(blob)
+}
+
 pub fn compute_cells_and_kzg_proofs_raw<B: EcBackend>(
     blob: [u8; BYTES_PER_BLOB],
     das: &impl DAS<B>,
@@ -102,6 +153,20 @@ where
     Ok(converted_cells)
 }
 
+pub fn compute_cell_kzg_proofs_raw<B: EcBackend>(
+    blob: [u8; BYTES_PER_BLOB],
+    cell_indices: &[usize],
+    das: &impl DAS<B>,
+) -> Result<Vec<[u8; BYTES_PER_PROOF]>, String> {
+    let blob = bytes_to_blob(&blob)?;
+
+    Ok(das
+        .compute_cell_kzg_proofs(&blob, cell_indices)?
+        .iter()
+        .map(|proof| proof.to_bytes())
+        .collect())
+}
+
 pub fn verify_cell_kzg_proof_batch_raw<B: EcBackend>(
     commitments: &[[u8; BYTES_PER_COMMITMENT]],
     cell_indices: &[usize],
@@ -109,10 +174,28 @@ pub fn verify_cell_kzg_proof_batch_raw<B: EcBackend>(
     proofs: &[[u8; BYTES_PER_PROOF]],
     das: &impl DAS<B>,
 ) -> Result<bool, String> {
-    let commitments = cfg_iter!(commitments)
-        .enumerate()
-        .map(|(index, commitment)| B::G1::from_bytes(commitment).map_err(|err| format!("Failed to deserialize commitment at index {index}, commitment 0x{commitment}: {err}", commitment = hex::encode(commitment))))
-        .collect::<Result<Vec<_>, _>>()?;
+    verify_cell_kzg_proof_batch_raw_with_validation(
+        commitments,
+        cell_indices,
+        cells,
+        proofs,
+        das,
+        PointValidation::Checked,
+    )
+}
+
+/// Like [`verify_cell_kzg_proof_batch_raw`], with the checks of the points picked by `validation`.
+pub fn verify_cell_kzg_proof_batch_raw_with_validation<B: EcBackend>(
+    commitments: &[[u8; BYTES_PER_COMMITMENT]],
+    cell_indices: &[usize],
+    cells: &[[u8; BYTES_PER_CELL]],
+    proofs: &[[u8; BYTES_PER_PROOF]],
+    das: &impl DAS<B>,
+    validation: PointValidation,
+) -> Result<bool, String> {
+    let commitments = validation
+        .g1_from_bytes_batch::<B::G1>(commitments)
+        .map_err(|err| format!("Failed to deserialize commitments: {err}"))?;
 
     let cells = cfg_chunks!(cells.as_flattened(), BYTES_PER_FIELD_ELEMENT)
         .enumerate()
@@ -123,19 +206,34 @@ pub fn verify_cell_kzg_proof_batch_raw<B: EcBackend>(
         }))
         .collect::<Result<Vec<_>, _>>()?;
 
-    let proofs = cfg_iter!(proofs)
+    let proofs = validation
+        .g1_from_bytes_batch::<B::G1>(proofs)
+        .map_err(|err| format!("Failed to deserialize proofs: {err}"))?;
+
+    // the points were checked while decoding
+    das.verify_cell_kzg_proof_batch_with_validation(
+        &commitments,
+        cell_indices,
+        &cells,
+        &proofs,
+        PointValidation::Trusted,
+    )
+}
+
+fn cells_bytes_to_cells_elements<B: EcBackend>(
+    cells: &[[u8; BYTES_PER_CELL]],
+) -> Result<Vec<B::Fr>, String> {
+    cfg_chunks!(cells.as_flattened(), BYTES_PER_FIELD_ELEMENT)
         .enumerate()
-        .map(|(index, proof)| {
-            B::G1::from_bytes(proof).map_err(|err| {
-                format!(
-                    "Failed to deserialize proof at index {index}, proof {proof}: {err}",
-                    proof = hex::encode(proof)
-                )
-            })
+        .map(|(index, bytes)| {
+            B::Fr::from_bytes(bytes)
+                .map_err(|e| {
+                    let start = index * BYTES_PER_FIELD_ELEMENT;
+                    let end = start + bytes.len();
+                    format!("Failed to deserialize field element with index {index} (bytes [{start}; {end})) with error: {e}")
+                })
         })
-        .collect::<Result<Vec<_>, _>>()?;
-
-    das.verify_cell_kzg_proof_batch(&commitments, cell_indices, &cells, &proofs)
+        .collect()
 }
 
 fn cells_elements_to_cells_bytes<B: EcBackend>(
@@ -1,5 +1,15 @@
+mod api;
+pub mod batch_verifier;
 pub mod c_bindings;
+pub mod custody;
+pub mod das_2d;
 pub mod eip_7594;
+pub mod point_proofs;
+#[cfg(feature = "rand")]
+pub mod sampling;
+pub mod sidecar;
+
+pub use api::KzgApi;
 
 pub const FIELD_ELEMENTS_PER_BLOB: usize = 4096;
 pub const BYTES_PER_G1: usize = 48;
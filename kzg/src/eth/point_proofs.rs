@@ -0,0 +1,171 @@
+//! KZG proofs for every field element of a blob, computed at once with FK20.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    cfg_iter,
+    common_utils::reverse_bit_order,
+    das::{poly_lagrange_to_monomial, toeplitz_coeffs_stride, EcBackend, DAS},
+    eip_4844::{bytes_to_blob, verify_kzg_proof_batch_rust},
+    eth::{BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_PROOF},
+    FFTFr, FFTSettings, Fr, G1Mul, KZGSettings, PointValidation, FFTG1, G1,
+};
+
+/// Trusted setup and FK20 table used by [`Self::compute_all_blob_point_proofs`].
+///
+/// The table is a G1 FFT of twice the blob size over the monomial setup, so it is built once
+/// and reused for every blob. The trusted setup is borrowed from the settings it was built from.
+pub struct BlobPointProofSettings<'a, B: EcBackend> {
+    kzg_settings: &'a B::KZGSettings,
+    x_ext_fft: Vec<B::G1>,
+}
+
+impl<'a, B: EcBackend> BlobPointProofSettings<'a, B> {
+    pub fn new(das: &'a impl DAS<B>) -> Result<Self, String> {
+        let kzg_settings = das.kzg_settings();
+        let g1_monomial = kzg_settings.get_g1_monomial();
+        let n = g1_monomial.len();
+        if n < 2 || !n.is_power_of_two() {
+            return Err("Invalid trusted setup size".to_string());
+        }
+
+        // [s^(n - 2)], ..., [s^0] and the identity, padded with the identity to 2n points
+        let mut x_ext = vec![B::G1::identity(); 2 * n];
+        for (x, point) in x_ext.iter_mut().zip(g1_monomial[..n - 1].iter().rev()) {
+            *x = point.clone();
+        }
+
+        let x_ext_fft = kzg_settings.get_fft_settings().fft_g1(&x_ext, false)?;
+
+        Ok(Self {
+            kzg_settings,
+            x_ext_fft,
+        })
+    }
+
+    pub fn kzg_settings(&self) -> &'a B::KZGSettings {
+        self.kzg_settings
+    }
+
+    /// Computes the proofs of the evaluations of the blob polynomial at every point of its domain.
+    ///
+    /// The proofs are in the bit-reversed order of the domain, so `proofs[i]` opens the
+    /// polynomial at `get_brp_roots_of_unity_at(i)` to `blob[i]`.
+    pub fn compute_all_blob_point_proofs(&self, blob: &[B::Fr]) -> Result<Vec<B::G1>, String> {
+        let n = self.x_ext_fft.len() / 2;
+        if blob.len() != n {
+            return Err(format!(
+                "Invalid blob length, expected {n} field elements, got {}",
+                blob.len()
+            ));
+        }
+
+        let fft_settings = self.kzg_settings.get_fft_settings();
+
+        let mut poly = blob.to_vec();
+        poly_lagrange_to_monomial::<B>(&mut poly, fft_settings)?;
+
+        let mut toeplitz_coeffs = vec![B::Fr::zero(); 2 * n];
+        toeplitz_coeffs_stride::<B>(&mut toeplitz_coeffs, &poly, n, 0, 1)?;
+        let toeplitz_coeffs_fft = fft_settings.fft_fr(&toeplitz_coeffs, false)?;
+
+        let h_ext_fft = cfg_iter!(self.x_ext_fft)
+            .zip(cfg_iter!(toeplitz_coeffs_fft))
+            .map(|(x, coeff)| x.mul(coeff))
+            .collect::<Vec<_>>();
+
+        // Only the lower half of h is used; its evaluations over the blob domain are the proofs
+        let mut h = fft_settings.fft_g1(&h_ext_fft, true)?;
+        h.truncate(n);
+
+        let mut proofs = fft_settings.fft_g1(&h, false)?;
+        reverse_bit_order(&mut proofs)?;
+
+        Ok(proofs)
+    }
+}
+
+impl<B: EcBackend> Clone for BlobPointProofSettings<'_, B> {
+    fn clone(&self) -> Self {
+        Self {
+            kzg_settings: self.kzg_settings,
+            x_ext_fft: self.x_ext_fft.clone(),
+        }
+    }
+}
+
+pub fn compute_all_blob_point_proofs_raw<B: EcBackend>(
+    blob: [u8; BYTES_PER_BLOB],
+    settings: &BlobPointProofSettings<'_, B>,
+) -> Result<Vec<[u8; BYTES_PER_PROOF]>, String> {
+    let blob = bytes_to_blob(&blob)?;
+
+    Ok(settings
+        .compute_all_blob_point_proofs(&blob)?
+        .iter()
+        .map(|proof| proof.to_bytes())
+        .collect())
+}
+
+/// Verifies the proofs made by [`BlobPointProofSettings::compute_all_blob_point_proofs`] with a
+/// single pairing check.
+pub fn verify_all_blob_point_proofs<B: EcBackend>(
+    commitment: &B::G1,
+    blob: &[B::Fr],
+    proofs: &[B::G1],
+    das: &impl DAS<B>,
+) -> Result<bool, String> {
+    let kzg_settings = das.kzg_settings();
+    let n = kzg_settings.get_g1_monomial().len();
+    if blob.len() != n {
+        return Err(format!(
+            "Invalid blob length, expected {n} field elements, got {}",
+            blob.len()
+        ));
+    }
+    if proofs.len() != n {
+        return Err(format!(
+            "Invalid amount of proofs, expected {n}, got {}",
+            proofs.len()
+        ));
+    }
+
+    let zs = &kzg_settings.get_fft_settings().get_brp_roots_of_unity()[..n];
+    let commitments = vec![commitment.clone(); n];
+
+    verify_kzg_proof_batch_rust::<
+        B::Fr,
+        B::G1,
+        B::G2,
+        B::FFTSettings,
+        B::Poly,
+        B::KZGSettings,
+        B::G1Fp,
+        B::G1Affine,
+        B::G1ProjAddAffine,
+    >(&commitments, zs, blob, proofs, kzg_settings)
+}
+
+pub fn verify_all_blob_point_proofs_raw<B: EcBackend>(
+    commitment: [u8; BYTES_PER_COMMITMENT],
+    blob: [u8; BYTES_PER_BLOB],
+    proofs: &[[u8; BYTES_PER_PROOF]],
+    das: &impl DAS<B>,
+) -> Result<bool, String> {
+    let commitment = B::G1::from_bytes(&commitment)
+        .map_err(|err| format!("Invalid commitment: {err}"))?;
+    let blob = bytes_to_blob(&blob)?;
+    let proofs = PointValidation::Checked
+        .g1_from_bytes_batch(proofs)
+        .map_err(|err| format!("Invalid proof: {err}"))?;
+
+    verify_all_blob_point_proofs::<B>(&commitment, &blob, &proofs, das)
+}
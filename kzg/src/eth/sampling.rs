@@ -0,0 +1,279 @@
+//! Data availability sampling for light clients: random cells of the blobs of a block are fetched
+//! and checked against the blob commitments, and every verified cell raises the confidence that
+//! the whole block is available.
+//!
+//! A blob is recoverable from any half of the cells of its extension, so hiding it means
+//! withholding more than half of them, and each uniformly random sample then fails with
+//! probability above one half.
+
+use alloc::{format, string::String, vec, vec::Vec};
+use hashbrown::HashSet;
+use rand_core::{RngCore, SeedableRng};
+
+use crate::{
+    das::{EcBackend, DAS},
+    eth::{
+        eip_7594::{verify_cell_kzg_proof_batch_raw, CellsKzgProofs},
+        BYTES_PER_CELL, BYTES_PER_COMMITMENT, BYTES_PER_PROOF, CELLS_PER_EXT_BLOB,
+    },
+};
+
+/// A cell with its proof.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SampledCell {
+    pub cell: [u8; BYTES_PER_CELL],
+    pub proof: [u8; BYTES_PER_PROOF],
+}
+
+/// Source of cells, e.g. the peers of a node.
+pub trait CellFetcher {
+    /// Fetches cell `cell_index` of blob `blob_index`, `None` when no one serves it.
+    fn fetch_cell(&mut self, blob_index: usize, cell_index: usize) -> Option<SampledCell>;
+
+    /// Fetches several cells, given as `(blob_index, cell_index)`. Fetchers that can request
+    /// cells in bulk should override it.
+    fn fetch_cells(&mut self, samples: &[(usize, usize)]) -> Vec<Option<SampledCell>> {
+        samples
+            .iter()
+            .map(|&(blob_index, cell_index)| self.fetch_cell(blob_index, cell_index))
+            .collect()
+    }
+}
+
+/// A network stand-in holding the cells of every blob in memory, some of which may be withheld.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryNetwork {
+    /// Cells of every blob by cell index, `None` for withheld cells.
+    pub cells: Vec<Vec<Option<SampledCell>>>,
+}
+
+impl InMemoryNetwork {
+    /// Serves the given cells and proofs of every blob, as returned by
+    /// [`crate::eth::eip_7594::compute_cells_and_kzg_proofs_raw`].
+    pub fn new(cells_and_proofs: &[CellsKzgProofs]) -> Self {
+        Self {
+            cells: cells_and_proofs
+                .iter()
+                .map(|(cells, proofs)| {
+                    cells
+                        .iter()
+                        .zip(proofs)
+                        .map(|(&cell, &proof)| Some(SampledCell { cell, proof }))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Stops serving a cell.
+    pub fn withhold(&mut self, blob_index: usize, cell_index: usize) {
+        if let Some(cell) = self
+            .cells
+            .get_mut(blob_index)
+            .and_then(|cells| cells.get_mut(cell_index))
+        {
+            *cell = None;
+        }
+    }
+}
+
+impl CellFetcher for InMemoryNetwork {
+    fn fetch_cell(&mut self, blob_index: usize, cell_index: usize) -> Option<SampledCell> {
+        self.cells.get(blob_index)?.get(cell_index)?.clone()
+    }
+}
+
+/// Outcome of fetching and verifying a set of samples, each given as `(blob_index, cell_index)`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SamplingRound {
+    /// Samples whose proofs verified.
+    pub verified: Vec<(usize, usize)>,
+    /// Samples the fetcher did not return.
+    pub missing: Vec<(usize, usize)>,
+    /// Samples returned with a wrong cell or proof.
+    pub invalid: Vec<(usize, usize)>,
+}
+
+/// Samples the blobs of a block and keeps track of the verified cells.
+///
+/// The indices are picked with `rng`. Peers that can predict them can serve just the sampled
+/// cells, so outside of tests use a cryptographically secure generator seeded privately.
+pub struct DasSampler<R> {
+    rng: R,
+    commitments: Vec<[u8; BYTES_PER_COMMITMENT]>,
+    verified: Vec<HashSet<usize>>,
+}
+
+impl<R: RngCore> DasSampler<R> {
+    /// Creates a sampler for the blobs with the given commitments.
+    pub fn new(rng: R, commitments: Vec<[u8; BYTES_PER_COMMITMENT]>) -> Self {
+        Self {
+            rng,
+            verified: vec![HashSet::new(); commitments.len()],
+            commitments,
+        }
+    }
+
+    /// Like [`Self::new`], with a generator seeded from `seed`, so that the picked indices can be
+    /// reproduced.
+    pub fn from_seed(seed: u64, commitments: Vec<[u8; BYTES_PER_COMMITMENT]>) -> Self
+    where
+        R: SeedableRng,
+    {
+        Self::new(R::seed_from_u64(seed), commitments)
+    }
+
+    /// Number of blobs sampled.
+    pub fn blob_count(&self) -> usize {
+        self.commitments.len()
+    }
+
+    /// Number of distinct verified cells of a blob.
+    pub fn verified_cell_count(&self, blob_index: usize) -> usize {
+        self.verified.get(blob_index).map_or(0, HashSet::len)
+    }
+
+    /// Picks `samples_per_blob` distinct random cells of every blob, as `(blob_index, cell_index)`.
+    /// Cells already verified are skipped, so fewer are picked once most cells are verified.
+    pub fn pick_cells(&mut self, samples_per_blob: usize) -> Vec<(usize, usize)> {
+        let mut samples = Vec::with_capacity(self.blob_count() * samples_per_blob);
+        for blob_index in 0..self.blob_count() {
+            let candidates = (0..CELLS_PER_EXT_BLOB)
+                .filter(|cell_index| !self.verified[blob_index].contains(cell_index))
+                .collect::<Vec<_>>();
+            samples.extend(
+                pick_distinct(&mut self.rng, candidates, samples_per_blob)
+                    .into_iter()
+                    .map(|cell_index| (blob_index, cell_index)),
+            );
+        }
+        samples
+    }
+
+    /// Picks `count` distinct random columns. Sampling a column samples its cell in every blob.
+    pub fn pick_columns(&mut self, count: usize) -> Vec<usize> {
+        pick_distinct(&mut self.rng, (0..CELLS_PER_EXT_BLOB).collect(), count)
+    }
+
+    /// Fetches the cells of the given columns from all blobs and verifies them.
+    pub fn sample_columns<B: EcBackend>(
+        &mut self,
+        columns: &[usize],
+        fetcher: &mut impl CellFetcher,
+        das: &impl DAS<B>,
+    ) -> Result<SamplingRound, String> {
+        let samples = columns
+            .iter()
+            .flat_map(|&column| (0..self.blob_count()).map(move |blob_index| (blob_index, column)))
+            .collect::<Vec<_>>();
+
+        self.sample(&samples, fetcher, das)
+    }
+
+    /// Fetches the given cells, as `(blob_index, cell_index)`, and verifies them against the blob
+    /// commitments. Verified cells count towards [`Self::confidence`].
+    pub fn sample<B: EcBackend>(
+        &mut self,
+        samples: &[(usize, usize)],
+        fetcher: &mut impl CellFetcher,
+        das: &impl DAS<B>,
+    ) -> Result<SamplingRound, String> {
+        for &(blob_index, cell_index) in samples {
+            if blob_index >= self.blob_count() || cell_index >= CELLS_PER_EXT_BLOB {
+                return Err(format!("Invalid sample ({blob_index}, {cell_index})"));
+            }
+        }
+
+        let mut round = SamplingRound::default();
+        let mut fetched = Vec::with_capacity(samples.len());
+        for (&sample, cell) in samples.iter().zip(fetcher.fetch_cells(samples)) {
+            match cell {
+                Some(cell) => fetched.push((sample, cell)),
+                None => round.missing.push(sample),
+            }
+        }
+
+        // One batch for all cells, one per cell only if some are wrong
+        let verify = |cells: &[((usize, usize), SampledCell)]| {
+            let commitments = cells
+                .iter()
+                .map(|((blob_index, _), _)| self.commitments[*blob_index])
+                .collect::<Vec<_>>();
+            let cell_indices = cells
+                .iter()
+                .map(|((_, cell_index), _)| *cell_index)
+                .collect::<Vec<_>>();
+            let (cells, proofs): (Vec<_>, Vec<_>) = cells
+                .iter()
+                .map(|(_, sampled)| (sampled.cell, sampled.proof))
+                .unzip();
+
+            verify_cell_kzg_proof_batch_raw::<B>(&commitments, &cell_indices, &cells, &proofs, das)
+                .unwrap_or(false)
+        };
+
+        if verify(&fetched) {
+            round.verified = fetched.iter().map(|(sample, _)| *sample).collect();
+        } else {
+            for cell in &fetched {
+                if verify(core::slice::from_ref(cell)) {
+                    round.verified.push(cell.0);
+                } else {
+                    round.invalid.push(cell.0);
+                }
+            }
+        }
+
+        for &(blob_index, cell_index) in &round.verified {
+            self.verified[blob_index].insert(cell_index);
+        }
+
+        Ok(round)
+    }
+
+    /// Confidence that every blob is available: the smallest, over the blobs, probability that a
+    /// blob with at least a `loss_threshold` fraction of its cells withheld would have failed one
+    /// of its verified samples. Blobs stay recoverable below a threshold of one half.
+    pub fn confidence(&self, loss_threshold: f64) -> f64 {
+        self.verified
+            .iter()
+            .map(|verified| {
+                availability_confidence(CELLS_PER_EXT_BLOB, verified.len(), loss_threshold)
+            })
+            .fold(1.0, f64::min)
+    }
+}
+
+/// Probability that `samples` distinct uniformly random cells out of `cell_count` would hit a
+/// withheld one, when at least a `loss_threshold` fraction of the cells is withheld.
+pub fn availability_confidence(cell_count: usize, samples: usize, loss_threshold: f64) -> f64 {
+    // Withholding the fewest cells makes passing all samples most likely
+    let withheld_fraction = loss_threshold * cell_count as f64;
+    let mut withheld = withheld_fraction as usize;
+    if (withheld as f64) < withheld_fraction {
+        withheld += 1;
+    }
+    let available = cell_count.saturating_sub(withheld);
+    if samples > available {
+        return 1.0;
+    }
+
+    // Hypergeometric probability that every sample is an available cell
+    let all_available = (0..samples).fold(1.0, |probability, i| {
+        probability * (available - i) as f64 / (cell_count - i) as f64
+    });
+
+    1.0 - all_available
+}
+
+/// Picks `count` distinct values out of `candidates`, or all of them if there are fewer.
+fn pick_distinct(rng: &mut impl RngCore, mut candidates: Vec<usize>, count: usize) -> Vec<usize> {
+    let count = count.min(candidates.len());
+    for i in 0..count {
+        // The modulo bias is below 2^-56 for the candidate counts here
+        let j = i + (rng.next_u64() % (candidates.len() - i) as u64) as usize;
+        candidates.swap(i, j);
+    }
+    candidates.truncate(count);
+    candidates
+}
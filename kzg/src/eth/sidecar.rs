@@ -0,0 +1,302 @@
+//! SSZ containers of the consensus specs that carry blobs and cells over the network, and the KZG
+//! checks of a data column sidecar.
+
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+
+use crate::{
+    das::{EcBackend, DAS},
+    eth::{
+        eip_7594::verify_cell_kzg_proof_batch_raw, BYTES_PER_BLOB, BYTES_PER_CELL,
+        BYTES_PER_COMMITMENT, BYTES_PER_PROOF, CELLS_PER_EXT_BLOB,
+    },
+};
+
+pub const MAX_BLOB_COMMITMENTS_PER_BLOCK: usize = 4096;
+pub const KZG_COMMITMENT_INCLUSION_PROOF_DEPTH: usize = 17;
+pub const KZG_COMMITMENTS_INCLUSION_PROOF_DEPTH: usize = 4;
+pub const BYTES_PER_SIGNATURE: usize = 96;
+
+const BYTES_PER_ROOT: usize = 32;
+const BYTES_PER_OFFSET: usize = 4;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BeaconBlockHeader {
+    pub slot: u64,
+    pub proposer_index: u64,
+    pub parent_root: [u8; BYTES_PER_ROOT],
+    pub state_root: [u8; BYTES_PER_ROOT],
+    pub body_root: [u8; BYTES_PER_ROOT],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedBeaconBlockHeader {
+    pub message: BeaconBlockHeader,
+    pub signature: [u8; BYTES_PER_SIGNATURE],
+}
+
+/// Blob sidecar of the Deneb consensus specs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlobSidecar {
+    pub index: u64,
+    pub blob: Box<[u8; BYTES_PER_BLOB]>,
+    pub kzg_commitment: [u8; BYTES_PER_COMMITMENT],
+    pub kzg_proof: [u8; BYTES_PER_PROOF],
+    pub signed_block_header: SignedBeaconBlockHeader,
+    pub kzg_commitment_inclusion_proof:
+        [[u8; BYTES_PER_ROOT]; KZG_COMMITMENT_INCLUSION_PROOF_DEPTH],
+}
+
+/// Data column sidecar of the Fulu consensus specs: the cells with index `index` of all blobs of
+/// a block, with their commitments and proofs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataColumnSidecar {
+    pub index: u64,
+    pub column: Vec<[u8; BYTES_PER_CELL]>,
+    pub kzg_commitments: Vec<[u8; BYTES_PER_COMMITMENT]>,
+    pub kzg_proofs: Vec<[u8; BYTES_PER_PROOF]>,
+    pub signed_block_header: SignedBeaconBlockHeader,
+    pub kzg_commitments_inclusion_proof:
+        [[u8; BYTES_PER_ROOT]; KZG_COMMITMENTS_INCLUSION_PROOF_DEPTH],
+}
+
+impl BeaconBlockHeader {
+    pub const SSZ_LEN: usize = 2 * 8 + 3 * BYTES_PER_ROOT;
+
+    fn write(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.slot.to_le_bytes());
+        out.extend_from_slice(&self.proposer_index.to_le_bytes());
+        out.extend_from_slice(&self.parent_root);
+        out.extend_from_slice(&self.state_root);
+        out.extend_from_slice(&self.body_root);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, String> {
+        Ok(Self {
+            slot: reader.u64()?,
+            proposer_index: reader.u64()?,
+            parent_root: reader.array()?,
+            state_root: reader.array()?,
+            body_root: reader.array()?,
+        })
+    }
+}
+
+impl SignedBeaconBlockHeader {
+    pub const SSZ_LEN: usize = BeaconBlockHeader::SSZ_LEN + BYTES_PER_SIGNATURE;
+
+    fn write(&self, out: &mut Vec<u8>) {
+        self.message.write(out);
+        out.extend_from_slice(&self.signature);
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, String> {
+        Ok(Self {
+            message: BeaconBlockHeader::read(reader)?,
+            signature: reader.array()?,
+        })
+    }
+}
+
+impl BlobSidecar {
+    /// All fields have a fixed size, so the encoding has a fixed size too.
+    pub const SSZ_LEN: usize = 8
+        + BYTES_PER_BLOB
+        + BYTES_PER_COMMITMENT
+        + BYTES_PER_PROOF
+        + SignedBeaconBlockHeader::SSZ_LEN
+        + KZG_COMMITMENT_INCLUSION_PROOF_DEPTH * BYTES_PER_ROOT;
+
+    pub fn to_ssz_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(Self::SSZ_LEN);
+        out.extend_from_slice(&self.index.to_le_bytes());
+        out.extend_from_slice(self.blob.as_slice());
+        out.extend_from_slice(&self.kzg_commitment);
+        out.extend_from_slice(&self.kzg_proof);
+        self.signed_block_header.write(&mut out);
+        out.extend_from_slice(self.kzg_commitment_inclusion_proof.as_flattened());
+        out
+    }
+
+    pub fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() != Self::SSZ_LEN {
+            return Err(format!(
+                "Invalid blob sidecar length. Expected {}, got {}",
+                Self::SSZ_LEN,
+                bytes.len()
+            ));
+        }
+
+        let mut reader = Reader(bytes);
+        Ok(Self {
+            index: reader.u64()?,
+            blob: reader.boxed()?,
+            kzg_commitment: reader.array()?,
+            kzg_proof: reader.array()?,
+            signed_block_header: SignedBeaconBlockHeader::read(&mut reader)?,
+            kzg_commitment_inclusion_proof: reader.roots()?,
+        })
+    }
+}
+
+impl DataColumnSidecar {
+    /// Length of the fixed part: `index`, three list offsets, the header and the inclusion proof.
+    pub const SSZ_FIXED_LEN: usize = 8
+        + 3 * BYTES_PER_OFFSET
+        + SignedBeaconBlockHeader::SSZ_LEN
+        + KZG_COMMITMENTS_INCLUSION_PROOF_DEPTH * BYTES_PER_ROOT;
+
+    pub fn to_ssz_bytes(&self) -> Vec<u8> {
+        let column = self.column.as_flattened();
+        let commitments = self.kzg_commitments.as_flattened();
+        let proofs = self.kzg_proofs.as_flattened();
+
+        let mut out = Vec::with_capacity(
+            Self::SSZ_FIXED_LEN + column.len() + commitments.len() + proofs.len(),
+        );
+        out.extend_from_slice(&self.index.to_le_bytes());
+        let column_offset = Self::SSZ_FIXED_LEN;
+        let commitments_offset = column_offset + column.len();
+        let proofs_offset = commitments_offset + commitments.len();
+        for offset in [column_offset, commitments_offset, proofs_offset] {
+            out.extend_from_slice(&(offset as u32).to_le_bytes());
+        }
+        self.signed_block_header.write(&mut out);
+        out.extend_from_slice(self.kzg_commitments_inclusion_proof.as_flattened());
+
+        out.extend_from_slice(column);
+        out.extend_from_slice(commitments);
+        out.extend_from_slice(proofs);
+        out
+    }
+
+    pub fn from_ssz_bytes(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.len() < Self::SSZ_FIXED_LEN {
+            return Err(format!(
+                "Invalid data column sidecar length. Expected at least {}, got {}",
+                Self::SSZ_FIXED_LEN,
+                bytes.len()
+            ));
+        }
+
+        let mut reader = Reader(bytes);
+        let index = reader.u64()?;
+        let offsets = [
+            reader.offset()?,
+            reader.offset()?,
+            reader.offset()?,
+            bytes.len(),
+        ];
+        let signed_block_header = SignedBeaconBlockHeader::read(&mut reader)?;
+        let kzg_commitments_inclusion_proof = reader.roots()?;
+
+        if offsets[0] != Self::SSZ_FIXED_LEN {
+            return Err(format!("Invalid first offset {}", offsets[0]));
+        }
+        if offsets.windows(2).any(|pair| pair[0] > pair[1]) {
+            return Err("Offsets are not in order".into());
+        }
+
+        Ok(Self {
+            index,
+            column: list(&bytes[offsets[0]..offsets[1]], "column")?,
+            kzg_commitments: list(&bytes[offsets[1]..offsets[2]], "kzg_commitments")?,
+            kzg_proofs: list(&bytes[offsets[2]..offsets[3]], "kzg_proofs")?,
+            signed_block_header,
+            kzg_commitments_inclusion_proof,
+        })
+    }
+}
+
+/// Checks the structure of the sidecar, and verifies the proofs of its cells against the
+/// commitments. The column index of the sidecar is the cell index of all of its cells.
+pub fn verify_data_column_sidecar_kzg_proofs<B: EcBackend>(
+    sidecar: &DataColumnSidecar,
+    das: &impl DAS<B>,
+) -> Result<bool, String> {
+    let cell_index = usize::try_from(sidecar.index)
+        .ok()
+        .filter(|&index| index < CELLS_PER_EXT_BLOB)
+        .ok_or_else(|| format!("Invalid column index {}", sidecar.index))?;
+
+    let len = sidecar.column.len();
+    if len == 0 {
+        return Err("Data column sidecar has no cells".into());
+    }
+    if len > MAX_BLOB_COMMITMENTS_PER_BLOCK {
+        return Err(format!(
+            "Too many cells. Expected at most {MAX_BLOB_COMMITMENTS_PER_BLOCK}, got {len}"
+        ));
+    }
+    if sidecar.kzg_commitments.len() != len || sidecar.kzg_proofs.len() != len {
+        return Err(format!(
+            "Column, commitment and proof counts differ: {}, {} and {}",
+            len,
+            sidecar.kzg_commitments.len(),
+            sidecar.kzg_proofs.len()
+        ));
+    }
+
+    let cell_indices = vec![cell_index; len];
+
+    verify_cell_kzg_proof_batch_raw::<B>(
+        &sidecar.kzg_commitments,
+        &cell_indices,
+        &sidecar.column,
+        &sidecar.kzg_proofs,
+        das,
+    )
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl Reader<'_> {
+    fn take(&mut self, len: usize) -> Result<&[u8], String> {
+        let (head, tail) = self
+            .0
+            .split_at_checked(len)
+            .ok_or_else(|| String::from("Unexpected end of input"))?;
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        self.take(N).map(|bytes| bytes.try_into().unwrap())
+    }
+
+    /// Like [`Reader::array`], without a copy of the array on the stack.
+    fn boxed<const N: usize>(&mut self) -> Result<Box<[u8; N]>, String> {
+        self.take(N)
+            .map(|bytes| Box::<[u8]>::from(bytes).try_into().unwrap())
+    }
+
+    fn u64(&mut self) -> Result<u64, String> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    fn offset(&mut self) -> Result<usize, String> {
+        self.array().map(|bytes| u32::from_le_bytes(bytes) as usize)
+    }
+
+    fn roots<const N: usize>(&mut self) -> Result<[[u8; BYTES_PER_ROOT]; N], String> {
+        let mut roots = [[0; BYTES_PER_ROOT]; N];
+        for root in &mut roots {
+            *root = self.array()?;
+        }
+        Ok(roots)
+    }
+}
+
+fn list<const N: usize>(bytes: &[u8], name: &str) -> Result<Vec<[u8; N]>, String> {
+    let (items, remainder) = bytes.as_chunks::<N>();
+    if !remainder.is_empty() {
+        return Err(format!("Length of {name} is not a multiple of {N}"));
+    }
+    if items.len() > MAX_BLOB_COMMITMENTS_PER_BLOCK {
+        return Err(format!(
+            "Too many {name}. Expected at most {MAX_BLOB_COMMITMENTS_PER_BLOCK}, got {}",
+            items.len()
+        ));
+    }
+
+    Ok(items.to_vec())
+}
@@ -2,18 +2,26 @@
 
 extern crate alloc;
 
-use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use alloc::{borrow::ToOwned, format, string::String, vec::Vec};
 use arbitrary::Arbitrary;
 use core::fmt::Debug;
+use hashbrown::HashMap;
 use msm::precompute::PrecomputationTable;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 pub mod common_utils;
 mod das;
 pub mod eip_4844;
+pub mod erasure;
 pub mod eth;
 pub mod msm;
+pub mod reed_solomon;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod transcript;
 
-pub use das::{EcBackend, DAS};
+pub use das::{new_das_settings, EcBackend, CELL_PROOFS_FK20_THRESHOLD, DAS};
 
 pub trait Fr: Default + Clone + PartialEq + Sync + for<'a> Arbitrary<'a> {
     fn null() -> Self;
@@ -88,6 +96,29 @@ pub trait G1: Clone + Default + PartialEq + Sync + Debug + Send {
 
     fn from_bytes(bytes: &[u8]) -> Result<Self, String>;
 
+    /// Like [`G1::from_bytes`], but without the subgroup check where the backend does one. Only
+    /// for points that were checked before, e.g. when reloaded from our own storage.
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, String> {
+        Self::from_bytes(bytes)
+    }
+
+    /// Decodes and checks many points at once, as [`G1::from_bytes`] and [`G1::is_valid`] would.
+    /// Repeated encodings, like the commitment of every cell of a blob, are decompressed and
+    /// checked only once, and with feature `parallel` the points are spread over threads.
+    ///
+    /// Square roots can't be shared between different points, and the subgroup checks are not
+    /// replaced by a single check of a random linear combination: the G1 cofactor is divisible by
+    /// 3, so a point with a component of order 3 would pass such a check with probability 1/3.
+    fn from_bytes_batch(bytes: &[[u8; 48]]) -> Result<Vec<Self>, String> {
+        g1_from_bytes_batch(bytes, |bytes| {
+            let point = Self::from_bytes(bytes)?;
+            if !point.is_inf() && !point.is_valid() {
+                return Err("Point is not in the G1 subgroup".to_owned());
+            }
+            Ok(point)
+        })
+    }
+
     fn from_hex(hex: &str) -> Result<Self, String>;
 
     fn to_bytes(&self) -> [u8; 48];
@@ -153,6 +184,14 @@ pub trait G1LinComb<
         precomputation: Option<&PrecomputationTable<TFr, Self, TG1Fp, TG1Affine, TG1ProjAddAffine>>,
     ) -> Self;
 
+    /// Same as [`G1LinComb::g1_lincomb`], for scalars that are all less than `2^scalar_bits`,
+    /// such as random 128-bit batch verification weights. Backends with their own Pippenger
+    /// use this to skip the windows of the high bits; by default the full-width MSM is used.
+    fn g1_lincomb_short(points: &[Self], scalars: &[TFr], len: usize, scalar_bits: usize) -> Self {
+        let _ = scalar_bits;
+        Self::g1_lincomb(points, scalars, len, None)
+    }
+
     fn g1_lincomb_batch(
         points: &[Vec<Self>],
         scalars: &[Vec<TFr>],
@@ -295,6 +334,83 @@ pub trait G1Affine<TG1: G1, TG1Fp: G1Fp>:
     fn to_bytes_uncompressed(&self) -> [u8; 96];
 
     fn from_bytes_uncompressed(bytes: [u8; 96]) -> Result<Self, String>;
+
+    /// Like [`G1Affine::from_bytes_uncompressed`], but without the subgroup check where the
+    /// backend does one.
+    fn from_bytes_uncompressed_unchecked(bytes: [u8; 96]) -> Result<Self, String> {
+        Self::from_bytes_uncompressed(bytes)
+    }
+
+    /// Decodes trusted uncompressed points, e.g. commitments and proofs reloaded from our own
+    /// storage. Neither decompression nor subgroup checks are done.
+    fn from_bytes_uncompressed_batch_unchecked(bytes: &[[u8; 96]]) -> Result<Vec<Self>, String> {
+        cfg_iter!(bytes)
+            .map(|bytes| Self::from_bytes_uncompressed_unchecked(*bytes))
+            .collect()
+    }
+}
+
+/// Decodes each distinct encoding once.
+fn g1_from_bytes_batch<TG1: G1>(
+    bytes: &[[u8; 48]],
+    decode: impl Fn(&[u8]) -> Result<TG1, String> + Sync,
+) -> Result<Vec<TG1>, String> {
+    let mut unique = Vec::new();
+    let mut unique_indices = HashMap::with_capacity(bytes.len());
+    let indices = bytes
+        .iter()
+        .enumerate()
+        .map(|(index, bytes)| {
+            *unique_indices.entry(bytes).or_insert_with(|| {
+                unique.push((index, bytes));
+                unique.len() - 1
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let points = cfg_iter!(unique)
+        .map(|(index, bytes)| {
+            decode(bytes.as_slice()).map_err(|err| format!("Invalid point at index {index}: {err}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(indices
+        .into_iter()
+        .map(|index| points[index].clone())
+        .collect())
+}
+
+/// How the byte-level APIs check the points they decode.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum PointValidation {
+    /// Points must be in the right subgroup. Required for input from the network.
+    #[default]
+    Checked,
+    /// Points are only decompressed. For points that were checked before they were stored.
+    Trusted,
+}
+
+impl PointValidation {
+    pub fn g1_from_bytes<TG1: G1>(self, bytes: &[u8]) -> Result<TG1, String> {
+        match self {
+            Self::Checked => TG1::from_bytes(bytes),
+            Self::Trusted => TG1::from_bytes_unchecked(bytes),
+        }
+    }
+
+    /// Decodes many points with [`G1::from_bytes_batch`]. The points are checked while decoding
+    /// in [`PointValidation::Checked`] mode, so they don't need to be checked again.
+    pub fn g1_from_bytes_batch<TG1: G1>(self, bytes: &[[u8; 48]]) -> Result<Vec<TG1>, String> {
+        match self {
+            Self::Checked => TG1::from_bytes_batch(bytes),
+            Self::Trusted => g1_from_bytes_batch(bytes, |bytes| TG1::from_bytes_unchecked(bytes)),
+        }
+    }
+
+    /// Whether the point passes the checks of this mode.
+    pub fn is_valid_g1<TG1: G1>(self, g1: &TG1) -> bool {
+        self == Self::Trusted || g1.is_inf() || g1.is_valid()
+    }
 }
 
 pub trait G1ProjAddAffine<TG1: G1, TG1Fp: G1Fp, TG1Affine: G1Affine<TG1, TG1Fp>>:
@@ -399,10 +515,17 @@ pub trait G2: Clone + Default {
 
     fn from_bytes(bytes: &[u8]) -> Result<Self, String>;
 
+    /// Like [`G2::from_bytes`], but without the subgroup check where the backend does one.
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, String> {
+        Self::from_bytes(bytes)
+    }
+
     fn to_bytes(&self) -> [u8; 96];
 
     fn add_or_dbl(&mut self, b: &Self) -> Self;
 
+    fn is_valid(&self) -> bool;
+
     fn dbl(&self) -> Self;
 
     fn sub(&self, b: &Self) -> Self;
@@ -418,6 +541,53 @@ pub trait PairingVerify<TG1: G1, TG2: G2> {
     fn verify(a1: &TG1, a2: &TG2, b1: &TG1, b2: &TG2) -> bool;
 }
 
+/// Element of the pairing target group, represented as an element of `Fp12`.
+///
+/// Miller loop outputs are stored in the same type, but only compare meaningfully after
+/// [`Pairing::final_exp`] has been applied.
+pub trait GT: Clone + Default + PartialEq + Sync + Debug + Send {
+    fn one() -> Self;
+
+    /// Deserializes an `Fp12` element from 12 big-endian `Fp` coefficients, ordered
+    /// `c0.c0.c0, c0.c0.c1, c0.c1.c0, ..., c1.c2.c1`. Rejects non-canonical coefficients and
+    /// elements outside of the order `r` subgroup.
+    fn from_bytes(bytes: &[u8]) -> Result<Self, String>;
+
+    fn to_bytes(&self) -> [u8; 576];
+
+    fn is_one(&self) -> bool;
+
+    fn mul(&self, b: &Self) -> Self;
+
+    fn sqr(&self) -> Self;
+
+    fn inverse(&self) -> Self;
+
+    fn equals(&self, b: &Self) -> bool;
+}
+
+pub trait GTPow<TFr: Fr>: GT {
+    fn pow(&self, b: &TFr) -> Self;
+}
+
+pub trait Pairing<TG1: G1, TG2: G2, TGT: GT> {
+    fn miller_loop(a: &TG1, b: &TG2) -> TGT;
+
+    fn final_exp(f: &TGT) -> TGT;
+
+    fn pairing(a: &TG1, b: &TG2) -> TGT {
+        Self::final_exp(&Self::miller_loop(a, b))
+    }
+
+    // Product of Miller loops, so that only a single final exponentiation is needed for a
+    // product of pairings
+    fn multi_miller_loop(a: &[TG1], b: &[TG2]) -> TGT {
+        a.iter()
+            .zip(b.iter())
+            .fold(TGT::one(), |acc, (a, b)| acc.mul(&Self::miller_loop(a, b)))
+    }
+}
+
 pub trait FFTFr<Coeff: Fr> {
     fn fft_fr(&self, data: &[Coeff], inverse: bool) -> Result<Vec<Coeff>, String>;
 }
@@ -603,6 +773,12 @@ pub trait KZGSettings<
     fn get_x_ext_fft_columns(&self) -> &[Vec<Coeff2>];
 
     fn get_cell_size(&self) -> usize;
+
+    /// Ratio of the extended blob to the blob in data availability sampling. The extended blob
+    /// spans the whole FFT domain of the settings.
+    fn get_extension_factor(&self) -> usize {
+        self.get_fft_settings().get_max_width() / self.get_g1_monomial().len()
+    }
 }
 
 pub trait FK20SingleSettings<
@@ -6,9 +6,13 @@ use super::arkmsm::arkmsm_msm::VariableBaseMSM;
 use super::precompute::PrecomputationTable;
 
 use super::tiling_pippenger_ops::tiling_pippenger;
+#[cfg(not(feature = "parallel"))]
+use super::tiling_pippenger_ops::tiling_pippenger_nbits;
 
 #[cfg(feature = "parallel")]
-use super::tiling_parallel_pippenger::{parallel_affine_conv, tiling_parallel_pippenger};
+use super::tiling_parallel_pippenger::{
+    parallel_affine_conv, tiling_parallel_pippenger, tiling_parallel_pippenger_nbits,
+};
 
 #[cfg(feature = "parallel")]
 fn msm_parallel<
@@ -146,3 +150,43 @@ pub fn msm<
         precomputation,
     );
 }
+
+/// Same as [`msm`], for scalars that are all less than `2^nbits`. The windows above `nbits` are
+/// skipped, which makes, for example, MSMs with random 128-bit weights about twice as fast.
+#[allow(clippy::extra_unused_type_parameters)]
+pub fn msm_nbits<
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+    TFr: Fr,
+>(
+    points: &[TG1],
+    scalars: &[TFr],
+    len: usize,
+    nbits: usize,
+) -> TG1 {
+    if len < 8 {
+        let mut out = TG1::zero();
+        for i in 0..len {
+            let tmp = points[i].mul(&scalars[i]);
+            out.add_or_dbl_assign(&tmp);
+        }
+        return out;
+    }
+
+    let (points, scalars): (Vec<_>, Vec<_>) = points[0..len]
+        .iter()
+        .cloned()
+        .zip(scalars[0..len].iter())
+        .filter(|(p, _)| !p.is_inf())
+        .collect();
+    let points = batch_convert::<TG1, TG1Fp, TG1Affine>(&points);
+    let scalars = scalars.iter().map(|s| s.to_scalar()).collect::<Vec<_>>();
+
+    #[cfg(feature = "parallel")]
+    return tiling_parallel_pippenger_nbits(&points, &scalars, nbits);
+
+    #[cfg(not(feature = "parallel"))]
+    return tiling_pippenger_nbits(&points, &scalars, nbits);
+}
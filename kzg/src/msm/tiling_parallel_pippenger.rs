@@ -13,7 +13,7 @@ use super::{
     parallel_pippenger_utils::breakdown,
     pippenger_utils::{pippenger_window_size, P1XYZZ},
     thread_pool::{da_pool, ThreadPoolExt},
-    tiling_pippenger_ops::{p1s_tile_pippenger_pub, tiling_pippenger},
+    tiling_pippenger_ops::{p1s_tile_pippenger_pub, tiling_pippenger_nbits},
 };
 
 struct Tile {
@@ -71,9 +71,23 @@ pub fn tiling_parallel_pippenger<
     TG1: G1 + G1GetFp<TG1Fp>,
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    points: &[TG1Affine],
+    scalars: &[Scalar256],
+) -> TG1 {
+    tiling_parallel_pippenger_nbits(points, scalars, 255)
+}
+
+/// Same as [`tiling_parallel_pippenger`], but only the lowest `nbits` bits of each scalar are
+/// processed. Every scalar must be less than `2^nbits`.
+pub fn tiling_parallel_pippenger_nbits<
+    TG1: G1 + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
 >(
     mut points: &[TG1Affine],
     scalars: &[Scalar256],
+    nbits: usize,
 ) -> TG1 {
     if scalars.len() < points.len() {
         points = &points[0..scalars.len()];
@@ -84,10 +98,10 @@ pub fn tiling_parallel_pippenger<
     let ncpus = pool.max_count();
 
     if ncpus < 2 || npoints < 32 {
-        return tiling_pippenger(points, scalars);
+        return tiling_pippenger_nbits(points, scalars, nbits);
     }
 
-    let (nx, ny, window) = breakdown(pippenger_window_size(npoints), ncpus);
+    let (nx, ny, window) = breakdown(pippenger_window_size(npoints), ncpus, nbits);
 
     // |grid[]| holds "coordinates" and place for result
     let mut grid: Vec<(Tile, Cell<TG1>)> = Vec::with_capacity(nx * ny);
@@ -103,7 +117,7 @@ pub fn tiling_parallel_pippenger<
         grid[total].0.x = total * dx;
         grid[total].0.dx = dx;
         grid[total].0.y = y;
-        grid[total].0.dy = 255 - y;
+        grid[total].0.dy = nbits - y;
         total += 1;
     }
     grid[total - 1].0.dx = npoints - grid[total - 1].0.x;
@@ -151,6 +165,7 @@ pub fn tiling_parallel_pippenger<
                     &mut buckets,
                     y,
                     window,
+                    nbits,
                 );
                 if row_sync[y / window].fetch_add(1, Ordering::AcqRel) == nx - 1 {
                     tx.send(y).expect("disaster");
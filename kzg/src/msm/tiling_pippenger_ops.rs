@@ -52,10 +52,10 @@ pub fn p1s_tile_pippenger_pub<TG1: G1 + G1GetFp<TFp>, TFp: G1Fp, TG1Affine: G1Af
     buckets: &mut [P1XYZZ<TFp>],
     bit0: usize,
     window: usize,
+    nbits: usize,
 ) {
-    const NBITS: usize = 255;
-    let (wbits, cbits) = if bit0 + window > NBITS {
-        let wbits = NBITS - bit0;
+    let (wbits, cbits) = if bit0 + window > nbits {
+        let wbits = nbits - bit0;
         (wbits, wbits + 1)
     } else {
         (window, window)
@@ -106,13 +106,29 @@ pub fn p1s_tile_pippenger<TG1: G1 + G1GetFp<TFp>, TFp: G1Fp, TG1Affine: G1Affine
 pub fn tiling_pippenger<TG1: G1 + G1GetFp<TG1Fp>, TG1Fp: G1Fp, TG1Affine: G1Affine<TG1, TG1Fp>>(
     points: &[TG1Affine],
     scalars: &[Scalar256],
+) -> TG1 {
+    tiling_pippenger_nbits(points, scalars, 255)
+}
+
+/// Same as [`tiling_pippenger`], but only the lowest `nbits` bits of each scalar are processed.
+///
+/// Every scalar must be less than `2^nbits`. Short scalars need fewer windows, so for example
+/// 128-bit scalars take about half as many bucket passes as full-width ones.
+pub fn tiling_pippenger_nbits<
+    TG1: G1 + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    points: &[TG1Affine],
+    scalars: &[Scalar256],
+    nbits: usize,
 ) -> TG1 {
     let window = pippenger_window_size(points.len());
     let mut buckets = vec![P1XYZZ::<TG1Fp>::default(); 1 << (window - 1)];
 
-    let mut wbits: usize = 255 % window;
+    let mut wbits: usize = nbits % window;
     let mut cbits: usize = wbits + 1;
-    let mut bit0: usize = 255;
+    let mut bit0: usize = nbits;
     let mut tile = TG1::zero();
 
     let mut ret = TG1::zero();
@@ -0,0 +1,217 @@
+//! Reed-Solomon decoding with error correction, for samples that may not only be missing but also
+//! wrong.
+//!
+//! [`crate::PolyRecover`] and [`DAS::recover_cells_and_kzg_proofs`] assume every provided sample
+//! is correct, which holds when cells are checked against their proofs first. Without proofs, a
+//! single wrong sample silently yields a wrong polynomial. The decoder here is Gao's algorithm: it
+//! recovers a polynomial of degree below `k` from `m` provided evaluations of which up to
+//! `(m - k) / 2` are wrong, and reports the wrong positions.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use crate::{
+    common_utils::{reverse_bit_order, reverse_bits_limited},
+    das::{EcBackend, DAS},
+    FFTFr, FFTSettings, Fr, KZGSettings,
+};
+
+/// Recovers the coefficients of a polynomial of degree below `data_len` from its evaluations over
+/// the roots of unity of order `samples.len()`, in natural order. Samples may be missing (`None`)
+/// and, out of the `m` provided ones, up to `(m - data_len) / 2` may be wrong.
+///
+/// Returns the `samples.len()` coefficients (zero from `data_len` on) and the sorted positions of
+/// the wrong samples. Fails when there are too many errors to decode.
+///
+/// Unlike the FFT-based erasure recovery, the decoder takes time quadratic in the sample count.
+pub fn decode_coeffs_with_errors<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>(
+    samples: &[Option<TFr>],
+    data_len: usize,
+    fs: &TFFTSettings,
+) -> Result<(Vec<TFr>, Vec<usize>), String> {
+    let n = samples.len();
+    if !n.is_power_of_two() || n > fs.get_max_width() {
+        return Err(format!(
+            "Sample count {n} must be a power of two, at most the FFT width {}",
+            fs.get_max_width()
+        ));
+    }
+    if data_len == 0 || data_len > n {
+        return Err(format!("Invalid data length {data_len}"));
+    }
+
+    let missing = (0..n).filter(|&i| samples[i].is_none()).collect::<Vec<_>>();
+    let known_count = n - missing.len();
+    if known_count < data_len {
+        return Err(format!(
+            "Not enough samples, expected at least {data_len}, got {known_count}"
+        ));
+    }
+
+    // g0 vanishes on the known points: (x^n - 1) / Z_missing(x)
+    let stride = fs.get_max_width() / n;
+    let mut vanishing = vec![TFr::zero(); n + 1];
+    vanishing[0] = TFr::one().negate();
+    vanishing[n] = TFr::one();
+    let zero_poly = missing.iter().fold(vec![TFr::one()], |poly, &i| {
+        poly_mul(
+            &poly,
+            &[fs.get_roots_of_unity_at(i * stride).negate(), TFr::one()],
+        )
+    });
+    let (g0, _) = poly_div_rem(&vanishing, &zero_poly);
+
+    // g1 interpolates the known samples
+    let evaluations = samples
+        .iter()
+        .map(|sample| sample.clone().unwrap_or_else(TFr::zero))
+        .collect::<Vec<_>>();
+    let interpolation = fs.fft_fr(&evaluations, true)?;
+    let (_, g1) = poly_div_rem(&interpolation, &g0);
+
+    // Partial extended Euclid: stop at the first remainder of degree below (m + k) / 2
+    let (mut r0, mut r1) = (g0, g1);
+    let (mut v0, mut v1) = (Vec::new(), vec![TFr::one()]);
+    while !r1.is_empty() && 2 * (r1.len() - 1) >= known_count + data_len {
+        let (quotient, remainder) = poly_div_rem(&r0, &r1);
+        let v = poly_sub(&v0, &poly_mul(&quotient, &v1));
+        (r0, r1) = (r1, remainder);
+        (v0, v1) = (v1, v);
+    }
+
+    // r1 = f * v1, where v1 is the error locator
+    let (coeffs, remainder) = poly_div_rem(&r1, &v1);
+    if !remainder.is_empty() || coeffs.len() > data_len {
+        return Err("Too many errors to decode".to_string());
+    }
+
+    let mut coeffs = coeffs;
+    coeffs.resize(n, TFr::zero());
+    let decoded = fs.fft_fr(&coeffs, false)?;
+    let error_positions = samples
+        .iter()
+        .zip(&decoded)
+        .enumerate()
+        .filter(|(_, (sample, decoded))| sample.as_ref().is_some_and(|s| !s.equals(decoded)))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    if 2 * error_positions.len() > known_count - data_len {
+        return Err("Too many errors to decode".to_string());
+    }
+
+    Ok((coeffs, error_positions))
+}
+
+/// Recovers all cells of an extended blob from cells that may be wrong, for when cells arrive
+/// without proofs. Up to `(m - n) / 2` wrong field elements are corrected, where `m` is the number
+/// of provided field elements and `n` the blob size.
+///
+/// Returns the cells of the extended blob and the sorted indices of the provided cells that were
+/// wrong.
+pub fn recover_cells_correcting_errors<B: EcBackend>(
+    cell_indices: &[usize],
+    cells: &[B::Fr],
+    das: &impl DAS<B>,
+) -> Result<(Vec<B::Fr>, Vec<usize>), String> {
+    let settings = das.kzg_settings();
+    let cell_size = settings.get_cell_size();
+    let field_elements_per_blob = settings.get_g1_monomial().len();
+    let field_elements_per_ext_blob = field_elements_per_blob * settings.get_extension_factor();
+    let cells_per_ext_blob = field_elements_per_ext_blob / cell_size;
+
+    if cells.len() != cell_indices.len() * cell_size {
+        return Err("Invalid cell count".to_string());
+    }
+
+    // Cells are in bit-reversed order, the decoder takes samples in natural order
+    let mut samples = vec![None; field_elements_per_ext_blob];
+    for (&cell_index, cell) in cell_indices.iter().zip(cells.chunks(cell_size)) {
+        if cell_index >= cells_per_ext_blob {
+            return Err(format!("Invalid cell index {cell_index}"));
+        }
+
+        for (i, value) in cell.iter().enumerate() {
+            let position =
+                reverse_bits_limited(field_elements_per_ext_blob, cell_index * cell_size + i);
+            if samples[position].is_some() {
+                return Err(format!("Cell index {cell_index} appears twice"));
+            }
+            samples[position] = Some(value.clone());
+        }
+    }
+
+    let fft_settings = settings.get_fft_settings();
+    let (coeffs, error_positions) =
+        decode_coeffs_with_errors(&samples, field_elements_per_blob, fft_settings)?;
+
+    let mut recovered_cells = fft_settings.fft_fr(&coeffs, false)?;
+    reverse_bit_order(&mut recovered_cells)?;
+
+    let mut wrong_cells = error_positions
+        .into_iter()
+        .map(|position| reverse_bits_limited(field_elements_per_ext_blob, position) / cell_size)
+        .collect::<Vec<_>>();
+    wrong_cells.sort_unstable();
+    wrong_cells.dedup();
+
+    Ok((recovered_cells, wrong_cells))
+}
+
+/// Coefficients from the lowest degree on, without trailing zeros; the zero polynomial is empty.
+fn trim<TFr: Fr>(mut poly: Vec<TFr>) -> Vec<TFr> {
+    while poly.last().is_some_and(Fr::is_zero) {
+        poly.pop();
+    }
+    poly
+}
+
+fn poly_mul<TFr: Fr>(a: &[TFr], b: &[TFr]) -> Vec<TFr> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+
+    let mut product = vec![TFr::zero(); a.len() + b.len() - 1];
+    for (i, a) in a.iter().enumerate() {
+        for (j, b) in b.iter().enumerate() {
+            product[i + j] = product[i + j].add(&a.mul(b));
+        }
+    }
+    trim(product)
+}
+
+fn poly_sub<TFr: Fr>(a: &[TFr], b: &[TFr]) -> Vec<TFr> {
+    let mut difference = vec![TFr::zero(); a.len().max(b.len())];
+    for (d, a) in difference.iter_mut().zip(a) {
+        *d = a.clone();
+    }
+    for (d, b) in difference.iter_mut().zip(b) {
+        *d = d.sub(b);
+    }
+    trim(difference)
+}
+
+/// Long division of `a` by the non-zero polynomial `b`, returns the quotient and the remainder.
+fn poly_div_rem<TFr: Fr>(a: &[TFr], b: &[TFr]) -> (Vec<TFr>, Vec<TFr>) {
+    let b = trim(b.to_vec());
+    let mut remainder = trim(a.to_vec());
+    if remainder.len() < b.len() {
+        return (Vec::new(), remainder);
+    }
+
+    let lead_inverse = b[b.len() - 1].inverse();
+    let mut quotient = vec![TFr::zero(); remainder.len() - b.len() + 1];
+    for i in (0..quotient.len()).rev() {
+        let factor = remainder[i + b.len() - 1].mul(&lead_inverse);
+        for (r, b) in remainder[i..].iter_mut().zip(&b) {
+            *r = r.sub(&factor.mul(b));
+        }
+        quotient[i] = factor;
+    }
+    remainder.truncate(b.len() - 1);
+
+    (trim(quotient), trim(remainder))
+}
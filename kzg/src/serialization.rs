@@ -0,0 +1,113 @@
+//! Serde encodings shared by the backends (feature `serde`).
+//!
+//! Values are encoded as their canonical byte representation: `0x`-prefixed hex strings in
+//! human-readable formats (JSON, YAML, ...) and raw bytes in binary ones. Deserialization goes
+//! through `from_bytes`, so field elements must be canonical and points must be valid compressed
+//! encodings in the right subgroup.
+
+use alloc::{format, string::String};
+use core::fmt;
+
+use serde::{
+    de::{self, Deserializer, SeqAccess, Visitor},
+    Serializer,
+};
+
+use crate::{Fr, G1, G2};
+
+pub fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+        let mut hex = String::with_capacity(2 + 2 * bytes.len());
+        hex.push_str("0x");
+        hex.push_str(&hex::encode(bytes));
+        serializer.serialize_str(&hex)
+    } else {
+        serializer.serialize_bytes(bytes)
+    }
+}
+
+pub fn deserialize_bytes<'de, D: Deserializer<'de>, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_str(BytesVisitor::<N>)
+    } else {
+        // some formats only hand out borrowed bytes up to a scratch buffer size, which blobs exceed
+        deserializer.deserialize_byte_buf(BytesVisitor::<N>)
+    }
+}
+
+struct BytesVisitor<const N: usize>;
+
+impl<'de, const N: usize> Visitor<'de> for BytesVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{N} bytes or a 0x-prefixed hex string of them")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let hex = value.strip_prefix("0x").unwrap_or(value);
+        if hex.len() != 2 * N {
+            return Err(E::invalid_length(hex.len() / 2, &self));
+        }
+
+        let mut bytes = [0u8; N];
+        hex::decode_to_slice(hex, &mut bytes).map_err(|err| E::custom(format!("{err}")))?;
+        Ok(bytes)
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        value
+            .try_into()
+            .map_err(|_| E::invalid_length(value.len(), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = [0u8; N];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(de::Error::invalid_length(N + 1, &self));
+        }
+        Ok(bytes)
+    }
+}
+
+pub fn serialize_fr<T: Fr, S: Serializer>(fr: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serialize_bytes(&fr.to_bytes(), serializer)
+}
+
+pub fn deserialize_fr<'de, T: Fr, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    let bytes = deserialize_bytes::<D, 32>(deserializer)?;
+    T::from_bytes(&bytes).map_err(de::Error::custom)
+}
+
+pub fn serialize_g1<T: G1, S: Serializer>(g1: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serialize_bytes(&g1.to_bytes(), serializer)
+}
+
+pub fn deserialize_g1<'de, T: G1, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    let bytes = deserialize_bytes::<D, 48>(deserializer)?;
+    let g1 = T::from_bytes(&bytes).map_err(de::Error::custom)?;
+    if !g1.is_inf() && !g1.is_valid() {
+        return Err(de::Error::custom("G1 point is not in the subgroup"));
+    }
+    Ok(g1)
+}
+
+pub fn serialize_g2<T: G2, S: Serializer>(g2: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serialize_bytes(&g2.to_bytes(), serializer)
+}
+
+pub fn deserialize_g2<'de, T: G2, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    let bytes = deserialize_bytes::<D, 96>(deserializer)?;
+    let g2 = T::from_bytes(&bytes).map_err(de::Error::custom)?;
+    if !g2.is_valid() {
+        return Err(de::Error::custom("G2 point is not in the subgroup"));
+    }
+    Ok(g2)
+}
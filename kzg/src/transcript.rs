@@ -0,0 +1,61 @@
+use sha2::{Digest, Sha256};
+
+use crate::eip_4844::{hash_to_bls_field, BYTES_PER_FIELD_ELEMENT};
+use crate::{Fr, G1};
+
+/// Fiat-Shamir transcript used to derive the challenges of the blob and cell proof protocols.
+///
+/// A transcript is started with the domain separator of the protocol
+/// (e.g. [`crate::eip_4844::FIAT_SHAMIR_PROTOCOL_DOMAIN`]), the protocol then absorbs its public
+/// inputs in a fixed order, and finally squeezes a single field element out of it.
+///
+/// [`Sha256Transcript`] reproduces the layouts of the consensus specs. Other implementations may
+/// use a different hash or replace the domain separators, but proofs made with one transcript only
+/// verify with the same transcript.
+pub trait Transcript {
+    /// Starts a new transcript for the protocol identified by `domain`.
+    fn new(domain: &[u8]) -> Self;
+
+    fn absorb_bytes(&mut self, bytes: &[u8]);
+
+    /// Absorbs `value` as 8 big-endian bytes.
+    fn absorb_u64(&mut self, value: u64) {
+        self.absorb_bytes(&value.to_be_bytes());
+    }
+
+    /// Absorbs the canonical encoding of `scalar`.
+    fn absorb_fr<TFr: Fr>(&mut self, scalar: &TFr) {
+        self.absorb_bytes(&scalar.to_bytes());
+    }
+
+    /// Absorbs the compressed encoding of `point`.
+    fn absorb_g1<TG1: G1>(&mut self, point: &TG1) {
+        self.absorb_bytes(&point.to_bytes());
+    }
+
+    fn squeeze_fr<TFr: Fr>(self) -> TFr;
+}
+
+/// The transcript of the consensus specs: SHA-256 over the concatenation of everything absorbed,
+/// interpreted as a big-endian integer modulo the field order.
+#[derive(Clone, Default)]
+pub struct Sha256Transcript {
+    hasher: Sha256,
+}
+
+impl Transcript for Sha256Transcript {
+    fn new(domain: &[u8]) -> Self {
+        let mut transcript = Self::default();
+        transcript.absorb_bytes(domain);
+        transcript
+    }
+
+    fn absorb_bytes(&mut self, bytes: &[u8]) {
+        self.hasher.update(bytes);
+    }
+
+    fn squeeze_fr<TFr: Fr>(self) -> TFr {
+        let digest: [u8; BYTES_PER_FIELD_ELEMENT] = self.hasher.finalize().into();
+        hash_to_bls_field(&digest)
+    }
+}
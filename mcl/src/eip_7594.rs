@@ -8,6 +8,7 @@ use crate::types::g1::MclG1;
 use crate::types::g1::MclG1Affine;
 use crate::types::g1::MclG1ProjAddAffine;
 use crate::types::g2::MclG2;
+use crate::types::gt::MclGT;
 use crate::types::kzg_settings::MclKZGSettings;
 use crate::types::poly::MclPoly;
 
@@ -22,6 +23,7 @@ impl EcBackend for MclBackend {
     type G1ProjAddAffine = MclG1ProjAddAffine;
     type G1 = MclG1;
     type G2 = MclG2;
+    type GT = MclGT;
     type Poly = MclPoly;
     type FFTSettings = MclFFTSettings;
     type KZGSettings = MclKZGSettings;
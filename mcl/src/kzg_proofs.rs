@@ -1,15 +1,19 @@
 extern crate alloc;
 
-use crate::mcl_methods::{mcl_gt, pairing};
+use crate::mcl_methods::{final_exp, mcl_gt, miller_loop, pairing, try_init_mcl};
 use crate::types::fp::MclFp;
 use crate::types::g1::{MclG1, MclG1ProjAddAffine};
 use crate::types::{fr::MclFr, g1::MclG1Affine};
 
-use kzg::msm::{msm_impls::msm, precompute::PrecomputationTable};
+use kzg::msm::{
+    msm_impls::{msm, msm_nbits},
+    precompute::PrecomputationTable,
+};
 
 use crate::types::g2::MclG2;
+use crate::types::gt::MclGT;
 
-use kzg::PairingVerify;
+use kzg::{Pairing, PairingVerify};
 
 impl PairingVerify<MclG1, MclG2> for MclG1 {
     fn verify(a1: &MclG1, a2: &MclG2, b1: &MclG1, b2: &MclG2) -> bool {
@@ -17,6 +21,32 @@ impl PairingVerify<MclG1, MclG2> for MclG1 {
     }
 }
 
+impl Pairing<MclG1, MclG2, MclGT> for MclG1 {
+    fn miller_loop(a: &MclG1, b: &MclG2) -> MclGT {
+        try_init_mcl();
+
+        let mut gt = mcl_gt::default();
+        miller_loop(&mut gt, &a.0, &b.0);
+        MclGT(gt)
+    }
+
+    fn final_exp(f: &MclGT) -> MclGT {
+        try_init_mcl();
+
+        let mut gt = mcl_gt::default();
+        final_exp(&mut gt, &f.0);
+        MclGT(gt)
+    }
+
+    fn pairing(a: &MclG1, b: &MclG2) -> MclGT {
+        try_init_mcl();
+
+        let mut gt = mcl_gt::default();
+        pairing(&mut gt, &a.0, &b.0);
+        MclGT(gt)
+    }
+}
+
 pub fn g1_linear_combination(
     out: &mut MclG1,
     points: &[MclG1],
@@ -34,6 +64,21 @@ pub fn g1_linear_combination(
     );
 }
 
+pub fn g1_linear_combination_short(
+    out: &mut MclG1,
+    points: &[MclG1],
+    scalars: &[MclFr],
+    len: usize,
+    scalar_bits: usize,
+) {
+    *out = msm_nbits::<MclG1, MclFp, MclG1Affine, MclG1ProjAddAffine, MclFr>(
+        points,
+        scalars,
+        len,
+        scalar_bits,
+    );
+}
+
 pub fn pairings_verify(a1: &MclG1, a2: &MclG2, b1: &MclG1, b2: &MclG2) -> bool {
     // Todo: make optimization
     let mut gt0 = mcl_gt::default();
@@ -10,6 +10,8 @@ pub mod fk20_proofs;
 pub mod kzg_proofs;
 pub mod mcl_methods;
 pub mod recovery;
+#[cfg(feature = "serde")]
+mod serialization;
 pub mod types;
 pub mod utils;
 pub mod zero_poly;
@@ -591,7 +591,7 @@ ec_impl![
 #[derive(Default, Debug, Clone)]
 #[repr(C)]
 pub struct mcl_gt {
-    d: [mcl_fp; 12],
+    pub d: [mcl_fp; 12],
 }
 common_impl![mcl_gt, mclBnGT_isEqual, mclBnGT_isZero];
 serialize_impl![
@@ -24,7 +24,7 @@ use kzg::G1ProjAddAffine;
 use kzg::{G1Mul, G1};
 
 use crate::consts::{G1_GENERATOR, G1_IDENTITY, G1_NEGATIVE_GENERATOR};
-use crate::kzg_proofs::g1_linear_combination;
+use crate::kzg_proofs::{g1_linear_combination, g1_linear_combination_short};
 use crate::mcl_methods::mclBnFp_neg;
 use crate::mcl_methods::mcl_fp;
 use crate::mcl_methods::mcl_g1;
@@ -320,6 +320,19 @@ impl G1LinComb<MclFr, MclFp, MclG1Affine, MclG1ProjAddAffine> for MclG1 {
         g1_linear_combination(&mut out, points, scalars, len, precomputation);
         out
     }
+
+    fn g1_lincomb_short(
+        points: &[Self],
+        scalars: &[MclFr],
+        len: usize,
+        scalar_bits: usize,
+    ) -> Self {
+        try_init_mcl();
+
+        let mut out = MclG1::default();
+        g1_linear_combination_short(&mut out, points, scalars, len, scalar_bits);
+        out
+    }
 }
 
 #[repr(C)]
@@ -127,6 +127,14 @@ impl G2 for MclG2 {
         Self(out)
     }
 
+    fn is_valid(&self) -> bool {
+        try_init_mcl();
+
+        let blst = self.to_blst_p2();
+
+        unsafe { blst::blst_p2_in_g2(&blst) }
+    }
+
     fn dbl(&self) -> Self {
         try_init_mcl();
 
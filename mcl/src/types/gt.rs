@@ -0,0 +1,151 @@
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::string::ToString;
+
+use blst::{blst_bendian_from_fp, blst_fp, blst_fp12, blst_fp_from_bendian};
+use kzg::eip_4844::BYTES_PER_GT;
+use kzg::{GTPow, GT};
+
+use crate::mcl_methods::{mcl_fp, mcl_gt, try_init_mcl};
+use crate::types::fr::MclFr;
+
+#[repr(C)]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct MclGT(pub mcl_gt);
+
+impl MclGT {
+    // mcl stores Fp12 coefficients in the same order and Montgomery form as blst
+    pub fn from_blst_fp12(fp12: &blst_fp12) -> Self {
+        try_init_mcl();
+
+        let mut gt = mcl_gt::default();
+        for (fp, blst_fp) in gt.d.iter_mut().zip(
+            fp12.fp6
+                .iter()
+                .flat_map(|fp6| fp6.fp2.iter())
+                .flat_map(|fp2| fp2.fp.iter()),
+        ) {
+            *fp = mcl_fp { d: blst_fp.l };
+        }
+        Self(gt)
+    }
+
+    pub fn to_blst_fp12(&self) -> blst_fp12 {
+        let mut fp12 = blst_fp12::default();
+        for (blst_fp, fp) in fp12
+            .fp6
+            .iter_mut()
+            .flat_map(|fp6| fp6.fp2.iter_mut())
+            .flat_map(|fp2| fp2.fp.iter_mut())
+            .zip(self.0.d.iter())
+        {
+            *blst_fp = blst_fp { l: fp.d };
+        }
+        fp12
+    }
+}
+
+impl GT for MclGT {
+    fn one() -> Self {
+        try_init_mcl();
+
+        Self(mcl_gt::from_int(1))
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        bytes
+            .try_into()
+            .map_err(|_| {
+                format!(
+                    "Invalid byte length. Expected {}, got {}",
+                    BYTES_PER_GT,
+                    bytes.len()
+                )
+            })
+            .and_then(|bytes: &[u8; BYTES_PER_GT]| {
+                let mut fp12 = blst_fp12::default();
+                for (fp, chunk) in fp12
+                    .fp6
+                    .iter_mut()
+                    .flat_map(|fp6| fp6.fp2.iter_mut())
+                    .flat_map(|fp2| fp2.fp.iter_mut())
+                    .zip(bytes.chunks_exact(48))
+                {
+                    let mut canonical = [0u8; 48];
+                    unsafe {
+                        // blst reduces the input modulo p, so check that encoding it back gives
+                        // the same bytes
+                        blst_fp_from_bendian(fp, chunk.as_ptr());
+                        blst_bendian_from_fp(canonical.as_mut_ptr(), fp);
+                    }
+                    if canonical != chunk {
+                        return Err("Invalid field element".to_string());
+                    }
+                }
+
+                if !fp12.in_group() {
+                    return Err("Element is not in the target group".to_string());
+                }
+
+                Ok(Self::from_blst_fp12(&fp12))
+            })
+    }
+
+    fn to_bytes(&self) -> [u8; 576] {
+        let mut out = [0u8; BYTES_PER_GT];
+        for (fp, chunk) in self.0.d.iter().zip(out.chunks_exact_mut(48)) {
+            unsafe {
+                blst_bendian_from_fp(chunk.as_mut_ptr(), &blst_fp { l: fp.d });
+            }
+        }
+        out
+    }
+
+    fn is_one(&self) -> bool {
+        try_init_mcl();
+
+        self.0.is_one()
+    }
+
+    fn mul(&self, b: &Self) -> Self {
+        try_init_mcl();
+
+        let mut out = mcl_gt::default();
+        mcl_gt::mul(&mut out, &self.0, &b.0);
+        Self(out)
+    }
+
+    fn sqr(&self) -> Self {
+        try_init_mcl();
+
+        let mut out = mcl_gt::default();
+        mcl_gt::sqr(&mut out, &self.0);
+        Self(out)
+    }
+
+    fn inverse(&self) -> Self {
+        try_init_mcl();
+
+        let mut out = mcl_gt::default();
+        mcl_gt::inv(&mut out, &self.0);
+        Self(out)
+    }
+
+    fn equals(&self, b: &Self) -> bool {
+        try_init_mcl();
+
+        self.0 == b.0
+    }
+}
+
+impl GTPow<MclFr> for MclGT {
+    fn pow(&self, b: &MclFr) -> Self {
+        try_init_mcl();
+
+        let mut out = mcl_gt::default();
+        mcl_gt::pow(&mut out, &self.0, &b.0);
+        Self(out)
+    }
+}
@@ -5,5 +5,6 @@ pub mod fp;
 pub mod fr;
 pub mod g1;
 pub mod g2;
+pub mod gt;
 pub mod kzg_settings;
 pub mod poly;
@@ -4,10 +4,12 @@ mod tests {
     use kzg_bench::tests::bls12_381::{
         fr_div_by_zero, fr_div_works, fr_equal_works, fr_from_uint64_works, fr_is_null_works,
         fr_is_one_works, fr_is_zero_works, fr_negate_works, fr_pow_works, fr_uint64s_roundtrip,
-        g1_identity_is_identity, g1_identity_is_infinity, g1_linear_combination_infinity_points,
-        g1_make_linear_combination, g1_random_linear_combination, g1_small_linear_combination,
-        log_2_byte_works, p1_mul_works, p1_sub_works, p2_add_or_dbl_works, p2_mul_works,
-        p2_sub_works, pairings_work,
+        g1_from_bytes_batch_works, g1_identity_is_identity, g1_identity_is_infinity,
+        g1_linear_combination_infinity_points, g1_make_linear_combination,
+        g1_random_linear_combination, g1_short_linear_combination, g1_small_linear_combination,
+        gt_arithmetic_works, gt_bytes_roundtrip, gt_miller_loop_and_final_exp_work,
+        gt_pairing_is_bilinear, log_2_byte_works, p1_mul_works, p1_sub_works, p2_add_or_dbl_works,
+        p2_mul_works, p2_sub_works, pairings_work,
     };
 
     use rust_kzg_mcl::kzg_proofs::{g1_linear_combination, pairings_verify};
@@ -15,6 +17,7 @@ mod tests {
     use rust_kzg_mcl::types::fr::MclFr;
     use rust_kzg_mcl::types::g1::{MclG1, MclG1Affine, MclG1ProjAddAffine};
     use rust_kzg_mcl::types::g2::MclG2;
+    use rust_kzg_mcl::types::gt::MclGT;
 
     #[test]
     fn log_2_byte_works_() {
@@ -106,6 +109,11 @@ mod tests {
         g1_identity_is_identity::<MclG1>()
     }
 
+    #[test]
+    fn g1_from_bytes_batch_works_() {
+        g1_from_bytes_batch_works::<MclFr, MclG1>()
+    }
+
     #[test]
     fn g1_make_linear_combination_() {
         g1_make_linear_combination::<MclFr, MclG1, MclFp, MclG1Affine, MclG1ProjAddAffine>(
@@ -128,6 +136,11 @@ mod tests {
         );
     }
 
+    #[test]
+    fn g1_short_linear_combination_() {
+        g1_short_linear_combination::<MclFr, MclG1, MclFp, MclG1Affine, MclG1ProjAddAffine>()
+    }
+
     #[test]
     fn g1_small_linear_combination_() {
         g1_small_linear_combination::<MclFr, MclG1, MclFp, MclG1Affine, MclG1ProjAddAffine>(
@@ -139,4 +152,23 @@ mod tests {
     fn pairings_work_() {
         pairings_work::<MclFr, MclG1, MclG2>(&pairings_verify)
     }
+    #[test]
+    fn gt_pairing_is_bilinear_() {
+        gt_pairing_is_bilinear::<MclFr, MclG1, MclG2, MclGT>()
+    }
+
+    #[test]
+    fn gt_miller_loop_and_final_exp_work_() {
+        gt_miller_loop_and_final_exp_work::<MclFr, MclG1, MclG2, MclGT>()
+    }
+
+    #[test]
+    fn gt_arithmetic_works_() {
+        gt_arithmetic_works::<MclFr, MclG1, MclG2, MclGT>()
+    }
+
+    #[test]
+    fn gt_bytes_roundtrip_() {
+        gt_bytes_roundtrip::<MclG1, MclG2, MclGT>()
+    }
 }
@@ -8,12 +8,13 @@ mod tests {
         load_trusted_setup_file_invalid_format_test, load_trusted_setup_file_valid_format_test,
         load_trusted_setup_invalid_form_test, load_trusted_setup_invalid_g1_byte_length_test,
         load_trusted_setup_invalid_g1_point_test, load_trusted_setup_invalid_g2_byte_length_test,
-        load_trusted_setup_invalid_g2_point_test,
+        load_trusted_setup_invalid_g2_point_test, recover_cells_test,
     };
     use rust_kzg_mcl::eip_4844::{
         blob_to_kzg_commitment, compute_blob_kzg_proof, free_trusted_setup, load_trusted_setup,
         load_trusted_setup_file,
     };
+    use rust_kzg_mcl::eip_7594::{cells_to_blob, compute_cells_and_kzg_proofs, recover_cells};
 
     #[test]
     fn blob_to_kzg_commitment_invalid_blob() {
@@ -77,4 +78,14 @@ mod tests {
             load_trusted_setup_file,
         );
     }
+
+    #[test]
+    fn recover_cells_without_proofs() {
+        recover_cells_test(
+            compute_cells_and_kzg_proofs,
+            recover_cells,
+            cells_to_blob,
+            load_trusted_setup_file,
+        );
+    }
 }
@@ -7,9 +7,16 @@ mod tests {
     use kzg_bench::tests::{
         eip_4844::generate_random_blob_bytes,
         eip_7594::{
+            all_blob_point_proofs_test, batch_verifier_reports_failed_items_test,
+            compute_cell_kzg_proofs_test, custom_transcript_test, das_2d_test,
+            das_extension_factor_and_cell_size_test, das_sampling_test, data_column_sidecar_test,
+            randomized_batch_verification_test, recover_cells_and_kzg_proofs_batch_test,
+            recover_cells_correcting_errors_test, recover_cells_without_proofs_test,
             test_vectors_compute_cells, test_vectors_compute_cells_and_kzg_proofs,
+            test_vectors_compute_columns_for_custody_group,
             test_vectors_compute_verify_cell_kzg_proof_batch_challenge,
-            test_vectors_recover_cells_and_kzg_proofs, test_vectors_verify_cell_kzg_proof_batch,
+            test_vectors_get_custody_groups, test_vectors_recover_cells_and_kzg_proofs,
+            test_vectors_verify_cell_kzg_proof_batch, trusted_point_validation_test,
         },
         utils::get_trusted_setup_path,
     };
@@ -17,6 +24,7 @@ mod tests {
         eip_4844::load_trusted_setup_filename_rust,
         eip_7594::MclBackend,
         types::{fr::MclFr, g1::MclG1, kzg_settings::MclKZGSettings},
+        utils::generate_trusted_setup,
     };
 
     #[test]
@@ -47,6 +55,81 @@ mod tests {
         test_vectors_compute_verify_cell_kzg_proof_batch_challenge::<MclBackend>();
     }
 
+    #[test]
+    pub fn test_vectors_get_custody_groups_() {
+        test_vectors_get_custody_groups();
+    }
+
+    #[test]
+    pub fn test_vectors_compute_columns_for_custody_group_() {
+        test_vectors_compute_columns_for_custody_group();
+    }
+
+    #[test]
+    pub fn batch_verifier_reports_failed_items_() {
+        batch_verifier_reports_failed_items_test::<MclBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn data_column_sidecar_() {
+        data_column_sidecar_test::<MclBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn trusted_point_validation_() {
+        trusted_point_validation_test::<MclBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn custom_transcript_() {
+        custom_transcript_test::<MclBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn randomized_batch_verification_() {
+        randomized_batch_verification_test::<MclBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn all_blob_point_proofs_() {
+        all_blob_point_proofs_test::<MclBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn compute_cell_kzg_proofs_() {
+        compute_cell_kzg_proofs_test::<MclBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn recover_cells_without_proofs_() {
+        recover_cells_without_proofs_test::<MclBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn das_sampling_() {
+        das_sampling_test::<MclBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn recover_cells_correcting_errors_() {
+        recover_cells_correcting_errors_test::<MclBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn recover_cells_and_kzg_proofs_batch_() {
+        recover_cells_and_kzg_proofs_batch_test::<MclBackend>(&load_trusted_setup_filename_rust);
+    }
+
+    #[test]
+    pub fn das_extension_factor_and_cell_size_() {
+        das_extension_factor_and_cell_size_test::<MclBackend>(&generate_trusted_setup);
+    }
+
+    #[test]
+    pub fn das_2d_() {
+        das_2d_test::<MclBackend>(&generate_trusted_setup);
+    }
+
     #[test]
     pub fn test_recover_cells_and_kzg_proofs_succeeds_random_blob() {
         let settings = load_trusted_setup_filename_rust(get_trusted_setup_path().as_str()).unwrap();
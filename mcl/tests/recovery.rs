@@ -26,4 +26,14 @@ mod tests {
     fn more_than_half_missing_() {
         more_than_half_missing::<MclFr, MclFFTSettings, MclPoly, MclPoly>();
     }
+
+    #[test]
+    fn decode_with_errors_() {
+        decode_with_errors::<MclFr, MclFFTSettings>();
+    }
+
+    #[test]
+    fn erasure_coder_() {
+        erasure_coder::<MclFr, MclFFTSettings, MclPoly>();
+    }
 }
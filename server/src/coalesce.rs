@@ -0,0 +1,151 @@
+//! Coalescing of concurrent verification requests into a single batch verification.
+//!
+//! Requests are queued for a worker thread, which takes every request that arrived while the
+//! previous batch was being verified and checks them with one call of the batch verifier. Only if
+//! the combined batch fails is every request verified on its own, so that an invalid proof in one
+//! request does not affect the result of the others.
+
+use std::{
+    sync::{mpsc, Arc},
+    thread,
+};
+
+use kzg::eth::{BYTES_PER_BLOB, BYTES_PER_CELL, BYTES_PER_COMMITMENT, BYTES_PER_PROOF};
+use rust_kzg_dyn::{DynKzg, KzgApi};
+
+/// Proofs that can be verified together with a single batch verification.
+pub trait Batch: Clone + Send + 'static {
+    fn num_proofs(&self) -> usize;
+
+    fn append(&mut self, other: &Self);
+
+    fn verify(&self, kzg: &DynKzg) -> Result<bool, String>;
+}
+
+/// Arguments of `verify_blob_kzg_proof_batch`.
+#[derive(Debug, Clone, Default)]
+pub struct BlobProofs {
+    pub blobs: Vec<[u8; BYTES_PER_BLOB]>,
+    pub commitments: Vec<[u8; BYTES_PER_COMMITMENT]>,
+    pub proofs: Vec<[u8; BYTES_PER_PROOF]>,
+}
+
+impl Batch for BlobProofs {
+    fn num_proofs(&self) -> usize {
+        self.proofs.len()
+    }
+
+    fn append(&mut self, other: &Self) {
+        self.blobs.extend_from_slice(&other.blobs);
+        self.commitments.extend_from_slice(&other.commitments);
+        self.proofs.extend_from_slice(&other.proofs);
+    }
+
+    fn verify(&self, kzg: &DynKzg) -> Result<bool, String> {
+        kzg.verify_blob_kzg_proof_batch(&self.blobs, &self.commitments, &self.proofs)
+    }
+}
+
+/// Arguments of `verify_cell_kzg_proof_batch`.
+#[derive(Debug, Clone, Default)]
+pub struct CellProofs {
+    pub commitments: Vec<[u8; BYTES_PER_COMMITMENT]>,
+    pub cell_indices: Vec<usize>,
+    pub cells: Vec<[u8; BYTES_PER_CELL]>,
+    pub proofs: Vec<[u8; BYTES_PER_PROOF]>,
+}
+
+impl Batch for CellProofs {
+    fn num_proofs(&self) -> usize {
+        self.proofs.len()
+    }
+
+    fn append(&mut self, other: &Self) {
+        self.commitments.extend_from_slice(&other.commitments);
+        self.cell_indices.extend_from_slice(&other.cell_indices);
+        self.cells.extend_from_slice(&other.cells);
+        self.proofs.extend_from_slice(&other.proofs);
+    }
+
+    fn verify(&self, kzg: &DynKzg) -> Result<bool, String> {
+        kzg.verify_cell_kzg_proof_batch(
+            &self.commitments,
+            &self.cell_indices,
+            &self.cells,
+            &self.proofs,
+        )
+    }
+}
+
+struct Job<T> {
+    batch: T,
+    result: mpsc::Sender<Result<bool, String>>,
+}
+
+/// Queue of a worker thread verifying requests of type `T`. The worker stops when the coalescer
+/// is dropped.
+pub struct Coalescer<T: Batch> {
+    jobs: mpsc::Sender<Job<T>>,
+}
+
+impl<T: Batch> Coalescer<T> {
+    /// Starts the worker thread. A combined batch holds at most `max_batch_proofs` proofs, unless
+    /// a single request is larger.
+    pub fn new(kzg: Arc<DynKzg>, max_batch_proofs: usize) -> Self {
+        let (jobs, queue) = mpsc::channel();
+        thread::spawn(move || work(&kzg, &queue, max_batch_proofs));
+
+        Self { jobs }
+    }
+
+    /// Verifies the batch together with concurrent requests. The arguments must be of equal
+    /// length, otherwise the requests combined with it would be misaligned.
+    pub fn verify(&self, batch: T) -> Result<bool, String> {
+        let (result, receiver) = mpsc::channel();
+        self.jobs
+            .send(Job { batch, result })
+            .map_err(|_| "Verification worker stopped".to_string())?;
+
+        receiver
+            .recv()
+            .map_err(|_| "Verification worker stopped".to_string())?
+    }
+}
+
+fn work<T: Batch>(kzg: &DynKzg, queue: &mpsc::Receiver<Job<T>>, max_batch_proofs: usize) {
+    while let Ok(first) = queue.recv() {
+        let mut num_proofs = first.batch.num_proofs();
+        let mut jobs = vec![first];
+        while num_proofs < max_batch_proofs {
+            let Ok(job) = queue.try_recv() else {
+                break;
+            };
+            num_proofs += job.batch.num_proofs();
+            jobs.push(job);
+        }
+
+        verify_jobs(kzg, jobs);
+    }
+}
+
+fn verify_jobs<T: Batch>(kzg: &DynKzg, jobs: Vec<Job<T>>) {
+    if let [job] = &jobs[..] {
+        let _ = job.result.send(job.batch.verify(kzg));
+        return;
+    }
+
+    let mut combined = jobs[0].batch.clone();
+    for job in &jobs[1..] {
+        combined.append(&job.batch);
+    }
+
+    if combined.verify(kzg) == Ok(true) {
+        for job in jobs {
+            let _ = job.result.send(Ok(true));
+        }
+    } else {
+        for job in jobs {
+            let _ = job.result.send(job.batch.verify(kzg));
+        }
+    }
+}
@@ -0,0 +1,254 @@
+//! Local KZG service: a single trusted setup shared by several processes over a Unix socket or a
+//! localhost TCP port, using the protocol of `rust-kzg-client`.
+//!
+//! Concurrent `verify_blob_kzg_proof`, `verify_blob_kzg_proof_batch` and
+//! `verify_cell_kzg_proof_batch` requests are coalesced into single batch verifications, see
+//! [`coalesce`].
+
+pub mod coalesce;
+
+use std::{
+    io::{Read, Write},
+    net::TcpListener,
+    sync::Arc,
+    thread,
+};
+
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+
+use kzg::eth::{
+    eip_7594::CellsKzgProofs, BYTES_PER_BLOB, BYTES_PER_CELL, BYTES_PER_COMMITMENT,
+    BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
+};
+use rust_kzg_client::protocol::{encode_response, read_frame, write_frame, Request};
+use rust_kzg_dyn::{DynKzg, KzgApi};
+
+use crate::coalesce::{BlobProofs, CellProofs, Coalescer};
+
+/// Default upper bound on the number of proofs in a coalesced batch.
+pub const DEFAULT_MAX_BATCH_PROOFS: usize = 1024;
+
+/// Trusted setup shared by all connections. Implements [`KzgApi`] itself, with verification
+/// requests going through the coalescers.
+pub struct Service {
+    kzg: Arc<DynKzg>,
+    blob_proofs: Coalescer<BlobProofs>,
+    cell_proofs: Coalescer<CellProofs>,
+}
+
+impl Service {
+    pub fn new(kzg: DynKzg, max_batch_proofs: usize) -> Self {
+        let kzg = Arc::new(kzg);
+
+        Self {
+            blob_proofs: Coalescer::new(kzg.clone(), max_batch_proofs),
+            cell_proofs: Coalescer::new(kzg.clone(), max_batch_proofs),
+            kzg,
+        }
+    }
+
+    pub fn kzg(&self) -> &DynKzg {
+        &self.kzg
+    }
+
+    /// Runs a request, returning the encoded response.
+    pub fn handle(&self, request: &Request<'_>) -> Vec<u8> {
+        match request {
+            Request::BlobToKzgCommitment { blob } => {
+                encode_response(&self.blob_to_kzg_commitment(blob))
+            }
+            Request::ComputeKzgProof { blob, z } => {
+                encode_response(&self.compute_kzg_proof(blob, z))
+            }
+            Request::ComputeBlobKzgProof { blob, commitment } => {
+                encode_response(&self.compute_blob_kzg_proof(blob, commitment))
+            }
+            Request::VerifyKzgProof {
+                commitment,
+                z,
+                y,
+                proof,
+            } => encode_response(&self.verify_kzg_proof(commitment, z, y, proof)),
+            Request::VerifyBlobKzgProof {
+                blob,
+                commitment,
+                proof,
+            } => encode_response(&self.verify_blob_kzg_proof(blob, commitment, proof)),
+            Request::VerifyBlobKzgProofBatch {
+                blobs,
+                commitments,
+                proofs,
+            } => encode_response(&self.verify_blob_kzg_proof_batch(blobs, commitments, proofs)),
+            Request::ComputeCells { blob } => encode_response(&self.compute_cells(blob)),
+            Request::ComputeCellsAndKzgProofs { blob } => {
+                encode_response(&self.compute_cells_and_kzg_proofs(blob))
+            }
+            Request::RecoverCellsAndKzgProofs {
+                cell_indices,
+                cells,
+            } => encode_response(&self.recover_cells_and_kzg_proofs(cell_indices, cells)),
+            Request::VerifyCellKzgProofBatch {
+                commitments,
+                cell_indices,
+                cells,
+                proofs,
+            } => encode_response(&self.verify_cell_kzg_proof_batch(
+                commitments,
+                cell_indices,
+                cells,
+                proofs,
+            )),
+        }
+    }
+
+    /// Serves requests of a single connection until it is closed. Malformed requests close the
+    /// connection, as the framing can no longer be trusted.
+    pub fn serve_connection(&self, mut stream: impl Read + Write) {
+        while let Ok(Some(body)) = read_frame(&mut stream) {
+            let response = match Request::decode(&body) {
+                Ok(request) => self.handle(&request),
+                Err(err) => {
+                    let _ = write_frame(
+                        &mut stream,
+                        &encode_response::<bool>(&Err(format!("Invalid request: {err}"))),
+                    );
+                    return;
+                }
+            };
+
+            if write_frame(&mut stream, &response).is_err() {
+                return;
+            }
+        }
+    }
+
+    /// Accepts connections on the Unix socket, serving each on its own thread.
+    #[cfg(unix)]
+    pub fn serve_unix(self: &Arc<Self>, listener: UnixListener) {
+        for stream in listener.incoming().flatten() {
+            let service = self.clone();
+            thread::spawn(move || service.serve_connection(stream));
+        }
+    }
+
+    /// Accepts connections on the TCP socket, serving each on its own thread.
+    pub fn serve_tcp(self: &Arc<Self>, listener: TcpListener) {
+        for stream in listener.incoming().flatten() {
+            let _ = stream.set_nodelay(true);
+            let service = self.clone();
+            thread::spawn(move || service.serve_connection(stream));
+        }
+    }
+}
+
+impl KzgApi for Service {
+    fn blob_to_kzg_commitment(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<[u8; BYTES_PER_COMMITMENT], String> {
+        self.kzg.blob_to_kzg_commitment(blob)
+    }
+
+    fn compute_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        z: &[u8; BYTES_PER_FIELD_ELEMENT],
+    ) -> Result<([u8; BYTES_PER_PROOF], [u8; BYTES_PER_FIELD_ELEMENT]), String> {
+        self.kzg.compute_kzg_proof(blob, z)
+    }
+
+    fn compute_blob_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+    ) -> Result<[u8; BYTES_PER_PROOF], String> {
+        self.kzg.compute_blob_kzg_proof(blob, commitment)
+    }
+
+    fn verify_kzg_proof(
+        &self,
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+        z: &[u8; BYTES_PER_FIELD_ELEMENT],
+        y: &[u8; BYTES_PER_FIELD_ELEMENT],
+        proof: &[u8; BYTES_PER_PROOF],
+    ) -> Result<bool, String> {
+        self.kzg.verify_kzg_proof(commitment, z, y, proof)
+    }
+
+    fn verify_blob_kzg_proof(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+        commitment: &[u8; BYTES_PER_COMMITMENT],
+        proof: &[u8; BYTES_PER_PROOF],
+    ) -> Result<bool, String> {
+        self.blob_proofs.verify(BlobProofs {
+            blobs: vec![*blob],
+            commitments: vec![*commitment],
+            proofs: vec![*proof],
+        })
+    }
+
+    fn verify_blob_kzg_proof_batch(
+        &self,
+        blobs: &[[u8; BYTES_PER_BLOB]],
+        commitments: &[[u8; BYTES_PER_COMMITMENT]],
+        proofs: &[[u8; BYTES_PER_PROOF]],
+    ) -> Result<bool, String> {
+        if blobs.len() != commitments.len() || blobs.len() != proofs.len() {
+            return self
+                .kzg
+                .verify_blob_kzg_proof_batch(blobs, commitments, proofs);
+        }
+
+        self.blob_proofs.verify(BlobProofs {
+            blobs: blobs.to_vec(),
+            commitments: commitments.to_vec(),
+            proofs: proofs.to_vec(),
+        })
+    }
+
+    fn compute_cells(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<Vec<[u8; BYTES_PER_CELL]>, String> {
+        self.kzg.compute_cells(blob)
+    }
+
+    fn compute_cells_and_kzg_proofs(
+        &self,
+        blob: &[u8; BYTES_PER_BLOB],
+    ) -> Result<CellsKzgProofs, String> {
+        self.kzg.compute_cells_and_kzg_proofs(blob)
+    }
+
+    fn recover_cells_and_kzg_proofs(
+        &self,
+        cell_indices: &[usize],
+        cells: &[[u8; BYTES_PER_CELL]],
+    ) -> Result<CellsKzgProofs, String> {
+        self.kzg.recover_cells_and_kzg_proofs(cell_indices, cells)
+    }
+
+    fn verify_cell_kzg_proof_batch(
+        &self,
+        commitments: &[[u8; BYTES_PER_COMMITMENT]],
+        cell_indices: &[usize],
+        cells: &[[u8; BYTES_PER_CELL]],
+        proofs: &[[u8; BYTES_PER_PROOF]],
+    ) -> Result<bool, String> {
+        let len = proofs.len();
+        if commitments.len() != len || cell_indices.len() != len || cells.len() != len {
+            return self
+                .kzg
+                .verify_cell_kzg_proof_batch(commitments, cell_indices, cells, proofs);
+        }
+
+        self.cell_proofs.verify(CellProofs {
+            commitments: commitments.to_vec(),
+            cell_indices: cell_indices.to_vec(),
+            cells: cells.to_vec(),
+            proofs: proofs.to_vec(),
+        })
+    }
+}
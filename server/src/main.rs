@@ -0,0 +1,118 @@
+//! `kzg-server`: loads the trusted setup once and serves the KZG operations to local processes.
+
+use std::{
+    fs,
+    net::{SocketAddr, TcpListener},
+    path::PathBuf,
+    process::ExitCode,
+    sync::Arc,
+    thread,
+};
+
+use clap::Parser;
+use rust_kzg_dyn::{Backend, DynKzg};
+use rust_kzg_server::{Service, DEFAULT_MAX_BATCH_PROOFS};
+
+/// Serve EIP-4844 and EIP-7594 KZG operations over a Unix socket or a localhost TCP port,
+/// coalescing concurrent verifications into batches.
+#[derive(Parser)]
+#[command(name = "kzg-server", version)]
+#[command(group = clap::ArgGroup::new("listen_on").required(true).multiple(true))]
+struct Cli {
+    /// Backend to run the operations with [default: first enabled backend]
+    #[arg(short, long)]
+    backend: Option<Backend>,
+
+    /// Trusted setup file, in the text format of c-kzg-4844
+    #[arg(short, long, env = "TRUSTED_SETUP")]
+    trusted_setup: PathBuf,
+
+    /// Unix socket to listen on
+    #[cfg(unix)]
+    #[arg(long, group = "listen_on")]
+    socket: Option<PathBuf>,
+
+    /// Loopback address to listen on, e.g. 127.0.0.1:7457
+    #[arg(long, group = "listen_on")]
+    listen: Option<SocketAddr>,
+
+    /// Maximum number of proofs in a coalesced batch
+    #[arg(long, default_value_t = DEFAULT_MAX_BATCH_PROOFS)]
+    max_batch_proofs: usize,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), String> {
+    let backend = cli.backend.unwrap_or(Backend::ENABLED[0]);
+    let contents = fs::read_to_string(&cli.trusted_setup)
+        .map_err(|err| format!("Unable to read {}: {err}", cli.trusted_setup.display()))?;
+
+    // bind before loading the trusted setup, so that configuration errors are reported at once
+    let tcp = cli
+        .listen
+        .map(|address| {
+            if !address.ip().is_loopback() {
+                return Err(format!(
+                    "Refusing to listen on non-loopback address {address}"
+                ));
+            }
+            TcpListener::bind(address).map_err(|err| format!("Unable to bind {address}: {err}"))
+        })
+        .transpose()?;
+    #[cfg(unix)]
+    let unix = cli.socket.as_deref().map(bind_unix).transpose()?;
+
+    let kzg = DynKzg::load_trusted_setup_string(backend, &contents)?;
+    let service = Arc::new(Service::new(kzg, cli.max_batch_proofs));
+    eprintln!("Loaded trusted setup into {backend} backend");
+
+    let mut threads = Vec::new();
+    if let Some(listener) = tcp {
+        eprintln!("Listening on {}", cli.listen.unwrap());
+        let service = service.clone();
+        threads.push(thread::spawn(move || service.serve_tcp(listener)));
+    }
+    #[cfg(unix)]
+    if let Some(listener) = unix {
+        eprintln!("Listening on {}", cli.socket.as_ref().unwrap().display());
+        let service = service.clone();
+        threads.push(thread::spawn(move || service.serve_unix(listener)));
+    }
+
+    for thread in threads {
+        let _ = thread.join();
+    }
+
+    Ok(())
+}
+
+/// Binds the Unix socket, replacing a stale socket file left by a previous run.
+#[cfg(unix)]
+fn bind_unix(path: &std::path::Path) -> Result<std::os::unix::net::UnixListener, String> {
+    use std::os::unix::{
+        fs::FileTypeExt,
+        net::{UnixListener, UnixStream},
+    };
+
+    if let Ok(metadata) = fs::symlink_metadata(path) {
+        if metadata.file_type().is_socket() {
+            if UnixStream::connect(path).is_ok() {
+                return Err(format!("{} is in use by a running server", path.display()));
+            }
+
+            fs::remove_file(path)
+                .map_err(|err| format!("Unable to remove {}: {err}", path.display()))?;
+        }
+    }
+
+    UnixListener::bind(path).map_err(|err| format!("Unable to bind {}: {err}", path.display()))
+}
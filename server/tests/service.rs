@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests {
+    use std::{net::TcpListener, sync::Arc, thread};
+
+    use kzg::eth::CELLS_PER_EXT_BLOB;
+    use kzg_bench::tests::{eip_4844::generate_random_blob_bytes, utils::get_trusted_setup_path};
+    use rust_kzg_client::{Address, KzgApi, KzgClient};
+    use rust_kzg_dyn::{Backend, DynKzg};
+    use rust_kzg_server::{Service, DEFAULT_MAX_BATCH_PROOFS};
+
+    fn start_service() -> (Arc<Service>, KzgClient) {
+        let kzg =
+            DynKzg::load_trusted_setup_file(Backend::ENABLED[0].name(), &get_trusted_setup_path())
+                .unwrap();
+        let service = Arc::new(Service::new(kzg, DEFAULT_MAX_BATCH_PROOFS));
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = Address::Tcp(listener.local_addr().unwrap());
+        let server = service.clone();
+        thread::spawn(move || server.serve_tcp(listener));
+
+        (service, KzgClient::connect(address).unwrap())
+    }
+
+    #[test]
+    fn client_matches_in_process_api_() {
+        let (service, client) = start_service();
+        let kzg = service.kzg();
+        let mut rng = rand::thread_rng();
+        let blob = generate_random_blob_bytes(&mut rng);
+
+        let commitment = client.blob_to_kzg_commitment(&blob).unwrap();
+        assert_eq!(commitment, kzg.blob_to_kzg_commitment(&blob).unwrap());
+
+        let z = blob[..32].try_into().unwrap();
+        let (proof, y) = client.compute_kzg_proof(&blob, &z).unwrap();
+        assert_eq!((proof, y), kzg.compute_kzg_proof(&blob, &z).unwrap());
+        assert!(client
+            .verify_kzg_proof(&commitment, &z, &y, &proof)
+            .unwrap());
+
+        let proof = client.compute_blob_kzg_proof(&blob, &commitment).unwrap();
+        assert!(client
+            .verify_blob_kzg_proof(&blob, &commitment, &proof)
+            .unwrap());
+        assert!(client
+            .verify_blob_kzg_proof_batch(&[blob], &[commitment], &[proof])
+            .unwrap());
+
+        let (cells, proofs) = client.compute_cells_and_kzg_proofs(&blob).unwrap();
+        assert_eq!(client.compute_cells(&blob).unwrap(), cells);
+
+        let cell_indices = (0..CELLS_PER_EXT_BLOB).step_by(2).collect::<Vec<_>>();
+        let half = cell_indices.iter().map(|&i| cells[i]).collect::<Vec<_>>();
+        assert_eq!(
+            client
+                .recover_cells_and_kzg_proofs(&cell_indices, &half)
+                .unwrap(),
+            (cells.clone(), proofs.clone())
+        );
+
+        let all_indices = (0..CELLS_PER_EXT_BLOB).collect::<Vec<_>>();
+        assert!(client
+            .verify_cell_kzg_proof_batch(
+                &vec![commitment; CELLS_PER_EXT_BLOB],
+                &all_indices,
+                &cells,
+                &proofs
+            )
+            .unwrap());
+    }
+
+    #[test]
+    fn errors_are_forwarded_() {
+        let (service, client) = start_service();
+
+        let invalid_blob = [0xffu8; kzg::eth::BYTES_PER_BLOB];
+        assert_eq!(
+            client.blob_to_kzg_commitment(&invalid_blob),
+            service.kzg().blob_to_kzg_commitment(&invalid_blob)
+        );
+        assert!(client.blob_to_kzg_commitment(&invalid_blob).is_err());
+
+        // mismatched lengths are not coalesced
+        assert!(client
+            .verify_blob_kzg_proof_batch(&[], &[[0u8; 48]], &[])
+            .is_err());
+        assert!(client
+            .verify_cell_kzg_proof_batch(&[[0u8; 48]], &[0, 1], &[], &[])
+            .is_err());
+    }
+
+    #[test]
+    fn concurrent_verifications_are_isolated_() {
+        let (service, client) = start_service();
+        let client = Arc::new(client);
+        let mut rng = rand::thread_rng();
+
+        let requests = (0..8)
+            .map(|i| {
+                let blob = generate_random_blob_bytes(&mut rng);
+                let commitment = service.kzg().blob_to_kzg_commitment(&blob).unwrap();
+                let mut proof = service
+                    .kzg()
+                    .compute_blob_kzg_proof(&blob, &commitment)
+                    .unwrap();
+                // every third request uses the proof of another commitment
+                if i % 3 == 0 {
+                    proof = service
+                        .kzg()
+                        .compute_blob_kzg_proof(&generate_random_blob_bytes(&mut rng), &commitment)
+                        .unwrap();
+                }
+
+                (blob, commitment, proof, i % 3 != 0)
+            })
+            .collect::<Vec<_>>();
+
+        let threads = requests
+            .into_iter()
+            .map(|(blob, commitment, proof, valid)| {
+                let client = client.clone();
+                thread::spawn(move || {
+                    for _ in 0..3 {
+                        assert_eq!(
+                            client.verify_blob_kzg_proof(&blob, &commitment, &proof),
+                            Ok(valid)
+                        );
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+    }
+}
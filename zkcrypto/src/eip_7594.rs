@@ -10,6 +10,7 @@ use crate::kzg_types::ZG1Affine;
 use crate::kzg_types::ZG1ProjAddAffine;
 use crate::kzg_types::ZG1;
 use crate::kzg_types::ZG2;
+use crate::kzg_types::ZGT;
 use crate::poly::PolyData;
 
 pub struct ZBackend;
@@ -21,6 +22,7 @@ impl EcBackend for ZBackend {
     type G1ProjAddAffine = ZG1ProjAddAffine;
     type G1 = ZG1;
     type G2 = ZG2;
+    type GT = ZGT;
     type Poly = PolyData;
     type FFTSettings = FFTSettings;
     type KZGSettings = KZGSettings;
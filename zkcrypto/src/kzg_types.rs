@@ -13,16 +13,19 @@ use crate::utils::{
     pc_g2projective_into_blst_p2, PRECOMPUTATION_TABLES,
 };
 use arbitrary::Arbitrary;
-use bls12_381::{Fp, G1Affine, G1Projective, G2Affine, G2Projective, Scalar, MODULUS, R2};
+use bls12_381::{
+    multi_miller_loop, Fp, Fp12, Fp2, Fp6, G1Affine, G1Projective, G2Affine, G2Prepared,
+    G2Projective, Gt, MillerLoopResult, Scalar, MODULUS, R2,
+};
 use ff::Field;
 use kzg::common_utils::reverse_bit_order;
-use kzg::eip_4844::{BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2};
+use kzg::eip_4844::{BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2, BYTES_PER_GT};
 use kzg::eth::c_bindings::{blst_fr, blst_p1, blst_p2, CKZGSettings};
 use kzg::msm::precompute::{precompute, PrecomputationTable};
 use kzg::{eth, G1Affine as G1AffineTrait};
 use kzg::{
     FFTFr, FFTSettings, Fr as KzgFr, G1Fp, G1GetFp, G1LinComb, G1Mul, G1ProjAddAffine, G2Mul,
-    KZGSettings, PairingVerify, Poly, Scalar256, G1, G2,
+    GTPow, KZGSettings, Pairing, PairingVerify, Poly, Scalar256, G1, G2, GT,
 };
 use std::hash::Hash;
 use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
@@ -397,13 +400,13 @@ impl Hash for ZG1 {
 }
 
 impl ZG1 {
-    pub const fn from_blst_p1(p1: blst_p1) -> Self {
+    pub fn from_blst_p1(p1: blst_p1) -> Self {
         Self {
             proj: blst_p1_into_pc_g1projective(&p1),
         }
     }
 
-    pub const fn to_blst_p1(&self) -> blst_p1 {
+    pub fn to_blst_p1(&self) -> blst_p1 {
         pc_g1projective_into_blst_p1(self.proj)
     }
     pub const fn from_g1_projective(proj: G1Projective) -> Self {
@@ -473,6 +476,24 @@ impl G1 for ZG1 {
             })
     }
 
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, String> {
+        bytes
+            .try_into()
+            .map_err(|_| {
+                format!(
+                    "Invalid byte length. Expected {}, got {}",
+                    BYTES_PER_G1,
+                    bytes.len()
+                )
+            })
+            .and_then(|bytes: &[u8; BYTES_PER_G1]| {
+                let affine: Option<G1Affine> = G1Affine::from_compressed_unchecked(bytes).into();
+                affine
+                    .map(ZG1::affine_to_projective)
+                    .ok_or_else(|| "Failed to deserialize G1: Affine not available".to_string())
+            })
+    }
+
     fn from_hex(hex: &str) -> Result<Self, String> {
         let bytes = hex::decode(&hex[2..]).unwrap();
         Self::from_bytes(&bytes)
@@ -661,6 +682,13 @@ impl G1AffineTrait<ZG1, ZFp> for ZG1Affine {
             .into_option()
             .ok_or("Failed to deserialize point".to_owned())
     }
+
+    fn from_bytes_uncompressed_unchecked(bytes: [u8; 96]) -> Result<Self, String> {
+        G1Affine::from_uncompressed_unchecked(&bytes)
+            .map(Self)
+            .into_option()
+            .ok_or("Failed to deserialize point".to_owned())
+    }
 }
 
 #[derive(Debug)]
@@ -731,6 +759,30 @@ impl G1LinComb<ZFr, ZFp, ZG1Affine, ZG1ProjAddAffine> for ZG1 {
         g1_linear_combination(&mut out, points, scalars, len, precomputation);
         out
     }
+
+    fn g1_lincomb_batch(
+        points: &[Vec<Self>],
+        scalars: &[Vec<ZFr>],
+        _precomputation: Option<&PrecomputationTable<ZFr, Self, ZFp, ZG1Affine, ZG1ProjAddAffine>>,
+    ) -> Result<Vec<Self>, String> {
+        // Precomputed tables are not used by zkcrypto MSM, so they are ignored here as well,
+        // even if another crate enables a precomputation feature of `kzg`
+        if points.len() != scalars.len() {
+            return Err("Invalid batch size".to_owned());
+        }
+
+        points
+            .iter()
+            .zip(scalars.iter())
+            .map(|(points, scalars)| {
+                if points.len() != scalars.len() {
+                    return Err("Invalid point count length".to_owned());
+                }
+
+                Ok(Self::g1_lincomb(points, scalars, points.len(), None))
+            })
+            .collect()
+    }
 }
 
 impl PairingVerify<ZG1, ZG2> for ZG1 {
@@ -739,13 +791,37 @@ impl PairingVerify<ZG1, ZG2> for ZG1 {
     }
 }
 
+impl Pairing<ZG1, ZG2, ZGT> for ZG1 {
+    fn miller_loop(a: &ZG1, b: &ZG2) -> ZGT {
+        Self::multi_miller_loop(core::slice::from_ref(a), core::slice::from_ref(b))
+    }
+
+    fn final_exp(f: &ZGT) -> ZGT {
+        // Final exponentiation of zero would otherwise panic on inversion
+        if bool::from(f.0.is_zero()) {
+            return *f;
+        }
+        ZGT(MillerLoopResult(f.0).final_exponentiation().0)
+    }
+
+    fn multi_miller_loop(a: &[ZG1], b: &[ZG2]) -> ZGT {
+        let a = a.iter().map(|a| G1Affine::from(a.proj)).collect::<Vec<_>>();
+        let b = b
+            .iter()
+            .map(|b| G2Prepared::from(G2Affine::from(b.proj)))
+            .collect::<Vec<_>>();
+        let terms = a.iter().zip(b.iter()).collect::<Vec<_>>();
+        ZGT(multi_miller_loop(&terms).0)
+    }
+}
+
 #[derive(Debug, Default, PartialEq, Eq, Clone)]
 pub struct ZG2 {
     pub proj: G2Projective,
 }
 
 impl ZG2 {
-    pub const fn from_blst_p2(p2: blst_p2) -> Self {
+    pub fn from_blst_p2(p2: blst_p2) -> Self {
         Self {
             proj: blst_p2_into_pc_g2projective(&p2),
         }
@@ -753,7 +829,7 @@ impl ZG2 {
     pub const fn from_g2_projective(proj: G2Projective) -> Self {
         Self { proj }
     }
-    pub const fn to_blst_p2(&self) -> blst_p2 {
+    pub fn to_blst_p2(&self) -> blst_p2 {
         pc_g2projective_into_blst_p2(self.proj)
     }
 }
@@ -779,8 +855,29 @@ impl G2 for ZG2 {
                 )
             })
             .and_then(|bytes: &[u8; BYTES_PER_G2]| {
-                let affine = G2Affine::from_compressed(bytes).unwrap();
-                Ok(ZG2::from_g2_projective(G2Projective::from(affine)))
+                let affine: Option<G2Affine> = G2Affine::from_compressed(bytes).into();
+                match affine {
+                    Some(x) => Ok(ZG2::from_g2_projective(G2Projective::from(x))),
+                    None => Err("Failed to deserialize G2: Affine not available".to_string()),
+                }
+            })
+    }
+
+    fn from_bytes_unchecked(bytes: &[u8]) -> Result<Self, String> {
+        bytes
+            .try_into()
+            .map_err(|_| {
+                format!(
+                    "Invalid byte length. Expected {}, got {}",
+                    BYTES_PER_G2,
+                    bytes.len()
+                )
+            })
+            .and_then(|bytes: &[u8; BYTES_PER_G2]| {
+                let affine: Option<G2Affine> = G2Affine::from_compressed_unchecked(bytes).into();
+                affine
+                    .map(|x| ZG2::from_g2_projective(G2Projective::from(x)))
+                    .ok_or_else(|| "Failed to deserialize G2: Affine not available".to_string())
             })
     }
 
@@ -795,6 +892,10 @@ impl G2 for ZG2 {
         }
     }
 
+    fn is_valid(&self) -> bool {
+        bool::from(self.proj.is_on_curve())
+    }
+
     fn dbl(&self) -> Self {
         Self {
             proj: self.proj.double(),
@@ -820,6 +921,115 @@ impl G2Mul<ZFr> for ZG2 {
     }
 }
 
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct ZGT(pub Fp12);
+
+impl ZGT {
+    fn coeffs(&self) -> [Fp; 12] {
+        let f = &self.0;
+        [
+            f.c0.c0.c0, f.c0.c0.c1, f.c0.c1.c0, f.c0.c1.c1, f.c0.c2.c0, f.c0.c2.c1, f.c1.c0.c0,
+            f.c1.c0.c1, f.c1.c1.c0, f.c1.c1.c1, f.c1.c2.c0, f.c1.c2.c1,
+        ]
+    }
+
+    fn from_coeffs(c: &[Fp; 12]) -> Self {
+        Self(Fp12 {
+            c0: Fp6 {
+                c0: Fp2 { c0: c[0], c1: c[1] },
+                c1: Fp2 { c0: c[2], c1: c[3] },
+                c2: Fp2 { c0: c[4], c1: c[5] },
+            },
+            c1: Fp6 {
+                c0: Fp2 { c0: c[6], c1: c[7] },
+                c1: Fp2 { c0: c[8], c1: c[9] },
+                c2: Fp2 {
+                    c0: c[10],
+                    c1: c[11],
+                },
+            },
+        })
+    }
+}
+
+impl GT for ZGT {
+    fn one() -> Self {
+        Self(Fp12::one())
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        bytes
+            .try_into()
+            .map_err(|_| {
+                format!(
+                    "Invalid byte length. Expected {}, got {}",
+                    BYTES_PER_GT,
+                    bytes.len()
+                )
+            })
+            .and_then(|bytes: &[u8; BYTES_PER_GT]| {
+                let mut coeffs = [Fp::zero(); 12];
+                for (fp, chunk) in coeffs.iter_mut().zip(bytes.chunks_exact(48)) {
+                    let fp_opt = Fp::from_bytes(chunk.try_into().unwrap());
+                    if fp_opt.is_none().into() {
+                        return Err("Invalid field element".to_string());
+                    }
+                    *fp = fp_opt.unwrap();
+                }
+
+                // f^r == 1 is checked as f^(r - 1) * f == 1, as r itself is not a valid scalar
+                let gt = Self::from_coeffs(&coeffs);
+                let order_minus_one = -Scalar::one();
+                if !gt
+                    .pow(&ZFr {
+                        fr: order_minus_one,
+                    })
+                    .mul(&gt)
+                    .is_one()
+                {
+                    return Err("Element is not in the target group".to_string());
+                }
+
+                Ok(gt)
+            })
+    }
+
+    fn to_bytes(&self) -> [u8; 576] {
+        let mut out = [0u8; BYTES_PER_GT];
+        for (fp, chunk) in self.coeffs().iter().zip(out.chunks_exact_mut(48)) {
+            chunk.copy_from_slice(&fp.to_bytes());
+        }
+        out
+    }
+
+    fn is_one(&self) -> bool {
+        self.0 == Fp12::one()
+    }
+
+    fn mul(&self, b: &Self) -> Self {
+        Self(self.0 * b.0)
+    }
+
+    fn sqr(&self) -> Self {
+        Self(self.0.square())
+    }
+
+    fn inverse(&self) -> Self {
+        Self(self.0.invert().unwrap_or(Fp12::zero()))
+    }
+
+    fn equals(&self, b: &Self) -> bool {
+        self.0 == b.0
+    }
+}
+
+impl GTPow<ZFr> for ZGT {
+    fn pow(&self, b: &ZFr) -> Self {
+        // Gt scalar multiplication is a plain square-and-multiply over Fp12
+        Self((Gt(self.0) * b.fr).0)
+    }
+}
+
 impl Default for ZFFTSettings {
     fn default() -> Self {
         Self::new(0).unwrap()
@@ -966,9 +1176,13 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine, ZG1ProjA
         }
 
         let field_elements_per_blob = g1_monomial.len();
-        let field_elements_per_ext_blob = field_elements_per_blob * 2;
+        // The extended blob spans the FFT domain, see `KZGSettings::get_extension_factor`.
+        // Settings with a narrower domain are not used for cells.
+        let field_elements_per_ext_blob = fft_settings
+            .get_max_width()
+            .max(field_elements_per_blob * 2);
 
-        let n = field_elements_per_ext_blob / 2;
+        let n = field_elements_per_blob;
         let k = n / cell_size;
         let k2 = 2 * k;
 
@@ -10,5 +10,7 @@ pub mod kzg_types;
 mod multiscalar_mul;
 pub mod poly;
 pub mod recover;
+#[cfg(feature = "serde")]
+mod serialization;
 pub mod utils;
 pub mod zero_poly;
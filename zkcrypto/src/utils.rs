@@ -26,36 +26,50 @@ pub const fn blst_fp2_into_pc_fq2(fp: &blst_fp2) -> bls12_381::Fp2 {
     bls12_381::Fp2 { c0, c1 }
 }
 
-pub const fn blst_p1_into_pc_g1projective(p1: &blst_p1) -> G1Projective {
+// blst points are in Jacobian coordinates (x = X / Z^2, y = Y / Z^3), while `bls12_381` uses
+// homogeneous projective coordinates (x = X / Z, y = Y / Z).
+
+pub fn blst_p1_into_pc_g1projective(p1: &blst_p1) -> G1Projective {
     let x = bls12_381::Fp(p1.x.l);
     let y = bls12_381::Fp(p1.y.l);
     let z = bls12_381::Fp(p1.z.l);
-    G1Projective { x, y, z }
+    G1Projective {
+        x: x * z,
+        y,
+        z: z.square() * z,
+    }
 }
 
-pub const fn pc_g1projective_into_blst_p1(p1: G1Projective) -> blst_p1 {
-    let x = blst_fp { l: p1.x.0 };
-    let y = blst_fp { l: p1.y.0 };
+pub fn pc_g1projective_into_blst_p1(p1: G1Projective) -> blst_p1 {
+    let x = blst_fp { l: (p1.x * p1.z).0 };
+    let y = blst_fp {
+        l: (p1.y * p1.z.square()).0,
+    };
     let z = blst_fp { l: p1.z.0 };
 
     blst_p1 { x, y, z }
 }
 
-pub const fn blst_p2_into_pc_g2projective(p2: &blst_p2) -> G2Projective {
+pub fn blst_p2_into_pc_g2projective(p2: &blst_p2) -> G2Projective {
+    let x = blst_fp2_into_pc_fq2(&p2.x);
+    let y = blst_fp2_into_pc_fq2(&p2.y);
+    let z = blst_fp2_into_pc_fq2(&p2.z);
     G2Projective {
-        x: blst_fp2_into_pc_fq2(&p2.x),
-        y: blst_fp2_into_pc_fq2(&p2.y),
-        z: blst_fp2_into_pc_fq2(&p2.z),
+        x: x * z,
+        y,
+        z: z.square() * z,
     }
 }
 
-pub const fn pc_g2projective_into_blst_p2(p2: G2Projective) -> blst_p2 {
+pub fn pc_g2projective_into_blst_p2(p2: G2Projective) -> blst_p2 {
+    let x = p2.x * p2.z;
     let x = blst_fp2 {
-        fp: [blst_fp { l: p2.x.c0.0 }, blst_fp { l: p2.x.c1.0 }],
+        fp: [blst_fp { l: x.c0.0 }, blst_fp { l: x.c1.0 }],
     };
 
+    let y = p2.y * p2.z.square();
     let y = blst_fp2 {
-        fp: [blst_fp { l: p2.y.c0.0 }, blst_fp { l: p2.y.c1.0 }],
+        fp: [blst_fp { l: y.c0.0 }, blst_fp { l: y.c1.0 }],
     };
 
     let z = blst_fp2 {
@@ -4,7 +4,7 @@ mod tests {
     use kzg_bench::tests::bls12_381::*;
     use rust_kzg_zkcrypto::fft_g1::g1_linear_combination;
     use rust_kzg_zkcrypto::kzg_proofs::pairings_verify;
-    use rust_kzg_zkcrypto::kzg_types::{ZFp, ZFr, ZG1Affine, ZG1ProjAddAffine, ZG1, ZG2};
+    use rust_kzg_zkcrypto::kzg_types::{ZFp, ZFr, ZG1Affine, ZG1ProjAddAffine, ZG1, ZG2, ZGT};
 
     #[test]
     pub fn log_2_byte_works_() {
@@ -92,6 +92,11 @@ mod tests {
         g1_identity_is_identity::<ZG1>();
     }
 
+    #[test]
+    pub fn g1_from_bytes_batch_works_() {
+        g1_from_bytes_batch_works::<ZFr, ZG1>();
+    }
+
     #[test]
     pub fn g1_make_linear_combination_() {
         g1_make_linear_combination::<ZFr, ZG1, ZFp, ZG1Affine, ZG1ProjAddAffine>(
@@ -123,4 +128,23 @@ mod tests {
     pub fn fr_is_null_works_() {
         fr_is_null_works::<ZFr>();
     }
+    #[test]
+    pub fn gt_pairing_is_bilinear_() {
+        gt_pairing_is_bilinear::<ZFr, ZG1, ZG2, ZGT>();
+    }
+
+    #[test]
+    pub fn gt_miller_loop_and_final_exp_work_() {
+        gt_miller_loop_and_final_exp_work::<ZFr, ZG1, ZG2, ZGT>();
+    }
+
+    #[test]
+    pub fn gt_arithmetic_works_() {
+        gt_arithmetic_works::<ZFr, ZG1, ZG2, ZGT>();
+    }
+
+    #[test]
+    pub fn gt_bytes_roundtrip_() {
+        gt_bytes_roundtrip::<ZG1, ZG2, ZGT>();
+    }
 }